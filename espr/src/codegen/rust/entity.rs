@@ -86,6 +86,34 @@ impl Entity {
         format_ident!("{}", self.name.as_str().into_safe())
     }
 
+    /// Variants of `XxxAny` enum for partial complex entities, e.g. `EmployeeAndStudent`,
+    /// and the types of their components, e.g. `Employee` or `EmployeeAny` if it is also a supertype
+    fn complex_variants(&self) -> Vec<(syn::Ident, Vec<syn::Ident>)> {
+        self.complex_constraints
+            .iter()
+            .map(|pce| {
+                let mut names = Vec::new();
+                let mut types = Vec::new();
+                for ty in pce {
+                    match ty {
+                        TypeRef::Entity {
+                            name, is_supertype, ..
+                        } => {
+                            names.push(name.to_pascal_case());
+                            if *is_supertype {
+                                types.push(format_ident!("{}Any", name.to_pascal_case()));
+                            } else {
+                                types.push(format_ident!("{}", name.to_pascal_case()));
+                            }
+                        }
+                        _ => unreachable!(),
+                    }
+                }
+                (format_ident!("{}", names.join("And")), types)
+            })
+            .collect()
+    }
+
     /// Generate declaration of `XxxAny` enum
     fn generate_any_enum(&self, tokens: &mut TokenStream) {
         let any = self.any_ident();
@@ -111,6 +139,18 @@ impl Entity {
             }
         }
 
+        // Complex entity instances, e.g. `(EMPLOYEE(..) PERSON(..) STUDENT(..))` for `employee & student`
+        let complex = self
+            .complex_variants()
+            .into_iter()
+            .map(|(variant, types)| {
+                quote! {
+                    #[holder(complex)]
+                    #variant(Box<(#(#types),*)>)
+                }
+            })
+            .collect::<Vec<_>>();
+
         tokens.append_all(quote! {
             #[derive(Debug, Clone, PartialEq, Holder)]
             #[holder(table = Tables)]
@@ -118,8 +158,9 @@ impl Entity {
             pub enum #any {
                 #(
                 #[holder(use_place_holder)]
-                #variants(Box<#constraints>)
-                ),*
+                #variants(Box<#constraints>),
+                )*
+                #(#complex,)*
             }
         }); // tokens.append_all
     }
@@ -167,6 +208,12 @@ impl Entity {
                 _ => unreachable!(),
             })
             .collect::<Vec<_>>();
+        // The first component of a complex entity is also a subtype of this entity
+        let complex = self
+            .complex_variants()
+            .into_iter()
+            .map(|(variant, _types)| variant)
+            .collect::<Vec<_>>();
 
         tokens.append_all(quote! {
             impl AsRef<#name> for #any {
@@ -174,6 +221,7 @@ impl Entity {
                     match self {
                         #any::#name (x) => x.as_ref(),
                         #(#any::#constraints (x) => (**x).as_ref(),)*
+                        #(#any::#complex (x) => x.0.as_ref(),)*
                     }
                 }
             }
@@ -193,6 +241,7 @@ impl Entity {
                         match self {
                             #any::#name (x) => AsRef::<#name>::as_ref(x).as_ref(),
                             #(#any::#constraints (x) => AsRef::<#name>::as_ref(x.as_ref()).as_ref(),)*
+                            #(#any::#complex (x) => AsRef::<#name>::as_ref(&x.0).as_ref(),)*
                         }
                     }
                 }
@@ -213,6 +262,7 @@ impl Entity {
                     attributes.push(parse_quote! { #[deref_mut] });
                 }
                 attributes.push(parse_quote! { #[holder(use_place_holder)] });
+                attributes.push(parse_quote! { #[holder(supertype)] });
                 let (name, ty) = match ty {
                     TypeRef::Named { name, .. } | TypeRef::Entity { name, .. } => {
                         let ty = format_ident!("{}", name.to_pascal_case());
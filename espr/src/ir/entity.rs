@@ -11,6 +11,12 @@ pub struct Entity {
     /// and `SUPERTYPE OF` declaration in EXPRESS schema
     pub constraints: Vec<TypeRef>,
 
+    /// Partial complex entities consist of two or more subtypes, e.g. `[employee, student]`
+    /// for `employee & student` allowed by `SUPERTYPE OF (employee ANDOR student)`
+    ///
+    /// This is empty if there are more than [MAX_COMPLEX_ENTITIES] of them.
+    pub complex_constraints: Vec<Vec<TypeRef>>,
+
     /// List of types to be inherited by this entity
     ///
     /// When this entity is `sub` defined like:
@@ -25,6 +31,12 @@ pub struct Entity {
     pub supertypes: Vec<TypeRef>,
 }
 
+/// Upper limit of [Entity::complex_constraints] for a supertype
+///
+/// `ANDOR` of n subtypes, including the default constraint, allows `2^n - n - 1` partial complex entities,
+/// e.g. more than a hundred thousand for `geometric_representation_item` in AP201.
+pub const MAX_COMPLEX_ENTITIES: usize = 128;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EntityAttribute {
     pub name: String,
@@ -81,15 +93,25 @@ impl Legalize for Entity {
         };
 
         let path = Path::entity(scope, &entity.name);
-        let constraints = if let Some(instantiables) = ss.instantiables.get(&path) {
-            instantiables
-                .iter()
-                .filter_map(|pce| match pce.len() {
-                    // FIXME ignore complex entity case
-                    1 => Some(TypeRef::from_path(ns, ss, &pce[0])),
-                    _ => None,
+        let instantiables = ss
+            .instantiables
+            .get(&path)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let constraints = instantiables
+            .iter()
+            .filter(|pce| pce.len() == 1)
+            .map(|pce| TypeRef::from_path(ns, ss, &pce[0]))
+            .collect::<Result<Vec<TypeRef>, SemanticError>>()?;
+        let complex = instantiables.iter().filter(|pce| pce.len() > 1);
+        let complex_constraints = if complex.clone().count() <= MAX_COMPLEX_ENTITIES {
+            complex
+                .map(|pce| {
+                    pce.iter()
+                        .map(|path| TypeRef::from_path(ns, ss, path))
+                        .collect::<Result<Vec<TypeRef>, SemanticError>>()
                 })
-                .collect::<Result<Vec<TypeRef>, SemanticError>>()?
+                .collect::<Result<Vec<_>, SemanticError>>()?
         } else {
             Vec::new()
         };
@@ -98,6 +120,7 @@ impl Legalize for Entity {
             name,
             attributes,
             constraints,
+            complex_constraints,
             supertypes,
         })
     }
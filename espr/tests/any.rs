@@ -99,6 +99,7 @@ fn any() {
             #[deref]
             #[deref_mut]
             #[holder(use_place_holder)]
+            #[holder(supertype)]
             pub base: Base,
             pub y1: f64,
         }
@@ -114,6 +115,7 @@ fn any() {
             #[deref]
             #[deref_mut]
             #[holder(use_place_holder)]
+            #[holder(supertype)]
             pub base: Base,
             pub y2: f64,
         }
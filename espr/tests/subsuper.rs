@@ -93,6 +93,7 @@ fn subsuper() {
             #[deref]
             #[deref_mut]
             #[holder(use_place_holder)]
+            #[holder(supertype)]
             pub base: Base,
             pub y: f64,
         }
@@ -143,6 +144,7 @@ fn subsuper() {
             #[deref]
             #[deref_mut]
             #[holder(use_place_holder)]
+            #[holder(supertype)]
             pub sub: Sub,
             pub z: f64,
         }
@@ -67,7 +67,7 @@ fn type_decl() {
         # [holder (field = a)]
         #[holder(generate_deserialize)]
         pub struct A(pub String);
-        #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+        #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
         pub enum B {
             Are,
             Sore,
@@ -14,6 +14,10 @@ pub fn as_holder_ident(input: &syn::Ident) -> syn::Ident {
     format_ident!("{}Holder", input)
 }
 
+pub fn as_view_ident(input: &syn::Ident) -> syn::Ident {
+    format_ident!("{}View", input)
+}
+
 pub fn as_holder_path(input: &syn::Type) -> syn::Type {
     let ft: FieldType = input
         .clone()
@@ -31,11 +35,6 @@ pub fn serde_crate() -> syn::Path {
     syn::parse_quote!( #ruststep::serde )
 }
 
-pub fn itertools_crate() -> syn::Path {
-    let ruststep = ruststep_crate();
-    syn::parse_quote!( #ruststep::itertools )
-}
-
 /// Returns `crate` or `::ruststep` as in ruststep crate or not
 pub fn ruststep_crate() -> syn::Path {
     let path = crate_name("ruststep").unwrap();
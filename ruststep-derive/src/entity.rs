@@ -22,6 +22,9 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
     let def_holder_tt = def_holder(ident, st);
     let impl_holder_tt = impl_holder(ident, attr, st);
     let impl_entity_table_tt = impl_entity_table(ident, attr);
+    let def_view_tt = def_view(ident, attr, st);
+    let impl_serialize_tt = impl_serialize(ident, &name, st);
+    let impl_holder_serialize_tt = impl_serialize(&holder_ident, &name, st);
     if attr.generate_deserialize {
         let def_visitor_tt = def_visitor(&holder_ident, &name, st);
         let impl_deserialize_tt = impl_deserialize(&holder_ident, &name, st);
@@ -30,6 +33,9 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #def_view_tt
+            #impl_serialize_tt
+            #impl_holder_serialize_tt
             #def_visitor_tt
             #impl_deserialize_tt
             #impl_with_visitor_tt
@@ -39,6 +45,9 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #def_view_tt
+            #impl_serialize_tt
+            #impl_holder_serialize_tt
         }
     }
 }
@@ -70,23 +79,12 @@ impl FieldEntries {
 
             let HolderAttr { place_holder, .. } = HolderAttr::parse(&field.attrs);
             if place_holder {
-                match &ft {
-                    FieldType::Path(_) => {
-                        into_owned.push(quote! { #ident.into_owned(#table_arg)? });
-                    }
-                    FieldType::Optional(_) => {
-                        into_owned.push(quote! { #ident.map(|holder| holder.into_owned(#table_arg)).transpose()? });
-                    }
-                    FieldType::List(_) => into_owned.push(quote! {
-                        #ident
-                            .into_iter()
-                            .map(|v| v.into_owned(#table_arg))
-                            .collect::<::std::result::Result<Vec<_>, _>>()?
-                    }),
-                    FieldType::Boxed(_) => abort_call_site!("Unexpected Box<T>"),
-                }
+                into_owned.push(ft.into_owned(quote! { #ident }, &table_arg));
                 holder_types.push(ft.as_holder().as_place_holder().into());
             } else {
+                if let FieldType::Identified(_) = ft {
+                    abort_call_site!("Identified<T> requires #[holder(use_place_holder)]");
+                }
                 into_owned.push(quote! { #ident });
                 holder_types.push(ft.into());
             }
@@ -128,12 +126,21 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
     let table_arg = table_arg();
     let ruststep = ruststep_crate();
 
+    let mut supertypes = Vec::new();
+    for field in &st.fields {
+        let HolderAttr { supertype, .. } = HolderAttr::parse(&field.attrs);
+        if supertype {
+            let ident = field.ident.as_ref().expect_or_abort("st is not struct");
+            supertypes.push(ident.to_string().to_screaming_snake_case());
+        }
+    }
+
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::IntoOwned for #holder_ident {
             type Table = #table;
             type Owned = #ident;
-            fn into_owned(self, #table_arg: &Self::Table) -> #ruststep::error::Result<Self::Owned> {
+            fn into_owned_in(self, #table_arg: &Self::Table, resolution: &mut #ruststep::tables::Resolution) -> #ruststep::error::Result<Self::Owned> {
                 let #holder_ident { #(#attributes),* } = self;
                 Ok(#ident { #(#attributes: #into_owned),* })
             }
@@ -146,6 +153,9 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
             fn attr_len() -> usize {
                 #attr_len
             }
+            fn supertypes() -> &'static [&'static str] {
+                &[#(#supertypes),*]
+            }
         }
     } // quote!
 }
@@ -158,12 +168,85 @@ pub fn impl_entity_table(ident: &syn::Ident, table: &HolderAttr) -> TokenStream2
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::EntityTable<#holder_ident> for #table {
-            fn get_owned(&self, entity_id: u64) -> #ruststep::error::Result<#ident> {
-                #ruststep::tables::get_owned(self, &self.#field, entity_id)
+            fn get_owned_in(&self, entity_id: u64, resolution: &mut #ruststep::tables::Resolution) -> #ruststep::error::Result<#ident> {
+                #ruststep::tables::get_owned_in(self, &self.#field, entity_id, resolution)
             }
             fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<#ident>> + 'table> {
                 #ruststep::tables::owned_iter(self, &self.#field)
             }
+            fn owned_iter_with_id<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<(u64, #ident)>> + 'table> {
+                #ruststep::tables::owned_iter_with_id(self, &self.#field)
+            }
+        }
+    }
+}
+
+/// View struct borrowing the holder and the table, e.g. `AView<'t>` for `A`
+pub fn def_view(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct) -> TokenStream2 {
+    let HolderAttr { table, field, .. } = table;
+    let holder_ident = as_holder_ident(ident);
+    let view_ident = as_view_ident(ident);
+    let view_name = view_ident.to_string();
+    let table_arg = table_arg();
+    let ruststep = ruststep_crate();
+
+    let mut methods = Vec::new();
+    for f in &st.fields {
+        let attr = f.ident.as_ref().expect_or_abort("st is not struct");
+        let ft: FieldType = f.ty.clone().try_into().unwrap();
+        let HolderAttr { place_holder, .. } = HolderAttr::parse(&f.attrs);
+        if place_holder {
+            let (ty, expr) = ft.as_holder().as_view(quote! { &holder.#attr }, &table_arg);
+            methods.push(quote! {
+                pub fn #attr(&self) -> #ty {
+                    let holder = self.holder;
+                    let #table_arg = self.table;
+                    #expr
+                }
+            });
+        } else {
+            let ty: syn::Type = ft.into();
+            methods.push(quote! {
+                pub fn #attr(&self) -> &'t #ty {
+                    let holder = self.holder;
+                    &holder.#attr
+                }
+            });
+        }
+    }
+
+    quote! {
+        /// Auto-generated by `#[derive(Holder)]`
+        #[derive(Clone, Copy)]
+        pub struct #view_ident<'t> {
+            pub holder: &'t #holder_ident,
+            pub table: &'t #table,
+        }
+
+        #[automatically_derived]
+        impl<'t> ::std::fmt::Debug for #view_ident<'t> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.debug_tuple(#view_name).field(self.holder).finish()
+            }
+        }
+
+        #[automatically_derived]
+        impl<'t> #view_ident<'t> {
+            #(#methods)*
+        }
+
+        #[automatically_derived]
+        impl<'t> #ruststep::tables::Viewable<'t> for #holder_ident {
+            type View = #view_ident<'t>;
+            fn view(&'t self, #table_arg: &'t Self::Table) -> Self::View {
+                #view_ident { holder: self, #table_arg }
+            }
+            fn view_id(#table_arg: &'t Self::Table, entity_id: u64) -> #ruststep::error::Result<Self::View> {
+                match #table_arg.#field.get(&entity_id) {
+                    Some(holder) => Ok(#view_ident { holder, #table_arg }),
+                    None => Err(#ruststep::error::Error::UnknownEntity(entity_id)),
+                }
+            }
         }
     }
 }
@@ -239,6 +322,43 @@ fn impl_deserialize(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> Tok
     } // quote!
 }
 
+// `name` may be different from `ident`
+// because this will be used for both Entity struct and its `*Holder` struct.
+fn impl_serialize(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStream2 {
+    let ruststep = ruststep_crate();
+    let serde = serde_crate();
+
+    let mut keys = Vec::new();
+    let mut values = Vec::new();
+    for field in &st.fields {
+        let ident = field.ident.as_ref().expect_or_abort("st is not struct");
+        keys.push(ident.to_string());
+        // Supertype is a part of this entity instance, and never be a reference
+        let HolderAttr { supertype, .. } = HolderAttr::parse(&field.attrs);
+        if supertype {
+            values.push(quote! { &#ruststep::ast::ser::Inline(&self.#ident) });
+        } else {
+            values.push(quote! { &self.#ident });
+        }
+    }
+    let attr_len = keys.len();
+
+    quote! {
+        #[automatically_derived]
+        impl #serde::ser::Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: #serde::ser::Serializer,
+            {
+                use #serde::ser::SerializeStruct;
+                let mut st = serializer.serialize_struct(#name, #attr_len)?;
+                #( st.serialize_field(#keys, #values)?; )*
+                st.end()
+            }
+        }
+    } // quote!
+}
+
 fn impl_with_visitor(ident: &syn::Ident) -> TokenStream2 {
     let ruststep = ruststep_crate();
 
@@ -1,6 +1,8 @@
 use super::*;
 
-use proc_macro_error::{Diagnostic, Level};
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro_error::{abort_call_site, Diagnostic, Level};
+use quote::quote;
 use std::convert::{TryFrom, TryInto};
 
 /// espr-generated field type
@@ -14,6 +16,8 @@ pub enum FieldType {
     List(Box<FieldType>),
     /// Like `Box<T>`
     Boxed(Box<FieldType>),
+    /// Like `Identified<T>`, i.e. owned value with the entity id of a reference
+    Identified(Box<FieldType>),
 }
 
 impl FieldType {
@@ -48,6 +52,10 @@ impl FieldType {
                 let holder = ty.as_holder();
                 FieldType::Boxed(Box::new(holder))
             }
+            FieldType::Identified(ty) => {
+                let holder = ty.as_holder();
+                FieldType::Identified(Box::new(holder))
+            }
         }
     }
 
@@ -70,6 +78,69 @@ impl FieldType {
                 let place_holder = ty.as_place_holder();
                 FieldType::Boxed(Box::new(place_holder))
             }
+            // Entity id is kept in the place-holder itself
+            FieldType::Identified(ty) => ty.as_place_holder(),
+        }
+    }
+
+    /// Type and expression of the view of a place-holder field,
+    /// where `expr` is a reference to the field, and `table` is a reference to the table.
+    ///
+    /// This must be called for holder type, i.e. after [FieldType::as_holder].
+    pub fn as_view(&self, expr: TokenStream2, table: &syn::Ident) -> (TokenStream2, TokenStream2) {
+        let ruststep = ruststep_crate();
+        match self {
+            FieldType::Path(path) => (
+                quote! { #ruststep::tables::Ref<'t, #path> },
+                quote! { #ruststep::tables::Ref::new(#expr, #table) },
+            ),
+            FieldType::Optional(ty) => {
+                let (ty, expr_v) = ty.as_view(quote! { v }, table);
+                (
+                    quote! { Option<#ty> },
+                    quote! { (#expr).as_ref().map(|v| #expr_v) },
+                )
+            }
+            FieldType::List(ty) => {
+                let (ty, expr_v) = ty.as_view(quote! { v }, table);
+                (
+                    quote! { Vec<#ty> },
+                    quote! { (#expr).iter().map(|v| #expr_v).collect::<Vec<_>>() },
+                )
+            }
+            FieldType::Identified(ty) => ty.as_view(expr, table),
+            FieldType::Boxed(_) => abort_call_site!("Unexpected Box<T>"),
+        }
+    }
+
+    /// Expression converting a place-holder field into owned value in `IntoOwned::into_owned_in`,
+    /// where `expr` is the field, and `table` is a reference to the table.
+    ///
+    /// This must be called for owned type, e.g. `Option<Identified<T>>`.
+    pub fn into_owned(&self, expr: TokenStream2, table: &syn::Ident) -> TokenStream2 {
+        let ruststep = ruststep_crate();
+        match self {
+            FieldType::Path(_) => quote! { #expr.into_owned_in(#table, resolution)? },
+            FieldType::Identified(_) => quote! { #expr.into_identified_in(#table, resolution)? },
+            FieldType::Optional(ty) => {
+                let owned = ty.into_owned(quote! { holder }, table);
+                quote! {
+                    match #expr {
+                        Some(holder) => Some(#owned),
+                        None => None,
+                    }
+                }
+            }
+            FieldType::List(ty) => {
+                let owned = ty.into_owned(quote! { holder }, table);
+                quote! {
+                    #expr
+                        .into_iter()
+                        .map(|holder| -> #ruststep::error::Result<_> { Ok(#owned) })
+                        .collect::<#ruststep::error::Result<Vec<_>>>()?
+                }
+            }
+            FieldType::Boxed(_) => abort_call_site!("Unexpected Box<T>"),
         }
     }
 }
@@ -102,6 +173,11 @@ impl Into<syn::Type> for FieldType {
                 let ty: syn::Type = (*ty).into();
                 syn::parse_quote! { Box<#ty> }
             }
+            FieldType::Identified(ty) => {
+                let ruststep = ruststep_crate();
+                let ty: syn::Type = (*ty).into();
+                syn::parse_quote! { #ruststep::tables::Identified<#ty> }
+            }
         };
         syn::Type::Path(syn::TypePath { qself: None, path })
     }
@@ -138,6 +214,9 @@ impl TryFrom<syn::Type> for FieldType {
                     if last_seg.ident == "Box" {
                         return Ok(FieldType::Boxed(ty));
                     }
+                    if last_seg.ident == "Identified" {
+                        return Ok(FieldType::Identified(ty));
+                    }
                 }
                 Err(UnsupportedTypeError {})
             }
@@ -232,5 +311,12 @@ mod tests {
         let ans: syn::Type =
             syn::parse_str("Option<Vec<::ruststep::tables::PlaceHolder<THolder>>>").unwrap();
         assert_eq!(<FieldType as Into<syn::Type>>::into(place_holder), ans);
+
+        let ty: syn::Type = syn::parse_str("Vec<Identified<T>>").unwrap();
+        let f: FieldType = ty.try_into().unwrap();
+        let place_holder = f.as_holder().as_place_holder();
+        let ans: syn::Type =
+            syn::parse_str("Vec<::ruststep::tables::PlaceHolder<THolder>>").unwrap();
+        assert_eq!(<FieldType as Into<syn::Type>>::into(place_holder), ans);
     }
 }
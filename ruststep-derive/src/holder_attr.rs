@@ -1,10 +1,12 @@
 //! Parse the associated attribute `#[holder(...)]` with `#[derive(Holder)]`
 //!
-//! There are three options:
+//! There are following options:
 //!
 //! - `#[holder(table = {path::to::table::struct})]`
 //! - `#[holder(field = {field_ident})]`
 //! - `#[holder(use_place_holder)]`
+//! - `#[holder(supertype)]`
+//! - `#[holder(complex)]`
 //! - `#[holder(generate_deserialize)]`
 //!
 
@@ -13,6 +15,8 @@ pub struct HolderAttr {
     pub table: Option<syn::Path>,
     pub field: Option<syn::Ident>,
     pub place_holder: bool,
+    pub supertype: bool,
+    pub complex: bool,
     pub generate_deserialize: bool,
 }
 
@@ -21,6 +25,8 @@ impl HolderAttr {
         let mut table = None;
         let mut field = None;
         let mut place_holder = false;
+        let mut supertype = false;
+        let mut complex = false;
         let mut generate_deserialize = false;
 
         for attr in attrs {
@@ -43,6 +49,12 @@ impl HolderAttr {
                 Attr::PlaceHolder => {
                     place_holder = true;
                 }
+                Attr::Supertype => {
+                    supertype = true;
+                }
+                Attr::Complex => {
+                    complex = true;
+                }
                 Attr::GenerateDeserialize => {
                     generate_deserialize = true;
                 }
@@ -52,6 +64,8 @@ impl HolderAttr {
             table,
             field,
             place_holder,
+            supertype,
+            complex,
             generate_deserialize,
         }
     }
@@ -62,6 +76,8 @@ enum Attr {
     Table(syn::Path),
     Field(syn::Ident),
     PlaceHolder,
+    Supertype,
+    Complex,
     GenerateDeserialize,
 }
 
@@ -80,10 +96,12 @@ impl syn::parse::Parse for Attr {
                 Ok(Attr::Field(ident))
             }
             "use_place_holder" => Ok(Attr::PlaceHolder),
+            "supertype" => Ok(Attr::Supertype),
+            "complex" => Ok(Attr::Complex),
             "generate_deserialize" => Ok(Attr::GenerateDeserialize),
             _ => Err(syn::parse::Error::new(
                 ident.span(),
-                "expected `table`, `field`, or `use_place_holder`",
+                "expected `table`, `field`, `use_place_holder`, `supertype`, `complex`, or `generate_deserialize`",
             )),
         }
     }
@@ -132,4 +150,20 @@ mod tests {
         // typo
         assert!(syn::parse_str::<Attr>("use_place_helder").is_err());
     }
+
+    #[test]
+    fn parse_attr_supertype() {
+        let attr: Attr = syn::parse_str("supertype").unwrap();
+        assert_eq!(attr, Attr::Supertype);
+
+        assert!(syn::parse_str::<Attr>("supertype = base").is_err());
+    }
+
+    #[test]
+    fn parse_attr_complex() {
+        let attr: Attr = syn::parse_str("complex").unwrap();
+        assert_eq!(attr, Attr::Complex);
+
+        assert!(syn::parse_str::<Attr>("complex = true").is_err());
+    }
 }
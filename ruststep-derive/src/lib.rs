@@ -40,7 +40,7 @@ use field_type::*;
 use holder_attr::*;
 use table_init::*;
 
-/// Derive `TableInit` for tables
+/// Derive `TableInit` and `ToDataSection` for tables
 ///
 /// ```
 /// use ruststep_derive::{as_holder, Holder, TableInit};
@@ -131,6 +131,10 @@ fn derive_deserialize(ast: &syn::DeriveInput) -> TokenStream2 {
 /// - `#[holder(use_place_holder)]`
 ///   - This can be both in field or variant attribute
 ///   - Specify the field is not a simple type
+///   - `Identified<T>` of a field, e.g. `Vec<Identified<A>>`, keeps the entity ids of the references
+/// - `#[holder(supertype)]`
+///   - This must be a field attribute
+///   - Specify the field is a supertype, which is always serialized inline
 ///
 #[proc_macro_error]
 #[proc_macro_derive(Holder, attributes(holder))]
@@ -196,10 +200,14 @@ mod snapshot_tests {
         impl ::ruststep::tables::IntoOwned for S1Holder {
             type Owned = S1;
             type Table = Table;
-            fn into_owned(self, table: &Self::Table) -> ::ruststep::error::Result<Self::Owned> {
+            fn into_owned_in(
+                self,
+                table: &Self::Table,
+                resolution: &mut ::ruststep::tables::Resolution,
+            ) -> ::ruststep::error::Result<Self::Owned> {
                 Ok(match self {
-                    S1Holder::A(sub) => S1::A(Box::new(sub.into_owned(table)?)),
-                    S1Holder::B(sub) => S1::B(Box::new(sub.into_owned(table)?)),
+                    S1Holder::A(sub) => S1::A(Box::new(sub.into_owned_in(table, resolution)?)),
+                    S1Holder::B(sub) => S1::B(Box::new(sub.into_owned_in(table, resolution)?)),
                 })
             }
         }
@@ -211,6 +219,57 @@ mod snapshot_tests {
                 0
             }
         }
+        #[automatically_derived]
+        impl ::ruststep::serde::ser::Serialize for S1 {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::ruststep::serde::ser::Serializer,
+            {
+                match self {
+                    S1::A(sub) => serializer.serialize_newtype_variant("S1", 0u32, "A", sub),
+                    S1::B(sub) => serializer.serialize_newtype_variant("S1", 1u32, "B", sub),
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::ruststep::serde::ser::Serialize for S1Holder {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::ruststep::serde::ser::Serializer,
+            {
+                match self {
+                    S1Holder::A(sub) => serializer.serialize_newtype_variant("S1", 0u32, "A", sub),
+                    S1Holder::B(sub) => serializer.serialize_newtype_variant("S1", 1u32, "B", sub),
+                }
+            }
+        }
+        #[doc = r" Auto-generated by `#[derive(Holder)]`"]
+        #[derive(Debug, Clone)]
+        pub enum S1View<'t> {
+            A(<AHolder as ::ruststep::tables::Viewable<'t>>::View),
+            B(<BHolder as ::ruststep::tables::Viewable<'t>>::View),
+        }
+        #[automatically_derived]
+        impl<'t> ::ruststep::tables::Viewable<'t> for S1Holder {
+            type View = S1View<'t>;
+            #[allow(unused_variables)]
+            fn view(&'t self, table: &'t Table) -> Self::View {
+                match self {
+                    S1Holder::A(sub) => S1View::A(::ruststep::tables::Viewable::view(sub.as_ref(), table)),
+                    S1Holder::B(sub) => S1View::B(::ruststep::tables::Viewable::view(sub.as_ref(), table)),
+                }
+            }
+            #[allow(unused_variables)]
+            fn view_id(table: &'t Table, entity_id: u64) -> ::ruststep::error::Result<Self::View> {
+                if let Ok(view) = <AHolder as ::ruststep::tables::Viewable<'t>>::view_id(table, entity_id) {
+                    return Ok(S1View::A(view));
+                }
+                if let Ok(view) = <BHolder as ::ruststep::tables::Viewable<'t>>::view_id(table, entity_id) {
+                    return Ok(S1View::B(view));
+                }
+                Err(::ruststep::error::Error::UnknownEntity(entity_id))
+            }
+        }
         impl<'de> ::ruststep::serde::de::Deserialize<'de> for S1Holder {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
             where
@@ -255,24 +314,50 @@ mod snapshot_tests {
             }
         }
         impl ::ruststep::tables::EntityTable<S1Holder> for Table {
-            fn get_owned(&self, entity_id: u64) -> ::ruststep::error::Result<S1> {
-                if let Ok(owned) = ::ruststep::tables::EntityTable::<AHolder>::get_owned(self, entity_id) {
-                    return Ok(S1::A(Box::new(owned.into())));
+            fn get_owned_in(
+                &self,
+                entity_id: u64,
+                resolution: &mut ::ruststep::tables::Resolution,
+            ) -> ::ruststep::error::Result<S1> {
+                match ::ruststep::tables::EntityTable::<AHolder>::get_owned_in(self, entity_id, resolution)
+                {
+                    Ok(owned) => return Ok(S1::A(Box::new(owned.into()))),
+                    Err(::ruststep::error::Error::UnknownEntity(id)) if id == entity_id => {}
+                    Err(e) => return Err(e),
                 }
-                if let Ok(owned) = ::ruststep::tables::EntityTable::<BHolder>::get_owned(self, entity_id) {
-                    return Ok(S1::B(Box::new(owned.into())));
+                match ::ruststep::tables::EntityTable::<BHolder>::get_owned_in(self, entity_id, resolution)
+                {
+                    Ok(owned) => return Ok(S1::B(Box::new(owned.into()))),
+                    Err(::ruststep::error::Error::UnknownEntity(id)) if id == entity_id => {}
+                    Err(e) => return Err(e),
                 }
                 Err(::ruststep::error::Error::UnknownEntity(entity_id))
             }
             fn owned_iter<'table>(
                 &'table self,
             ) -> Box<dyn Iterator<Item = ::ruststep::error::Result<S1>> + 'table> {
-                Box::new(::ruststep::itertools::chain![
-                    ::ruststep::tables::EntityTable::<AHolder>::owned_iter(self)
-                        .map(|owned| owned.map(|owned| S1::A(Box::new(owned.into())))),
-                    ::ruststep::tables::EntityTable::<BHolder>::owned_iter(self)
-                        .map(|owned| owned.map(|owned| S1::B(Box::new(owned.into()))))
-                ])
+                Box::new(
+                    ::ruststep::tables::EntityTable::<S1Holder>::owned_iter_with_id(self)
+                        .map(|owned| owned.map(|(_id, owned)| owned)),
+                )
+            }
+            fn owned_iter_with_id<'table>(
+                &'table self,
+            ) -> Box<dyn Iterator<Item = ::ruststep::error::Result<(u64, S1)>> + 'table> {
+                let mut iters: Vec<
+                    Box<dyn Iterator<Item = ::ruststep::error::Result<(u64, S1)>> + 'table>,
+                > = Vec::new();
+                iters.push(Box::new(
+                    ::ruststep::tables::EntityTable::<AHolder>::owned_iter_with_id(self)
+                        .map(|owned| owned.map(|(id, owned)| (id, S1::A(Box::new(owned.into()))))),
+                ));
+                iters.push(Box::new(
+                    ::ruststep::tables::EntityTable::<BHolder>::owned_iter_with_id(self)
+                        .map(|owned| owned.map(|(id, owned)| (id, S1::B(Box::new(owned.into()))))),
+                ));
+                ::ruststep::tables::merge_with_id(iters, move |entity_id| {
+                    ::ruststep::tables::EntityTable::<S1Holder>::get_owned(self, entity_id)
+                })
             }
         }
         "###);
@@ -308,10 +393,18 @@ mod snapshot_tests {
         impl ::ruststep::tables::IntoOwned for BaseAnyHolder {
             type Owned = BaseAny;
             type Table = Tables;
-            fn into_owned(self, table: &Self::Table) -> ::ruststep::error::Result<Self::Owned> {
+            fn into_owned_in(
+                self,
+                table: &Self::Table,
+                resolution: &mut ::ruststep::tables::Resolution,
+            ) -> ::ruststep::error::Result<Self::Owned> {
                 Ok(match self {
-                    BaseAnyHolder::Base(sub) => BaseAny::Base(Box::new(sub.into_owned(table)?)),
-                    BaseAnyHolder::Sub(sub) => BaseAny::Sub(Box::new(sub.into_owned(table)?)),
+                    BaseAnyHolder::Base(sub) => {
+                        BaseAny::Base(Box::new(sub.into_owned_in(table, resolution)?))
+                    }
+                    BaseAnyHolder::Sub(sub) => {
+                        BaseAny::Sub(Box::new(sub.into_owned_in(table, resolution)?))
+                    }
                 })
             }
         }
@@ -323,6 +416,71 @@ mod snapshot_tests {
                 0
             }
         }
+        #[automatically_derived]
+        impl ::ruststep::serde::ser::Serialize for BaseAny {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::ruststep::serde::ser::Serializer,
+            {
+                match self {
+                    BaseAny::Base(sub) => {
+                        serializer.serialize_newtype_variant("BASE_ANY", 0u32, "BASE", sub)
+                    }
+                    BaseAny::Sub(sub) => serializer.serialize_newtype_variant("BASE_ANY", 1u32, "SUB", sub),
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::ruststep::serde::ser::Serialize for BaseAnyHolder {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::ruststep::serde::ser::Serializer,
+            {
+                match self {
+                    BaseAnyHolder::Base(sub) => {
+                        serializer.serialize_newtype_variant("BASE_ANY", 0u32, "BASE", sub)
+                    }
+                    BaseAnyHolder::Sub(sub) => {
+                        serializer.serialize_newtype_variant("BASE_ANY", 1u32, "SUB", sub)
+                    }
+                }
+            }
+        }
+        #[doc = r" Auto-generated by `#[derive(Holder)]`"]
+        #[derive(Debug, Clone)]
+        pub enum BaseAnyView<'t> {
+            Base(<BaseHolder as ::ruststep::tables::Viewable<'t>>::View),
+            Sub(<SubAnyHolder as ::ruststep::tables::Viewable<'t>>::View),
+        }
+        #[automatically_derived]
+        impl<'t> ::ruststep::tables::Viewable<'t> for BaseAnyHolder {
+            type View = BaseAnyView<'t>;
+            #[allow(unused_variables)]
+            fn view(&'t self, table: &'t Tables) -> Self::View {
+                match self {
+                    BaseAnyHolder::Base(sub) => {
+                        BaseAnyView::Base(::ruststep::tables::Viewable::view(sub.as_ref(), table))
+                    }
+                    BaseAnyHolder::Sub(sub) => {
+                        BaseAnyView::Sub(::ruststep::tables::Viewable::view(sub.as_ref(), table))
+                    }
+                }
+            }
+            #[allow(unused_variables)]
+            fn view_id(table: &'t Tables, entity_id: u64) -> ::ruststep::error::Result<Self::View> {
+                if let Ok(view) =
+                    <BaseHolder as ::ruststep::tables::Viewable<'t>>::view_id(table, entity_id)
+                {
+                    return Ok(BaseAnyView::Base(view));
+                }
+                if let Ok(view) =
+                    <SubAnyHolder as ::ruststep::tables::Viewable<'t>>::view_id(table, entity_id)
+                {
+                    return Ok(BaseAnyView::Sub(view));
+                }
+                Err(::ruststep::error::Error::UnknownEntity(entity_id))
+            }
+        }
         impl<'de> ::ruststep::serde::de::Deserialize<'de> for BaseAnyHolder {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
             where
@@ -367,27 +525,52 @@ mod snapshot_tests {
             }
         }
         impl ::ruststep::tables::EntityTable<BaseAnyHolder> for Tables {
-            fn get_owned(&self, entity_id: u64) -> ::ruststep::error::Result<BaseAny> {
-                if let Ok(owned) = ::ruststep::tables::EntityTable::<BaseHolder>::get_owned(self, entity_id)
-                {
-                    return Ok(BaseAny::Base(Box::new(owned.into())));
+            fn get_owned_in(
+                &self,
+                entity_id: u64,
+                resolution: &mut ::ruststep::tables::Resolution,
+            ) -> ::ruststep::error::Result<BaseAny> {
+                match ::ruststep::tables::EntityTable::<BaseHolder>::get_owned_in(
+                    self, entity_id, resolution,
+                ) {
+                    Ok(owned) => return Ok(BaseAny::Base(Box::new(owned.into()))),
+                    Err(::ruststep::error::Error::UnknownEntity(id)) if id == entity_id => {}
+                    Err(e) => return Err(e),
                 }
-                if let Ok(owned) =
-                    ::ruststep::tables::EntityTable::<SubAnyHolder>::get_owned(self, entity_id)
-                {
-                    return Ok(BaseAny::Sub(Box::new(owned.into())));
+                match ::ruststep::tables::EntityTable::<SubAnyHolder>::get_owned_in(
+                    self, entity_id, resolution,
+                ) {
+                    Ok(owned) => return Ok(BaseAny::Sub(Box::new(owned.into()))),
+                    Err(::ruststep::error::Error::UnknownEntity(id)) if id == entity_id => {}
+                    Err(e) => return Err(e),
                 }
                 Err(::ruststep::error::Error::UnknownEntity(entity_id))
             }
             fn owned_iter<'table>(
                 &'table self,
             ) -> Box<dyn Iterator<Item = ::ruststep::error::Result<BaseAny>> + 'table> {
-                Box::new(::ruststep::itertools::chain![
-                    ::ruststep::tables::EntityTable::<BaseHolder>::owned_iter(self)
-                        .map(|owned| owned.map(|owned| BaseAny::Base(Box::new(owned.into())))),
-                    ::ruststep::tables::EntityTable::<SubAnyHolder>::owned_iter(self)
-                        .map(|owned| owned.map(|owned| BaseAny::Sub(Box::new(owned.into()))))
-                ])
+                Box::new(
+                    ::ruststep::tables::EntityTable::<BaseAnyHolder>::owned_iter_with_id(self)
+                        .map(|owned| owned.map(|(_id, owned)| owned)),
+                )
+            }
+            fn owned_iter_with_id<'table>(
+                &'table self,
+            ) -> Box<dyn Iterator<Item = ::ruststep::error::Result<(u64, BaseAny)>> + 'table> {
+                let mut iters: Vec<
+                    Box<dyn Iterator<Item = ::ruststep::error::Result<(u64, BaseAny)>> + 'table>,
+                > = Vec::new();
+                iters.push(Box::new(
+                    ::ruststep::tables::EntityTable::<BaseHolder>::owned_iter_with_id(self)
+                        .map(|owned| owned.map(|(id, owned)| (id, BaseAny::Base(Box::new(owned.into()))))),
+                ));
+                iters.push(Box::new(
+                    ::ruststep::tables::EntityTable::<SubAnyHolder>::owned_iter_with_id(self)
+                        .map(|owned| owned.map(|(id, owned)| (id, BaseAny::Sub(Box::new(owned.into()))))),
+                ));
+                ::ruststep::tables::merge_with_id(iters, move |entity_id| {
+                    ::ruststep::tables::EntityTable::<BaseAnyHolder>::get_owned(self, entity_id)
+                })
             }
         }
         "###);
@@ -430,10 +613,14 @@ mod snapshot_tests {
         impl ::ruststep::tables::IntoOwned for Sub1Holder {
             type Table = Tables;
             type Owned = Sub1;
-            fn into_owned(self, table: &Self::Table) -> ::ruststep::error::Result<Self::Owned> {
+            fn into_owned_in(
+                self,
+                table: &Self::Table,
+                resolution: &mut ::ruststep::tables::Resolution,
+            ) -> ::ruststep::error::Result<Self::Owned> {
                 let Sub1Holder { base, y1 } = self;
                 Ok(Sub1 {
-                    base: base.into_owned(table)?,
+                    base: base.into_owned_in(table, resolution)?,
                     y1: y1,
                 })
             }
@@ -446,17 +633,95 @@ mod snapshot_tests {
             fn attr_len() -> usize {
                 2usize
             }
+            fn supertypes() -> &'static [&'static str] {
+                &[]
+            }
         }
         #[automatically_derived]
         impl ::ruststep::tables::EntityTable<Sub1Holder> for Tables {
-            fn get_owned(&self, entity_id: u64) -> ::ruststep::error::Result<Sub1> {
-                ::ruststep::tables::get_owned(self, &self.sub1, entity_id)
+            fn get_owned_in(
+                &self,
+                entity_id: u64,
+                resolution: &mut ::ruststep::tables::Resolution,
+            ) -> ::ruststep::error::Result<Sub1> {
+                ::ruststep::tables::get_owned_in(self, &self.sub1, entity_id, resolution)
             }
             fn owned_iter<'table>(
                 &'table self,
             ) -> Box<dyn Iterator<Item = ::ruststep::error::Result<Sub1>> + 'table> {
                 ::ruststep::tables::owned_iter(self, &self.sub1)
             }
+            fn owned_iter_with_id<'table>(
+                &'table self,
+            ) -> Box<dyn Iterator<Item = ::ruststep::error::Result<(u64, Sub1)>> + 'table> {
+                ::ruststep::tables::owned_iter_with_id(self, &self.sub1)
+            }
+        }
+        #[doc = r" Auto-generated by `#[derive(Holder)]`"]
+        #[derive(Clone, Copy)]
+        pub struct Sub1View<'t> {
+            pub holder: &'t Sub1Holder,
+            pub table: &'t Tables,
+        }
+        #[automatically_derived]
+        impl<'t> ::std::fmt::Debug for Sub1View<'t> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.debug_tuple("Sub1View").field(self.holder).finish()
+            }
+        }
+        #[automatically_derived]
+        impl<'t> Sub1View<'t> {
+            pub fn base(&self) -> ::ruststep::tables::Ref<'t, BaseHolder> {
+                let holder = self.holder;
+                let table = self.table;
+                ::ruststep::tables::Ref::new(&holder.base, table)
+            }
+            pub fn y1(&self) -> &'t f64 {
+                let holder = self.holder;
+                &holder.y1
+            }
+        }
+        #[automatically_derived]
+        impl<'t> ::ruststep::tables::Viewable<'t> for Sub1Holder {
+            type View = Sub1View<'t>;
+            fn view(&'t self, table: &'t Self::Table) -> Self::View {
+                Sub1View {
+                    holder: self,
+                    table,
+                }
+            }
+            fn view_id(table: &'t Self::Table, entity_id: u64) -> ::ruststep::error::Result<Self::View> {
+                match table.sub1.get(&entity_id) {
+                    Some(holder) => Ok(Sub1View { holder, table }),
+                    None => Err(::ruststep::error::Error::UnknownEntity(entity_id)),
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::ruststep::serde::ser::Serialize for Sub1 {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::ruststep::serde::ser::Serializer,
+            {
+                use ruststep::serde::ser::SerializeStruct;
+                let mut st = serializer.serialize_struct("SUB_1", 2usize)?;
+                st.serialize_field("base", &self.base)?;
+                st.serialize_field("y1", &self.y1)?;
+                st.end()
+            }
+        }
+        #[automatically_derived]
+        impl ::ruststep::serde::ser::Serialize for Sub1Holder {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::ruststep::serde::ser::Serializer,
+            {
+                use ruststep::serde::ser::SerializeStruct;
+                let mut st = serializer.serialize_struct("SUB_1", 2usize)?;
+                st.serialize_field("base", &self.base)?;
+                st.serialize_field("y1", &self.y1)?;
+                st.end()
+            }
         }
         #[doc(hidden)]
         pub struct Sub1HolderVisitor;
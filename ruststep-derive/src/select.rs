@@ -2,7 +2,7 @@ use super::*;
 use inflector::Inflector;
 use proc_macro2::TokenStream as TokenStream2;
 use proc_macro_error::*;
-use quote::quote;
+use quote::{format_ident, quote};
 
 struct Input {
     name: String,
@@ -17,6 +17,26 @@ struct Input {
     holder_types: Vec<syn::Type>,
     holder_exprs: Vec<TokenStream2>,
     place_holders: Vec<bool>,
+    /// Variants for complex entity instances, e.g. `EmployeeAndStudent(Box<(Employee, Student)>)`,
+    /// sorted in descending order of the number of components
+    complex_variants: Vec<syn::Ident>,
+    /// Holders of the components of complex entity instances, e.g. `[EmployeeHolder, StudentHolder]`
+    complex_holders: Vec<Vec<syn::Type>>,
+}
+
+/// Holders of the components of a complex variant `Box<(A, B, ...)>`
+fn complex_holders(ty: &syn::Type) -> Vec<syn::Type> {
+    if let syn::Type::Path(path) = ty {
+        let last = path.path.segments.last().unwrap();
+        if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
+            if let (true, Some(syn::GenericArgument::Type(syn::Type::Tuple(tuple)))) =
+                (last.ident == "Box", args.args.first())
+            {
+                return tuple.elems.iter().map(as_holder_path).collect();
+            }
+        }
+    }
+    abort_call_site!("Complex entity must be `Box<(A, B, ...)>`")
 }
 
 impl Input {
@@ -24,11 +44,6 @@ impl Input {
         let name = ident.to_string().to_screaming_snake_case();
         let holder_ident = as_holder_ident(ident);
         let holder_visitor_ident = as_visitor_ident(&holder_ident);
-        let variants: Vec<syn::Ident> = e.variants.iter().map(|var| var.ident.clone()).collect();
-        let variant_names: Vec<_> = variants
-            .iter()
-            .map(|id| id.to_string().to_screaming_snake_case())
-            .collect();
         let table = attr
             .table
             .clone()
@@ -39,18 +54,31 @@ impl Input {
         let mut variant_exprs = Vec::new();
         let mut variant_into_exprs = Vec::new();
         let mut place_holders = Vec::new();
+        let mut variants = Vec::new();
+        let mut complex = Vec::new();
         for var in &e.variants {
-            let HolderAttr { place_holder, .. } = HolderAttr::parse(&var.attrs);
+            let HolderAttr {
+                place_holder,
+                complex: is_complex,
+                ..
+            } = HolderAttr::parse(&var.attrs);
+            assert_eq!(var.fields.len(), 1);
+            if is_complex {
+                let f = var.fields.iter().next().unwrap();
+                complex.push((var.ident.clone(), complex_holders(&f.ty)));
+                continue;
+            }
+            variants.push(var.ident.clone());
             place_holders.push(place_holder);
 
-            assert_eq!(var.fields.len(), 1);
             for f in &var.fields {
                 let ty = FieldType::try_from(f.ty.clone()).unwrap();
                 if let FieldType::Boxed(_) = ty {
                     if place_holder {
                         // ENTITY case
                         holder_types.push(as_holder_path(&f.ty));
-                        holder_exprs.push(quote! { Box::new(sub.into_owned(table)?) });
+                        holder_exprs
+                            .push(quote! { Box::new(sub.into_owned_in(table, resolution)?) });
                         variant_exprs.push(quote! { Box::new(owned) });
                         variant_into_exprs.push(quote! { Box::new(owned.into()) });
                     } else {
@@ -62,7 +90,7 @@ impl Input {
                     if place_holder {
                         // *Any case
                         holder_types.push(as_holder_path(&f.ty));
-                        holder_exprs.push(quote! { sub.into_owned(table)? });
+                        holder_exprs.push(quote! { sub.into_owned_in(table, resolution)? });
                     } else {
                         // SimpleType case
                         holder_types.push(f.ty.clone());
@@ -72,6 +100,14 @@ impl Input {
             }
         }
 
+        let variant_names: Vec<_> = variants
+            .iter()
+            .map(|id| id.to_string().to_screaming_snake_case())
+            .collect();
+        // Look up the complex entity with the most components first
+        complex.sort_by_key(|(_, holders)| std::cmp::Reverse(holders.len()));
+        let (complex_variants, complex_holders) = complex.into_iter().unzip();
+
         Input {
             name,
             table,
@@ -85,6 +121,8 @@ impl Input {
             holder_types,
             holder_exprs,
             place_holders,
+            complex_variants,
+            complex_holders,
         }
     }
 
@@ -119,7 +157,7 @@ impl Input {
             impl #ruststep::tables::IntoOwned for #holder_ident {
                 type Owned = #ident;
                 type Table = #table;
-                fn into_owned(self, table: &Self::Table) -> #ruststep::error::Result<Self::Owned> {
+                fn into_owned_in(self, table: &Self::Table, resolution: &mut #ruststep::tables::Resolution) -> #ruststep::error::Result<Self::Owned> {
                     Ok(match self {
                         #(#holder_ident::#variants(sub) => #ident::#variants(#holder_exprs)),*
                     })
@@ -210,6 +248,123 @@ impl Input {
         } // quote!
     }
 
+    // Generate for both owned enum and its holder,
+    // since they have same variants except for complex entities
+    fn impl_serialize(&self, ident: &syn::Ident, complex_variants: &[syn::Ident]) -> TokenStream2 {
+        let Input {
+            name,
+            variants,
+            variant_names,
+            ..
+        } = self;
+        let serde = serde_crate();
+        let indices = (0..variants.len() as u32).collect::<Vec<_>>();
+        quote! {
+            #[automatically_derived]
+            impl #serde::ser::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                where
+                    S: #serde::ser::Serializer,
+                {
+                    match self {
+                        #(
+                        #ident::#variants(sub) => serializer.serialize_newtype_variant(#name, #indices, #variant_names, sub),
+                        )*
+                        #(
+                        #ident::#complex_variants(_) => Err(<S::Error as #serde::ser::Error>::custom(
+                            "Complex entity instance cannot be serialized as a parameter"
+                        )),
+                        )*
+                    }
+                }
+            }
+        } // quote!
+    }
+
+    /// View enum whose variants are the views of the items, e.g. `SView<'t>` for `S`
+    fn def_view(&self) -> TokenStream2 {
+        let Input {
+            ident,
+            holder_ident,
+            variants,
+            holder_types,
+            table,
+            place_holders,
+            ..
+        } = self;
+        let view_ident = as_view_ident(ident);
+        let ruststep = ruststep_crate();
+
+        let mut complex_view_types = Vec::new();
+        let mut complex_view_ids = Vec::new();
+        for (var, holders) in self.complex_variants.iter().zip(&self.complex_holders) {
+            complex_view_types.push(quote! {
+                (#(<#holders as #ruststep::tables::Viewable<'t>>::View),*)
+            });
+            let views: Vec<_> = (0..holders.len())
+                .map(|i| format_ident!("view{}", i))
+                .collect();
+            complex_view_ids.push(quote! {
+                if let (#(Ok(#views),)*) = (#(<#holders as #ruststep::tables::Viewable<'t>>::view_id(table, entity_id),)*) {
+                    return Ok(#view_ident::#var((#(#views),*)));
+                }
+            });
+        }
+        let complex_variants = &self.complex_variants;
+
+        let mut view_types = Vec::new();
+        let mut view_exprs = Vec::new();
+        let mut vars = Vec::new();
+        let mut holders = Vec::<syn::Type>::new();
+        for ((var, holder), place_holder) in variants.iter().zip(holder_types).zip(place_holders) {
+            if *place_holder {
+                let (holder, sub) = match holder.clone().try_into().unwrap() {
+                    FieldType::Boxed(path) => {
+                        (path.as_ref().clone().into(), quote! { sub.as_ref() })
+                    }
+                    _ => (holder.clone(), quote! { sub }),
+                };
+                view_types.push(quote! { <#holder as #ruststep::tables::Viewable<'t>>::View });
+                view_exprs.push(quote! { #ruststep::tables::Viewable::view(#sub, table) });
+                vars.push(var);
+                holders.push(holder);
+            } else {
+                view_types.push(quote! { &'t #holder });
+                view_exprs.push(quote! { sub });
+            }
+        }
+
+        quote! {
+            /// Auto-generated by `#[derive(Holder)]`
+            #[derive(Debug, Clone)]
+            pub enum #view_ident<'t> {
+                #(#variants(#view_types),)*
+                #(#complex_variants(#complex_view_types),)*
+            }
+
+            #[automatically_derived]
+            impl<'t> #ruststep::tables::Viewable<'t> for #holder_ident {
+                type View = #view_ident<'t>;
+                #[allow(unused_variables)]
+                fn view(&'t self, table: &'t #table) -> Self::View {
+                    match self {
+                        #(#holder_ident::#variants(sub) => #view_ident::#variants(#view_exprs)),*
+                    }
+                }
+                #[allow(unused_variables)]
+                fn view_id(table: &'t #table, entity_id: u64) -> #ruststep::error::Result<Self::View> {
+                    #(#complex_view_ids)*
+                    #(
+                    if let Ok(view) = <#holders as #ruststep::tables::Viewable<'t>>::view_id(table, entity_id) {
+                        return Ok(#view_ident::#vars(view));
+                    }
+                    )*
+                    Err(#ruststep::error::Error::UnknownEntity(entity_id))
+                }
+            }
+        } // quote!
+    }
+
     fn impl_entity_table(&self) -> TokenStream2 {
         let Input {
             ident,
@@ -222,7 +377,6 @@ impl Input {
             ..
         } = self;
         let ruststep = ruststep_crate();
-        let itertools = itertools_crate();
         let mut vars = Vec::new();
         let mut holders = Vec::<syn::Type>::new();
         let mut exprs = Vec::new();
@@ -243,23 +397,50 @@ impl Input {
             }
         }
 
+        // Complex entity instance is stored in the tables of all of its components
+        let mut complex = Vec::new();
+        for (var, holders) in self.complex_variants.iter().zip(&self.complex_holders) {
+            complex.push(quote! {
+                if #(<#holders as #ruststep::tables::Viewable>::view_id(self, entity_id).is_ok())&&* {
+                    return Ok(#ident::#var(Box::new((
+                        #(#ruststep::tables::EntityTable::<#holders>::get_owned_in(self, entity_id, resolution)?),*
+                    ))));
+                }
+            });
+        }
+
         quote! {
             impl #ruststep::tables::EntityTable<#holder_ident> for #table {
-                fn get_owned(&self, entity_id: u64) -> #ruststep::error::Result<#ident> {
+                fn get_owned_in(&self, entity_id: u64, resolution: &mut #ruststep::tables::Resolution) -> #ruststep::error::Result<#ident> {
+                    #(#complex)*
+                    // Try next variant only if the entity is not in the table of this variant
                     #(
-                    if let Ok(owned) = #ruststep::tables::EntityTable::<#holders>::get_owned(self, entity_id) {
-                        return Ok(#ident::#vars(#exprs));
+                    match #ruststep::tables::EntityTable::<#holders>::get_owned_in(self, entity_id, resolution) {
+                        Ok(owned) => return Ok(#ident::#vars(#exprs)),
+                        Err(#ruststep::error::Error::UnknownEntity(id)) if id == entity_id => {}
+                        Err(e) => return Err(e),
                     }
                     )*
                     Err(#ruststep::error::Error::UnknownEntity(entity_id))
                 }
                 fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<#ident>> + 'table> {
-                    Box::new(#itertools::chain![
-                        #(
-                        #ruststep::tables::EntityTable::<#holders>::owned_iter(self)
-                            .map(|owned| owned.map(|owned| #ident::#vars(#exprs)))
-                        ),*
-                    ])
+                    Box::new(
+                        #ruststep::tables::EntityTable::<#holder_ident>::owned_iter_with_id(self)
+                            .map(|owned| owned.map(|(_id, owned)| owned))
+                    )
+                }
+                fn owned_iter_with_id<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<(u64, #ident)>> + 'table> {
+                    let mut iters: Vec<Box<dyn Iterator<Item = #ruststep::error::Result<(u64, #ident)>> + 'table>> = Vec::new();
+                    #(
+                    iters.push(Box::new(
+                        #ruststep::tables::EntityTable::<#holders>::owned_iter_with_id(self)
+                            .map(|owned| owned.map(|(id, owned)| (id, #ident::#vars(#exprs))))
+                    ));
+                    )*
+                    // Entity found in several tables is a complex entity instance, and looked up again
+                    #ruststep::tables::merge_with_id(iters, move |entity_id| {
+                        #ruststep::tables::EntityTable::<#holder_ident>::get_owned(self, entity_id)
+                    })
                 }
             }
         } // quote!
@@ -270,6 +451,9 @@ pub fn derive_holder(ident: &syn::Ident, e: &syn::DataEnum, attr: &HolderAttr) -
     let input = Input::parse(ident, e, attr);
     let def_holder_tt = input.def_holder();
     let impl_holder_tt = input.impl_holder();
+    let impl_serialize_tt = input.impl_serialize(&input.ident, &input.complex_variants);
+    let impl_holder_serialize_tt = input.impl_serialize(&input.holder_ident, &[]);
+    let def_view_tt = input.def_view();
 
     if attr.generate_deserialize {
         let impl_deserialize_tt = input.impl_deserialize();
@@ -278,6 +462,9 @@ pub fn derive_holder(ident: &syn::Ident, e: &syn::DataEnum, attr: &HolderAttr) -
         quote! {
             #def_holder_tt
             #impl_holder_tt
+            #impl_serialize_tt
+            #impl_holder_serialize_tt
+            #def_view_tt
             #impl_deserialize_tt
             #def_visitor_tt
             #impl_entity_table_tt
@@ -286,6 +473,9 @@ pub fn derive_holder(ident: &syn::Ident, e: &syn::DataEnum, attr: &HolderAttr) -
         quote! {
             #def_holder_tt
             #impl_holder_tt
+            #impl_serialize_tt
+            #impl_holder_serialize_tt
+            #def_view_tt
         } // quote!
     }
 }
@@ -17,71 +17,191 @@ pub fn derive_table_init(ast: &syn::DeriveInput) -> TokenStream2 {
     }
 }
 
+/// Holder type `T` of a table `HashMap<u64, T>`
+fn holder_type(ty: &syn::Type) -> &syn::Type {
+    if let syn::Type::Path(path) = ty {
+        if let Some(syn::PathArguments::AngleBracketed(args)) =
+            path.path.segments.last().map(|seg| &seg.arguments)
+        {
+            if let Some(syn::GenericArgument::Type(ty)) = args.args.last() {
+                return ty;
+            }
+        }
+    }
+    abort_call_site!("Table must be `HashMap<u64, T>`")
+}
+
 fn entity_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
     let mut table_names = Vec::new();
     let mut entity_names = Vec::new();
+    let mut holder_types = Vec::new();
     for field in &st.fields {
         let ident = field.ident.as_ref().expect_or_abort("unreachable!");
         let name = ident.to_string().to_screaming_snake_case();
         table_names.push(ident);
         entity_names.push(name);
+        holder_types.push(holder_type(&field.ty));
     }
     assert_eq!(table_names.len(), entity_names.len());
 
     let ruststep = ruststep_crate();
 
+    // Names of the direct supertypes of each entity, which does not depend on the table contents
+    let supertypes = quote! {
+        fn supertypes(name: &str) -> Option<&'static [&'static str]> {
+            match name {
+                #( #entity_names => Some(<#holder_types as #ruststep::tables::Holder>::supertypes()), )*
+                _ => None,
+            }
+        }
+    };
+
+    let insert_complex = quote! {
+        let records = #ruststep::tables::compose_complex(&subsuper, supertypes)?;
+        for record in &records {
+            match record.name.as_str() {
+                #(
+                #entity_names => insert_record(&mut self.#table_names, *id, record)?,
+                )*
+                _ => unreachable!(),
+            }
+        }
+    };
+
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::TableInit for #ident {
             fn append_data_section(
                 &mut self,
                 data_sec: &#ruststep::ast::DataSection
+            ) -> #ruststep::error::Result<()> {
+                for entity in &data_sec.entities {
+                    #ruststep::tables::TableInit::append_instance(self, entity)?;
+                }
+                Ok(())
+            }
+
+            fn append_instance(
+                &mut self,
+                entity: &#ruststep::ast::EntityInstance
             ) -> #ruststep::error::Result<()> {
                 use #ruststep::{error::Error, tables::insert_record, ast::EntityInstance};
+                #supertypes
+                match entity {
+                    EntityInstance::Simple { id, record } => match record.name.as_str() {
+                        #(
+                        #entity_names => insert_record(&mut self.#table_names, *id, record)?,
+                        )*
+                        _ => {
+                            return Err(Error::UnknownEntityName {
+                                entity_name: record.name.clone(),
+                                schema: "".to_string(),
+                            });
+                        }
+                    },
+                    EntityInstance::Complex { id, subsuper } => {
+                        #insert_complex
+                    }
+                }
+                Ok(())
+            }
+
+            fn append_borrowed_data_section(
+                &mut self,
+                data_sec: &#ruststep::ast::borrowed::DataSection
+            ) -> #ruststep::error::Result<()> {
                 for entity in &data_sec.entities {
-                    match entity {
-                        EntityInstance::Simple { id, record } => match record.name.as_str() {
-                            #(
-                            #entity_names => insert_record(&mut self.#table_names, *id, record)?,
-                            )*
-                            _ => {
-                                return Err(Error::UnknownEntityName {
-                                    entity_name: record.name.clone(),
-                                    schema: "".to_string(),
-                                });
-                            }
-                        },
-                        EntityInstance::Complex { .. } => {
-                            unimplemented!("Complex entity is not supported")
+                    #ruststep::tables::TableInit::append_borrowed_instance(self, entity)?;
+                }
+                Ok(())
+            }
+
+            fn append_borrowed_instance(
+                &mut self,
+                entity: &#ruststep::ast::borrowed::EntityInstance
+            ) -> #ruststep::error::Result<()> {
+                use #ruststep::{error::Error, tables::insert_record, ast::borrowed::EntityInstance};
+                #supertypes
+                match entity {
+                    EntityInstance::Simple { id, record } => match record.name {
+                        #(
+                        #entity_names => insert_record(&mut self.#table_names, *id, record)?,
+                        )*
+                        _ => {
+                            return Err(Error::UnknownEntityName {
+                                entity_name: record.name.to_string(),
+                                schema: "".to_string(),
+                            });
                         }
+                    },
+                    EntityInstance::Complex { id, subsuper } => {
+                        // Complex entities are rare, and composed records are owned anyway
+                        let subsuper: #ruststep::ast::SubSuperRecord = subsuper.into();
+                        #insert_complex
                     }
                 }
                 Ok(())
             }
         }
 
+        #[automatically_derived]
+        impl #ruststep::tables::ToDataSection for #ident {
+            fn append_to_builder(
+                &self,
+                builder: &mut #ruststep::ast::ser::DataSectionBuilder
+            ) -> #ruststep::error::Result<()> {
+                // Entities owned by other entities get ids larger than all of them
+                #( builder.reserve(self.#table_names.keys().copied()); )*
+
+                // An entity id stored in several tables is a complex entity instance
+                let mut count = ::std::collections::HashMap::<u64, usize>::new();
+                #(
+                for id in self.#table_names.keys() {
+                    *count.entry(*id).or_default() += 1;
+                }
+                )*
+                let mut complex = ::std::collections::BTreeMap::<u64, Vec<#ruststep::ast::Record>>::new();
+                #(
+                let mut ids: Vec<u64> = self.#table_names.keys().copied().collect();
+                ids.sort_unstable();
+                for id in ids {
+                    if count[&id] > 1 {
+                        let record = builder.serialize_record(&self.#table_names[&id])?;
+                        complex.entry(id).or_default().push(record);
+                    } else {
+                        builder.insert(id, &self.#table_names[&id])?;
+                    }
+                }
+                )*
+                #supertypes
+                for (id, records) in complex {
+                    let subsuper = #ruststep::tables::decompose_complex(records, supertypes)?;
+                    builder.insert_complex(id, subsuper)?;
+                }
+                Ok(())
+            }
+        }
+
         #[automatically_derived]
         impl ::std::str::FromStr for #ident {
             type Err = #ruststep::error::Error;
             fn from_str(input: &str) -> #ruststep::error::Result<Self> {
-                use #ruststep::{tables::TableInit, ast::DataSection};
-                let data_sec = DataSection::from_str(input)?;
-                Ok(Self::from_data_section(&data_sec)?)
+                use #ruststep::{tables::TableInit, ast::borrowed::DataSection};
+                let data_sec = DataSection::parse(input)?;
+                Ok(Self::from_borrowed_data_section(&data_sec)?)
             }
         }
     }
 }
 
 fn tuple_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
-    let mut table_names = Vec::new();
-    let mut entity_names = Vec::new();
-    for field in &st.fields {
-        let ty = &field.ty;
-        let ident = quote! { #ty }.to_string().to_screaming_snake_case();
-        table_names.push(ident.clone());
-        entity_names.push(ident);
+    // Entity names are not known from the field types, and looked up by `Holder::name`
+    let mut table_indices = Vec::new();
+    let mut holder_types = Vec::new();
+    for (index, field) in st.fields.iter().enumerate() {
+        table_indices.push(syn::Index::from(index));
+        holder_types.push(holder_type(&field.ty));
     }
-    assert_eq!(table_names.len(), entity_names.len());
 
     let ruststep = ruststep_crate();
 
@@ -92,26 +212,51 @@ fn tuple_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStrea
                 &mut self,
                 data_sec: &#ruststep::ast::DataSection
             ) -> #ruststep::error::Result<()> {
-                use #ruststep::{error::Error, tables::insert_record, ast::EntityInstance};
                 for entity in &data_sec.entities {
-                    match entity {
-                        EntityInstance::Simple { id, record } => match record.name.as_str() {
+                    #ruststep::tables::TableInit::append_instance(self, entity)?;
+                }
+                Ok(())
+            }
+
+            fn append_instance(
+                &mut self,
+                entity: &#ruststep::ast::EntityInstance
+            ) -> #ruststep::error::Result<()> {
+                use #ruststep::{error::Error, tables::{insert_record, Holder}, ast::EntityInstance};
+                fn supertypes(name: &str) -> Option<&'static [&'static str]> {
+                    #(
+                    if name == <#holder_types as Holder>::name() {
+                        return Some(<#holder_types as Holder>::supertypes());
+                    }
+                    )*
+                    None
+                }
+                match entity {
+                    EntityInstance::Simple { id, record } => {
+                        #(
+                        if record.name == <#holder_types as Holder>::name() {
+                            return insert_record(&mut self.#table_indices, *id, record);
+                        }
+                        )*
+                        Err(Error::UnknownEntityName {
+                            entity_name: record.name.clone(),
+                            schema: "".to_string(),
+                        })
+                    }
+                    EntityInstance::Complex { id, subsuper } => {
+                        let records = #ruststep::tables::compose_complex(subsuper, supertypes)?;
+                        'records: for record in &records {
                             #(
-                            #entity_names => insert_record(&mut self.#table_names, *id, record)?,
-                            )*
-                            _ => {
-                                return Err(Error::UnknownEntityName {
-                                    entity_name: record.name.clone(),
-                                    schema: "".to_string(),
-                                });
+                            if record.name == <#holder_types as Holder>::name() {
+                                insert_record(&mut self.#table_indices, *id, record)?;
+                                continue 'records;
                             }
-                        },
-                        EntityInstance::Complex { .. } => {
-                            unimplemented!("Complex entity is not supported")
+                            )*
+                            unreachable!()
                         }
+                        Ok(())
                     }
                 }
-                Ok(())
             }
         }
 
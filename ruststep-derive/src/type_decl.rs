@@ -11,6 +11,9 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
     let def_holder_tt = def_holder(ident, st);
     let impl_holder_tt = impl_holder(ident, attr, st);
     let impl_entity_table_tt = impl_entity_table(ident, attr);
+    let impl_view_tt = impl_view(ident, attr, st);
+    let impl_serialize_tt = impl_serialize(ident, &name);
+    let impl_holder_serialize_tt = impl_serialize(&holder_ident, &name);
     if attr.generate_deserialize {
         let def_visitor_tt = def_visitor(&holder_ident, &name, st);
         let impl_deserialize_tt = impl_deserialize(&holder_ident, &name, st);
@@ -19,6 +22,9 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #impl_view_tt
+            #impl_serialize_tt
+            #impl_holder_serialize_tt
             #def_visitor_tt
             #impl_deserialize_tt
             #impl_with_visitor_tt
@@ -28,6 +34,9 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #impl_view_tt
+            #impl_serialize_tt
+            #impl_holder_serialize_tt
         }
     }
 }
@@ -59,7 +68,7 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
         impl #ruststep::tables::IntoOwned for #holder_ident {
             type Table = #table;
             type Owned = #ident;
-            fn into_owned(self, #table_arg: &Self::Table) -> #ruststep::error::Result<Self::Owned> {
+            fn into_owned_in(self, #table_arg: &Self::Table, resolution: &mut #ruststep::tables::Resolution) -> #ruststep::error::Result<Self::Owned> {
                 Ok(#ident ( #(#into_owned),* ))
             }
         }
@@ -83,12 +92,53 @@ pub fn impl_entity_table(ident: &syn::Ident, table: &HolderAttr) -> TokenStream2
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::EntityTable<#holder_ident> for #table {
-            fn get_owned(&self, entity_id: u64) -> #ruststep::error::Result<#ident> {
-                #ruststep::tables::get_owned(self, &self.#field, entity_id)
+            fn get_owned_in(&self, entity_id: u64, resolution: &mut #ruststep::tables::Resolution) -> #ruststep::error::Result<#ident> {
+                #ruststep::tables::get_owned_in(self, &self.#field, entity_id, resolution)
             }
             fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<#ident>> + 'table> {
                 #ruststep::tables::owned_iter(self, &self.#field)
             }
+            fn owned_iter_with_id<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<(u64, #ident)>> + 'table> {
+                #ruststep::tables::owned_iter_with_id(self, &self.#field)
+            }
+        }
+    }
+}
+
+/// Defined type is viewed as its underlying type
+pub fn impl_view(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct) -> TokenStream2 {
+    let HolderAttr { field, .. } = table;
+    let holder_ident = as_holder_ident(ident);
+    let table_arg = table_arg();
+    let ruststep = ruststep_crate();
+
+    let underlying = match st.fields.iter().next() {
+        Some(underlying) if st.fields.len() == 1 => underlying,
+        _ => return TokenStream2::new(),
+    };
+    let ft: FieldType = underlying.ty.clone().try_into().unwrap();
+    let HolderAttr { place_holder, .. } = HolderAttr::parse(&underlying.attrs);
+    let (ty, expr) = if place_holder {
+        ft.as_holder().as_view(quote! { &self.0 }, &table_arg)
+    } else {
+        let ty: syn::Type = ft.into();
+        (quote! { &'t #ty }, quote! { &self.0 })
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl<'t> #ruststep::tables::Viewable<'t> for #holder_ident {
+            type View = #ty;
+            #[allow(unused_variables)]
+            fn view(&'t self, #table_arg: &'t Self::Table) -> Self::View {
+                #expr
+            }
+            fn view_id(#table_arg: &'t Self::Table, entity_id: u64) -> #ruststep::error::Result<Self::View> {
+                match #table_arg.#field.get(&entity_id) {
+                    Some(holder) => Ok(#ruststep::tables::Viewable::view(holder, #table_arg)),
+                    None => Err(#ruststep::error::Error::UnknownEntity(entity_id)),
+                }
+            }
         }
     }
 }
@@ -167,6 +217,26 @@ fn impl_deserialize(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> Tok
     } // quote!
 }
 
+// Defined type is serialized as a record with single parameter, e.g. `A('KORE')`,
+// which is accepted by the visitor defined above.
+fn impl_serialize(ident: &syn::Ident, name: &str) -> TokenStream2 {
+    let serde = serde_crate();
+    quote! {
+        #[automatically_derived]
+        impl #serde::ser::Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: #serde::ser::Serializer,
+            {
+                use #serde::ser::SerializeStruct;
+                let mut st = serializer.serialize_struct(#name, 1)?;
+                st.serialize_field("0", &self.0)?;
+                st.end()
+            }
+        }
+    } // quote!
+}
+
 fn impl_with_visitor(ident: &syn::Ident) -> TokenStream2 {
     let ruststep = ruststep_crate();
 
@@ -202,23 +272,12 @@ impl FieldEntries {
 
             let HolderAttr { place_holder, .. } = HolderAttr::parse(&field.attrs);
             if place_holder {
-                match &ft {
-                    FieldType::Path(_) => {
-                        into_owned.push(quote! { self.#index.into_owned(#table_arg)? });
-                    }
-                    FieldType::Optional(_) => {
-                        into_owned.push(quote! { self.#index.map(|holder| holder.into_owned(#table_arg)).transpose()? },);
-                    }
-                    FieldType::List(_) => into_owned.push(quote! {
-                        self.#index
-                            .into_iter()
-                            .map(|v| v.into_owned(#table_arg))
-                            .collect::<::std::result::Result<Vec<_>, _>>()?
-                    }),
-                    FieldType::Boxed(_) => abort_call_site!("Unexpected Box<T>"),
-                }
+                into_owned.push(ft.into_owned(quote! { self.#index }, &table_arg));
                 holder_types.push(ft.as_holder().as_place_holder().into());
             } else {
+                if let FieldType::Identified(_) = ft {
+                    abort_call_site!("Identified<T> requires #[holder(use_place_holder)]");
+                }
                 into_owned.push(quote! { self.#index });
                 holder_types.push(ft.into());
             }
@@ -1454,7 +1454,7 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         Axis2Placement2D(Box<Axis2Placement2D>),
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum BSplineCurveForm {
         EllipticArc,
         PolylineForm,
@@ -1618,7 +1618,7 @@ pub mod explicit_draughting {
     # [holder (field = dimension_count)]
     #[holder(generate_deserialize)]
     pub struct DimensionCount(pub i64);
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum DimensionExtentUsage {
         Origin,
         Target,
@@ -1750,7 +1750,7 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         PresentationRepresentation(PresentationRepresentationAny),
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum KnotType {
         UniformKnots,
         QuasiUniformKnots,
@@ -1804,7 +1804,7 @@ pub mod explicit_draughting {
     # [holder (field = month_in_year_number)]
     #[holder(generate_deserialize)]
     pub struct MonthInYearNumber(pub i64);
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum NullStyle {
         Null,
     }
@@ -1902,7 +1902,7 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ProductDefinitionShape(Box<ProductDefinitionShape>),
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum SiPrefix {
         Exa,
         Pico,
@@ -1921,7 +1921,7 @@ pub mod explicit_draughting {
         Kilo,
         Deca,
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum SiUnitName {
         Hertz,
         DegreeCelsius,
@@ -2025,21 +2025,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         TextLiteral(TextLiteralAny),
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum TextPath {
         Up,
         Right,
         Down,
         Left,
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum TransitionCode {
         Discontinuous,
         ContSameGradientSameCurvature,
         ContSameGradient,
         Continuous,
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum TrimmingPreference {
         Parameter,
         Unspecified,
@@ -2117,6 +2117,8 @@ pub mod explicit_draughting {
         OrganizationalAddress(Box<OrganizationalAddress>),
         #[holder(use_place_holder)]
         PersonalAddress(Box<PersonalAddress>),
+        #[holder(complex)]
+        OrganizationalAddressAndPersonalAddress(Box<(OrganizationalAddress, PersonalAddress)>),
     }
     impl Into<AddressAny> for Address {
         fn into(self) -> AddressAny {
@@ -2139,6 +2141,7 @@ pub mod explicit_draughting {
                 AddressAny::Address(x) => x.as_ref(),
                 AddressAny::OrganizationalAddress(x) => (**x).as_ref(),
                 AddressAny::PersonalAddress(x) => (**x).as_ref(),
+                AddressAny::OrganizationalAddressAndPersonalAddress(x) => x.0.as_ref(),
             }
         }
     }
@@ -2154,6 +2157,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
     #[derive(
@@ -2168,6 +2172,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -2182,6 +2187,16 @@ pub mod explicit_draughting {
         LeaderCurve(Box<LeaderCurve>),
         #[holder(use_place_holder)]
         ProjectionCurve(Box<ProjectionCurve>),
+        #[holder(complex)]
+        DimensionCurveAndLeaderCurve(Box<(DimensionCurve, LeaderCurve)>),
+        #[holder(complex)]
+        DimensionCurveAndProjectionCurve(Box<(DimensionCurve, ProjectionCurve)>),
+        #[holder(complex)]
+        LeaderCurveAndProjectionCurve(Box<(LeaderCurve, ProjectionCurve)>),
+        #[holder(complex)]
+        DimensionCurveAndLeaderCurveAndProjectionCurve(
+            Box<(DimensionCurve, LeaderCurve, ProjectionCurve)>,
+        ),
     }
     impl Into<AnnotationCurveOccurrenceAny> for AnnotationCurveOccurrence {
         fn into(self) -> AnnotationCurveOccurrenceAny {
@@ -2210,6 +2225,12 @@ pub mod explicit_draughting {
                 AnnotationCurveOccurrenceAny::DimensionCurve(x) => (**x).as_ref(),
                 AnnotationCurveOccurrenceAny::LeaderCurve(x) => (**x).as_ref(),
                 AnnotationCurveOccurrenceAny::ProjectionCurve(x) => (**x).as_ref(),
+                AnnotationCurveOccurrenceAny::DimensionCurveAndLeaderCurve(x) => x.0.as_ref(),
+                AnnotationCurveOccurrenceAny::DimensionCurveAndProjectionCurve(x) => x.0.as_ref(),
+                AnnotationCurveOccurrenceAny::LeaderCurveAndProjectionCurve(x) => x.0.as_ref(),
+                AnnotationCurveOccurrenceAny::DimensionCurveAndLeaderCurveAndProjectionCurve(x) => {
+                    x.0.as_ref()
+                }
             }
         }
     }
@@ -2228,6 +2249,18 @@ pub mod explicit_draughting {
                 AnnotationCurveOccurrenceAny::ProjectionCurve(x) => {
                     AsRef::<AnnotationCurveOccurrence>::as_ref(x.as_ref()).as_ref()
                 }
+                AnnotationCurveOccurrenceAny::DimensionCurveAndLeaderCurve(x) => {
+                    AsRef::<AnnotationCurveOccurrence>::as_ref(&x.0).as_ref()
+                }
+                AnnotationCurveOccurrenceAny::DimensionCurveAndProjectionCurve(x) => {
+                    AsRef::<AnnotationCurveOccurrence>::as_ref(&x.0).as_ref()
+                }
+                AnnotationCurveOccurrenceAny::LeaderCurveAndProjectionCurve(x) => {
+                    AsRef::<AnnotationCurveOccurrence>::as_ref(&x.0).as_ref()
+                }
+                AnnotationCurveOccurrenceAny::DimensionCurveAndLeaderCurveAndProjectionCurve(x) => {
+                    AsRef::<AnnotationCurveOccurrence>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -2243,6 +2276,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub boundaries: Vec<CurveAny>,
@@ -2259,6 +2293,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
         #[holder(use_place_holder)]
         pub fill_style_target: PointAny,
@@ -2275,6 +2310,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub styled_item: StyledItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -2293,6 +2329,25 @@ pub mod explicit_draughting {
         AnnotationTextOccurrence(Box<AnnotationTextOccurrence>),
         #[holder(use_place_holder)]
         DraughtingAnnotationOccurrence(Box<DraughtingAnnotationOccurrence>),
+        #[holder(complex)]
+        AnnotationCurveOccurrenceAndDraughtingAnnotationOccurrence(
+            Box<(AnnotationCurveOccurrenceAny, DraughtingAnnotationOccurrence)>,
+        ),
+        #[holder(complex)]
+        AnnotationFillAreaOccurrenceAndDraughtingAnnotationOccurrence(
+            Box<(AnnotationFillAreaOccurrence, DraughtingAnnotationOccurrence)>,
+        ),
+        #[holder(complex)]
+        AnnotationSymbolOccurrenceAndDraughtingAnnotationOccurrence(
+            Box<(
+                AnnotationSymbolOccurrenceAny,
+                DraughtingAnnotationOccurrence,
+            )>,
+        ),
+        #[holder(complex)]
+        AnnotationTextOccurrenceAndDraughtingAnnotationOccurrence(
+            Box<(AnnotationTextOccurrence, DraughtingAnnotationOccurrence)>,
+        ),
     }
     impl Into<AnnotationOccurrenceAny> for AnnotationOccurrence {
         fn into(self) -> AnnotationOccurrenceAny {
@@ -2326,38 +2381,12 @@ pub mod explicit_draughting {
     }
     impl AsRef<AnnotationOccurrence> for AnnotationOccurrenceAny {
         fn as_ref(&self) -> &AnnotationOccurrence {
-            match self {
-                AnnotationOccurrenceAny::AnnotationOccurrence(x) => x.as_ref(),
-                AnnotationOccurrenceAny::AnnotationCurveOccurrence(x) => (**x).as_ref(),
-                AnnotationOccurrenceAny::AnnotationFillAreaOccurrence(x) => (**x).as_ref(),
-                AnnotationOccurrenceAny::AnnotationSymbolOccurrence(x) => (**x).as_ref(),
-                AnnotationOccurrenceAny::AnnotationTextOccurrence(x) => (**x).as_ref(),
-                AnnotationOccurrenceAny::DraughtingAnnotationOccurrence(x) => (**x).as_ref(),
-            }
+            match self { AnnotationOccurrenceAny :: AnnotationOccurrence (x) => x . as_ref () , AnnotationOccurrenceAny :: AnnotationCurveOccurrence (x) => (* * x) . as_ref () , AnnotationOccurrenceAny :: AnnotationFillAreaOccurrence (x) => (* * x) . as_ref () , AnnotationOccurrenceAny :: AnnotationSymbolOccurrence (x) => (* * x) . as_ref () , AnnotationOccurrenceAny :: AnnotationTextOccurrence (x) => (* * x) . as_ref () , AnnotationOccurrenceAny :: DraughtingAnnotationOccurrence (x) => (* * x) . as_ref () , AnnotationOccurrenceAny :: AnnotationCurveOccurrenceAndDraughtingAnnotationOccurrence (x) => x . 0 . as_ref () , AnnotationOccurrenceAny :: AnnotationFillAreaOccurrenceAndDraughtingAnnotationOccurrence (x) => x . 0 . as_ref () , AnnotationOccurrenceAny :: AnnotationSymbolOccurrenceAndDraughtingAnnotationOccurrence (x) => x . 0 . as_ref () , AnnotationOccurrenceAny :: AnnotationTextOccurrenceAndDraughtingAnnotationOccurrence (x) => x . 0 . as_ref () , }
         }
     }
     impl AsRef<StyledItem> for AnnotationOccurrenceAny {
         fn as_ref(&self) -> &StyledItem {
-            match self {
-                AnnotationOccurrenceAny::AnnotationOccurrence(x) => {
-                    AsRef::<AnnotationOccurrence>::as_ref(x).as_ref()
-                }
-                AnnotationOccurrenceAny::AnnotationCurveOccurrence(x) => {
-                    AsRef::<AnnotationOccurrence>::as_ref(x.as_ref()).as_ref()
-                }
-                AnnotationOccurrenceAny::AnnotationFillAreaOccurrence(x) => {
-                    AsRef::<AnnotationOccurrence>::as_ref(x.as_ref()).as_ref()
-                }
-                AnnotationOccurrenceAny::AnnotationSymbolOccurrence(x) => {
-                    AsRef::<AnnotationOccurrence>::as_ref(x.as_ref()).as_ref()
-                }
-                AnnotationOccurrenceAny::AnnotationTextOccurrence(x) => {
-                    AsRef::<AnnotationOccurrence>::as_ref(x.as_ref()).as_ref()
-                }
-                AnnotationOccurrenceAny::DraughtingAnnotationOccurrence(x) => {
-                    AsRef::<AnnotationOccurrence>::as_ref(x.as_ref()).as_ref()
-                }
-            }
+            match self { AnnotationOccurrenceAny :: AnnotationOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (x) . as_ref () , AnnotationOccurrenceAny :: AnnotationCurveOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (x . as_ref ()) . as_ref () , AnnotationOccurrenceAny :: AnnotationFillAreaOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (x . as_ref ()) . as_ref () , AnnotationOccurrenceAny :: AnnotationSymbolOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (x . as_ref ()) . as_ref () , AnnotationOccurrenceAny :: AnnotationTextOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (x . as_ref ()) . as_ref () , AnnotationOccurrenceAny :: DraughtingAnnotationOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (x . as_ref ()) . as_ref () , AnnotationOccurrenceAny :: AnnotationCurveOccurrenceAndDraughtingAnnotationOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (& x . 0) . as_ref () , AnnotationOccurrenceAny :: AnnotationFillAreaOccurrenceAndDraughtingAnnotationOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (& x . 0) . as_ref () , AnnotationOccurrenceAny :: AnnotationSymbolOccurrenceAndDraughtingAnnotationOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (& x . 0) . as_ref () , AnnotationOccurrenceAny :: AnnotationTextOccurrenceAndDraughtingAnnotationOccurrence (x) => AsRef :: < AnnotationOccurrence > :: as_ref (& x . 0) . as_ref () , }
         }
     }
     #[derive(
@@ -2372,6 +2401,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_symbol_occurrence: AnnotationSymbolOccurrence,
     }
     #[derive(
@@ -2386,6 +2416,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub mapped_item: MappedItem,
     }
     #[derive(
@@ -2400,6 +2431,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -2412,6 +2444,10 @@ pub mod explicit_draughting {
         AnnotationSubfigureOccurrence(Box<AnnotationSubfigureOccurrence>),
         #[holder(use_place_holder)]
         TerminatorSymbol(Box<TerminatorSymbolAny>),
+        #[holder(complex)]
+        AnnotationSubfigureOccurrenceAndTerminatorSymbol(
+            Box<(AnnotationSubfigureOccurrence, TerminatorSymbolAny)>,
+        ),
     }
     impl Into<AnnotationSymbolOccurrenceAny> for AnnotationSymbolOccurrence {
         fn into(self) -> AnnotationSymbolOccurrenceAny {
@@ -2434,6 +2470,9 @@ pub mod explicit_draughting {
                 AnnotationSymbolOccurrenceAny::AnnotationSymbolOccurrence(x) => x.as_ref(),
                 AnnotationSymbolOccurrenceAny::AnnotationSubfigureOccurrence(x) => (**x).as_ref(),
                 AnnotationSymbolOccurrenceAny::TerminatorSymbol(x) => (**x).as_ref(),
+                AnnotationSymbolOccurrenceAny::AnnotationSubfigureOccurrenceAndTerminatorSymbol(
+                    x,
+                ) => x.0.as_ref(),
             }
         }
     }
@@ -2449,6 +2488,9 @@ pub mod explicit_draughting {
                 AnnotationSymbolOccurrenceAny::TerminatorSymbol(x) => {
                     AsRef::<AnnotationSymbolOccurrence>::as_ref(x.as_ref()).as_ref()
                 }
+                AnnotationSymbolOccurrenceAny::AnnotationSubfigureOccurrenceAndTerminatorSymbol(
+                    x,
+                ) => AsRef::<AnnotationSymbolOccurrence>::as_ref(&x.0).as_ref(),
             }
         }
     }
@@ -2464,6 +2506,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub mapped_item: MappedItem,
     }
     #[derive(
@@ -2478,6 +2521,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -2679,6 +2723,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub placement: Placement,
         #[holder(use_place_holder)]
         pub ref_direction: Option<Direction>,
@@ -2695,6 +2740,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         pub degree: i64,
         #[holder(use_place_holder)]
@@ -2719,6 +2765,16 @@ pub mod explicit_draughting {
         RationalBSplineCurve(Box<RationalBSplineCurve>),
         #[holder(use_place_holder)]
         UniformCurve(Box<UniformCurve>),
+        #[holder(complex)]
+        BSplineCurveWithKnotsAndRationalBSplineCurve(
+            Box<(BSplineCurveWithKnots, RationalBSplineCurve)>,
+        ),
+        #[holder(complex)]
+        BezierCurveAndRationalBSplineCurve(Box<(BezierCurve, RationalBSplineCurve)>),
+        #[holder(complex)]
+        QuasiUniformCurveAndRationalBSplineCurve(Box<(QuasiUniformCurve, RationalBSplineCurve)>),
+        #[holder(complex)]
+        RationalBSplineCurveAndUniformCurve(Box<(RationalBSplineCurve, UniformCurve)>),
     }
     impl Into<BSplineCurveAny> for BSplineCurve {
         fn into(self) -> BSplineCurveAny {
@@ -2759,6 +2815,10 @@ pub mod explicit_draughting {
                 BSplineCurveAny::QuasiUniformCurve(x) => (**x).as_ref(),
                 BSplineCurveAny::RationalBSplineCurve(x) => (**x).as_ref(),
                 BSplineCurveAny::UniformCurve(x) => (**x).as_ref(),
+                BSplineCurveAny::BSplineCurveWithKnotsAndRationalBSplineCurve(x) => x.0.as_ref(),
+                BSplineCurveAny::BezierCurveAndRationalBSplineCurve(x) => x.0.as_ref(),
+                BSplineCurveAny::QuasiUniformCurveAndRationalBSplineCurve(x) => x.0.as_ref(),
+                BSplineCurveAny::RationalBSplineCurveAndUniformCurve(x) => x.0.as_ref(),
             }
         }
     }
@@ -2781,6 +2841,18 @@ pub mod explicit_draughting {
                 BSplineCurveAny::UniformCurve(x) => {
                     AsRef::<BSplineCurve>::as_ref(x.as_ref()).as_ref()
                 }
+                BSplineCurveAny::BSplineCurveWithKnotsAndRationalBSplineCurve(x) => {
+                    AsRef::<BSplineCurve>::as_ref(&x.0).as_ref()
+                }
+                BSplineCurveAny::BezierCurveAndRationalBSplineCurve(x) => {
+                    AsRef::<BSplineCurve>::as_ref(&x.0).as_ref()
+                }
+                BSplineCurveAny::QuasiUniformCurveAndRationalBSplineCurve(x) => {
+                    AsRef::<BSplineCurve>::as_ref(&x.0).as_ref()
+                }
+                BSplineCurveAny::RationalBSplineCurveAndUniformCurve(x) => {
+                    AsRef::<BSplineCurve>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -2796,6 +2868,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
         pub knot_multiplicities: Vec<i64>,
         #[holder(use_place_holder)]
@@ -2814,6 +2887,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
     #[derive(
@@ -2828,6 +2902,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -2910,6 +2985,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date: Date,
         #[holder(use_place_holder)]
         pub day_component: DayInMonthNumber,
@@ -2928,6 +3004,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub mapped_item: MappedItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -2979,6 +3056,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub camera_image: CameraImage,
     }
     #[derive(
@@ -2993,6 +3071,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3044,6 +3123,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub camera_model: CameraModel,
         #[holder(use_place_holder)]
         pub view_window: PlanarBox,
@@ -3061,6 +3141,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_map: RepresentationMap,
     }
     #[derive(
@@ -3075,6 +3156,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub coordinates: Vec<LengthMeasure>,
@@ -3091,6 +3173,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
@@ -3110,6 +3193,8 @@ pub mod explicit_draughting {
         ColourSpecification(Box<ColourSpecificationAny>),
         #[holder(use_place_holder)]
         PreDefinedColour(Box<PreDefinedColourAny>),
+        #[holder(complex)]
+        ColourSpecificationAndPreDefinedColour(Box<(ColourSpecificationAny, PreDefinedColourAny)>),
     }
     impl Into<ColourAny> for Colour {
         fn into(self) -> ColourAny {
@@ -3132,6 +3217,7 @@ pub mod explicit_draughting {
                 ColourAny::Colour(x) => x.as_ref(),
                 ColourAny::ColourSpecification(x) => (**x).as_ref(),
                 ColourAny::PreDefinedColour(x) => (**x).as_ref(),
+                ColourAny::ColourSpecificationAndPreDefinedColour(x) => x.0.as_ref(),
             }
         }
     }
@@ -3147,6 +3233,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub colour_specification: ColourSpecification,
         pub red: f64,
         pub green: f64,
@@ -3164,6 +3251,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub colour: Colour,
         #[holder(use_place_holder)]
         pub name: ColourAny,
@@ -3219,6 +3307,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub segments: Vec<CompositeCurveSegment>,
@@ -3246,6 +3335,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub collected_text: Vec<TextOrCharacter>,
@@ -3262,6 +3352,29 @@ pub mod explicit_draughting {
         CompositeTextWithBlankingBox(Box<CompositeTextWithBlankingBox>),
         #[holder(use_place_holder)]
         CompositeTextWithExtent(Box<CompositeTextWithExtent>),
+        #[holder(complex)]
+        CompositeTextWithAssociatedCurvesAndCompositeTextWithBlankingBox(
+            Box<(
+                CompositeTextWithAssociatedCurves,
+                CompositeTextWithBlankingBox,
+            )>,
+        ),
+        #[holder(complex)]
+        CompositeTextWithAssociatedCurvesAndCompositeTextWithExtent(
+            Box<(CompositeTextWithAssociatedCurves, CompositeTextWithExtent)>,
+        ),
+        #[holder(complex)]
+        CompositeTextWithBlankingBoxAndCompositeTextWithExtent(
+            Box<(CompositeTextWithBlankingBox, CompositeTextWithExtent)>,
+        ),
+        #[holder(complex)]
+        CompositeTextWithAssociatedCurvesAndCompositeTextWithBlankingBoxAndCompositeTextWithExtent(
+            Box<(
+                CompositeTextWithAssociatedCurves,
+                CompositeTextWithBlankingBox,
+                CompositeTextWithExtent,
+            )>,
+        ),
     }
     impl Into<CompositeTextAny> for CompositeText {
         fn into(self) -> CompositeTextAny {
@@ -3285,28 +3398,12 @@ pub mod explicit_draughting {
     }
     impl AsRef<CompositeText> for CompositeTextAny {
         fn as_ref(&self) -> &CompositeText {
-            match self {
-                CompositeTextAny::CompositeText(x) => x.as_ref(),
-                CompositeTextAny::CompositeTextWithAssociatedCurves(x) => (**x).as_ref(),
-                CompositeTextAny::CompositeTextWithBlankingBox(x) => (**x).as_ref(),
-                CompositeTextAny::CompositeTextWithExtent(x) => (**x).as_ref(),
-            }
+            match self { CompositeTextAny :: CompositeText (x) => x . as_ref () , CompositeTextAny :: CompositeTextWithAssociatedCurves (x) => (* * x) . as_ref () , CompositeTextAny :: CompositeTextWithBlankingBox (x) => (* * x) . as_ref () , CompositeTextAny :: CompositeTextWithExtent (x) => (* * x) . as_ref () , CompositeTextAny :: CompositeTextWithAssociatedCurvesAndCompositeTextWithBlankingBox (x) => x . 0 . as_ref () , CompositeTextAny :: CompositeTextWithAssociatedCurvesAndCompositeTextWithExtent (x) => x . 0 . as_ref () , CompositeTextAny :: CompositeTextWithBlankingBoxAndCompositeTextWithExtent (x) => x . 0 . as_ref () , CompositeTextAny :: CompositeTextWithAssociatedCurvesAndCompositeTextWithBlankingBoxAndCompositeTextWithExtent (x) => x . 0 . as_ref () , }
         }
     }
     impl AsRef<GeometricRepresentationItem> for CompositeTextAny {
         fn as_ref(&self) -> &GeometricRepresentationItem {
-            match self {
-                CompositeTextAny::CompositeText(x) => AsRef::<CompositeText>::as_ref(x).as_ref(),
-                CompositeTextAny::CompositeTextWithAssociatedCurves(x) => {
-                    AsRef::<CompositeText>::as_ref(x.as_ref()).as_ref()
-                }
-                CompositeTextAny::CompositeTextWithBlankingBox(x) => {
-                    AsRef::<CompositeText>::as_ref(x.as_ref()).as_ref()
-                }
-                CompositeTextAny::CompositeTextWithExtent(x) => {
-                    AsRef::<CompositeText>::as_ref(x.as_ref()).as_ref()
-                }
-            }
+            match self { CompositeTextAny :: CompositeText (x) => AsRef :: < CompositeText > :: as_ref (x) . as_ref () , CompositeTextAny :: CompositeTextWithAssociatedCurves (x) => AsRef :: < CompositeText > :: as_ref (x . as_ref ()) . as_ref () , CompositeTextAny :: CompositeTextWithBlankingBox (x) => AsRef :: < CompositeText > :: as_ref (x . as_ref ()) . as_ref () , CompositeTextAny :: CompositeTextWithExtent (x) => AsRef :: < CompositeText > :: as_ref (x . as_ref ()) . as_ref () , CompositeTextAny :: CompositeTextWithAssociatedCurvesAndCompositeTextWithBlankingBox (x) => AsRef :: < CompositeText > :: as_ref (& x . 0) . as_ref () , CompositeTextAny :: CompositeTextWithAssociatedCurvesAndCompositeTextWithExtent (x) => AsRef :: < CompositeText > :: as_ref (& x . 0) . as_ref () , CompositeTextAny :: CompositeTextWithBlankingBoxAndCompositeTextWithExtent (x) => AsRef :: < CompositeText > :: as_ref (& x . 0) . as_ref () , CompositeTextAny :: CompositeTextWithAssociatedCurvesAndCompositeTextWithBlankingBoxAndCompositeTextWithExtent (x) => AsRef :: < CompositeText > :: as_ref (& x . 0) . as_ref () , }
         }
     }
     #[derive(
@@ -3321,6 +3418,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_text: CompositeText,
         #[holder(use_place_holder)]
         pub associated_curves: Vec<CurveAny>,
@@ -3337,6 +3435,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_text: CompositeText,
         #[holder(use_place_holder)]
         pub blanking: PlanarBox,
@@ -3353,6 +3452,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_text: CompositeText,
         #[holder(use_place_holder)]
         pub extent: PlanarExtentAny,
@@ -3369,6 +3469,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub position: Axis2Placement,
@@ -3447,6 +3548,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub invisibility: Invisibility,
         #[holder(use_place_holder)]
         pub presentation_context: InvisibilityContext,
@@ -3518,6 +3620,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         #[holder(use_place_holder)]
         pub name: Label,
@@ -3536,6 +3639,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3552,6 +3656,12 @@ pub mod explicit_draughting {
         Line(Box<Line>),
         #[holder(use_place_holder)]
         OffsetCurve2D(Box<OffsetCurve2D>),
+        #[holder(complex)]
+        BoundedCurveAndConic(Box<(BoundedCurveAny, ConicAny)>),
+        #[holder(complex)]
+        BoundedCurveAndLine(Box<(BoundedCurveAny, Line)>),
+        #[holder(complex)]
+        BoundedCurveAndOffsetCurve2D(Box<(BoundedCurveAny, OffsetCurve2D)>),
     }
     impl Into<CurveAny> for Curve {
         fn into(self) -> CurveAny {
@@ -3586,6 +3696,9 @@ pub mod explicit_draughting {
                 CurveAny::Conic(x) => (**x).as_ref(),
                 CurveAny::Line(x) => (**x).as_ref(),
                 CurveAny::OffsetCurve2D(x) => (**x).as_ref(),
+                CurveAny::BoundedCurveAndConic(x) => x.0.as_ref(),
+                CurveAny::BoundedCurveAndLine(x) => x.0.as_ref(),
+                CurveAny::BoundedCurveAndOffsetCurve2D(x) => x.0.as_ref(),
             }
         }
     }
@@ -3597,6 +3710,9 @@ pub mod explicit_draughting {
                 CurveAny::Conic(x) => AsRef::<Curve>::as_ref(x.as_ref()).as_ref(),
                 CurveAny::Line(x) => AsRef::<Curve>::as_ref(x.as_ref()).as_ref(),
                 CurveAny::OffsetCurve2D(x) => AsRef::<Curve>::as_ref(x.as_ref()).as_ref(),
+                CurveAny::BoundedCurveAndConic(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
+                CurveAny::BoundedCurveAndLine(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
+                CurveAny::BoundedCurveAndOffsetCurve2D(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
             }
         }
     }
@@ -3612,6 +3728,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -3695,6 +3812,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(
@@ -3709,6 +3827,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(
@@ -3723,6 +3842,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub definition: DefinedSymbolSelect,
@@ -3741,6 +3861,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
     #[derive(
@@ -3755,6 +3876,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
     #[derive(
@@ -3769,6 +3891,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
     #[derive(
@@ -3783,6 +3906,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
     #[derive(
@@ -3797,6 +3921,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3815,6 +3940,121 @@ pub mod explicit_draughting {
         LinearDimension(Box<LinearDimension>),
         #[holder(use_place_holder)]
         RadiusDimension(Box<RadiusDimension>),
+        #[holder(complex)]
+        AngularDimensionAndCurveDimension(Box<(AngularDimension, CurveDimension)>),
+        #[holder(complex)]
+        AngularDimensionAndDiameterDimension(Box<(AngularDimension, DiameterDimension)>),
+        #[holder(complex)]
+        AngularDimensionAndLinearDimension(Box<(AngularDimension, LinearDimension)>),
+        #[holder(complex)]
+        AngularDimensionAndRadiusDimension(Box<(AngularDimension, RadiusDimension)>),
+        #[holder(complex)]
+        CurveDimensionAndDiameterDimension(Box<(CurveDimension, DiameterDimension)>),
+        #[holder(complex)]
+        CurveDimensionAndLinearDimension(Box<(CurveDimension, LinearDimension)>),
+        #[holder(complex)]
+        CurveDimensionAndRadiusDimension(Box<(CurveDimension, RadiusDimension)>),
+        #[holder(complex)]
+        DiameterDimensionAndLinearDimension(Box<(DiameterDimension, LinearDimension)>),
+        #[holder(complex)]
+        DiameterDimensionAndRadiusDimension(Box<(DiameterDimension, RadiusDimension)>),
+        #[holder(complex)]
+        LinearDimensionAndRadiusDimension(Box<(LinearDimension, RadiusDimension)>),
+        #[holder(complex)]
+        AngularDimensionAndCurveDimensionAndDiameterDimension(
+            Box<(AngularDimension, CurveDimension, DiameterDimension)>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndCurveDimensionAndLinearDimension(
+            Box<(AngularDimension, CurveDimension, LinearDimension)>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndCurveDimensionAndRadiusDimension(
+            Box<(AngularDimension, CurveDimension, RadiusDimension)>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndDiameterDimensionAndLinearDimension(
+            Box<(AngularDimension, DiameterDimension, LinearDimension)>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndDiameterDimensionAndRadiusDimension(
+            Box<(AngularDimension, DiameterDimension, RadiusDimension)>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndLinearDimensionAndRadiusDimension(
+            Box<(AngularDimension, LinearDimension, RadiusDimension)>,
+        ),
+        #[holder(complex)]
+        CurveDimensionAndDiameterDimensionAndLinearDimension(
+            Box<(CurveDimension, DiameterDimension, LinearDimension)>,
+        ),
+        #[holder(complex)]
+        CurveDimensionAndDiameterDimensionAndRadiusDimension(
+            Box<(CurveDimension, DiameterDimension, RadiusDimension)>,
+        ),
+        #[holder(complex)]
+        CurveDimensionAndLinearDimensionAndRadiusDimension(
+            Box<(CurveDimension, LinearDimension, RadiusDimension)>,
+        ),
+        #[holder(complex)]
+        DiameterDimensionAndLinearDimensionAndRadiusDimension(
+            Box<(DiameterDimension, LinearDimension, RadiusDimension)>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndCurveDimensionAndDiameterDimensionAndLinearDimension(
+            Box<(
+                AngularDimension,
+                CurveDimension,
+                DiameterDimension,
+                LinearDimension,
+            )>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndCurveDimensionAndDiameterDimensionAndRadiusDimension(
+            Box<(
+                AngularDimension,
+                CurveDimension,
+                DiameterDimension,
+                RadiusDimension,
+            )>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndCurveDimensionAndLinearDimensionAndRadiusDimension(
+            Box<(
+                AngularDimension,
+                CurveDimension,
+                LinearDimension,
+                RadiusDimension,
+            )>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension(
+            Box<(
+                AngularDimension,
+                DiameterDimension,
+                LinearDimension,
+                RadiusDimension,
+            )>,
+        ),
+        #[holder(complex)]
+        CurveDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension(
+            Box<(
+                CurveDimension,
+                DiameterDimension,
+                LinearDimension,
+                RadiusDimension,
+            )>,
+        ),
+        #[holder(complex)]
+        AngularDimensionAndCurveDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension(
+            Box<(
+                AngularDimension,
+                CurveDimension,
+                DiameterDimension,
+                LinearDimension,
+                RadiusDimension,
+            )>,
+        ),
     }
     impl Into<DimensionCurveDirectedCalloutAny> for DimensionCurveDirectedCallout {
         fn into(self) -> DimensionCurveDirectedCalloutAny {
@@ -3848,38 +4088,12 @@ pub mod explicit_draughting {
     }
     impl AsRef<DimensionCurveDirectedCallout> for DimensionCurveDirectedCalloutAny {
         fn as_ref(&self) -> &DimensionCurveDirectedCallout {
-            match self {
-                DimensionCurveDirectedCalloutAny::DimensionCurveDirectedCallout(x) => x.as_ref(),
-                DimensionCurveDirectedCalloutAny::AngularDimension(x) => (**x).as_ref(),
-                DimensionCurveDirectedCalloutAny::CurveDimension(x) => (**x).as_ref(),
-                DimensionCurveDirectedCalloutAny::DiameterDimension(x) => (**x).as_ref(),
-                DimensionCurveDirectedCalloutAny::LinearDimension(x) => (**x).as_ref(),
-                DimensionCurveDirectedCalloutAny::RadiusDimension(x) => (**x).as_ref(),
-            }
+            match self { DimensionCurveDirectedCalloutAny :: DimensionCurveDirectedCallout (x) => x . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimension (x) => (* * x) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimension (x) => (* * x) . as_ref () , DimensionCurveDirectedCalloutAny :: DiameterDimension (x) => (* * x) . as_ref () , DimensionCurveDirectedCalloutAny :: LinearDimension (x) => (* * x) . as_ref () , DimensionCurveDirectedCalloutAny :: RadiusDimension (x) => (* * x) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndDiameterDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndLinearDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndDiameterDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndLinearDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: DiameterDimensionAndLinearDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: DiameterDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: LinearDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndDiameterDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndLinearDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndDiameterDimensionAndLinearDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndDiameterDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndLinearDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndDiameterDimensionAndLinearDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndDiameterDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndLinearDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: DiameterDimensionAndLinearDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndDiameterDimensionAndLinearDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndDiameterDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndLinearDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension (x) => x . 0 . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension (x) => x . 0 . as_ref () , }
         }
     }
     impl AsRef<DraughtingCallout> for DimensionCurveDirectedCalloutAny {
         fn as_ref(&self) -> &DraughtingCallout {
-            match self {
-                DimensionCurveDirectedCalloutAny::DimensionCurveDirectedCallout(x) => {
-                    AsRef::<DimensionCurveDirectedCallout>::as_ref(x).as_ref()
-                }
-                DimensionCurveDirectedCalloutAny::AngularDimension(x) => {
-                    AsRef::<DimensionCurveDirectedCallout>::as_ref(x.as_ref()).as_ref()
-                }
-                DimensionCurveDirectedCalloutAny::CurveDimension(x) => {
-                    AsRef::<DimensionCurveDirectedCallout>::as_ref(x.as_ref()).as_ref()
-                }
-                DimensionCurveDirectedCalloutAny::DiameterDimension(x) => {
-                    AsRef::<DimensionCurveDirectedCallout>::as_ref(x.as_ref()).as_ref()
-                }
-                DimensionCurveDirectedCalloutAny::LinearDimension(x) => {
-                    AsRef::<DimensionCurveDirectedCallout>::as_ref(x.as_ref()).as_ref()
-                }
-                DimensionCurveDirectedCalloutAny::RadiusDimension(x) => {
-                    AsRef::<DimensionCurveDirectedCallout>::as_ref(x.as_ref()).as_ref()
-                }
-            }
+            match self { DimensionCurveDirectedCalloutAny :: DimensionCurveDirectedCallout (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (x) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (x . as_ref ()) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (x . as_ref ()) . as_ref () , DimensionCurveDirectedCalloutAny :: DiameterDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (x . as_ref ()) . as_ref () , DimensionCurveDirectedCalloutAny :: LinearDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (x . as_ref ()) . as_ref () , DimensionCurveDirectedCalloutAny :: RadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (x . as_ref ()) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndDiameterDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndLinearDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndDiameterDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndLinearDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: DiameterDimensionAndLinearDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: DiameterDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: LinearDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndDiameterDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndLinearDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndDiameterDimensionAndLinearDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndDiameterDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndLinearDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndDiameterDimensionAndLinearDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndDiameterDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndLinearDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: DiameterDimensionAndLinearDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndDiameterDimensionAndLinearDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndDiameterDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndLinearDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: CurveDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , DimensionCurveDirectedCalloutAny :: AngularDimensionAndCurveDimensionAndDiameterDimensionAndLinearDimensionAndRadiusDimension (x) => AsRef :: < DimensionCurveDirectedCallout > :: as_ref (& x . 0) . as_ref () , }
         }
     }
     #[derive(
@@ -3894,6 +4108,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub terminator_symbol: TerminatorSymbol,
         pub role: DimensionExtentUsage,
     }
@@ -3909,6 +4124,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -3936,6 +4152,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         pub direction_ratios: Vec<f64>,
     }
@@ -4010,6 +4227,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
     #[derive(
@@ -4024,6 +4242,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub approval_assignment: ApprovalAssignment,
         #[holder(use_place_holder)]
         pub approved_items: Vec<ApprovedItem>,
@@ -4040,6 +4259,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub contents: Vec<DraughtingCalloutElement>,
@@ -4186,6 +4406,29 @@ pub mod explicit_draughting {
         DimensionCalloutRelationship(Box<DimensionCalloutRelationship>),
         #[holder(use_place_holder)]
         DimensionPair(Box<DimensionPair>),
+        #[holder(complex)]
+        DimensionCalloutComponentRelationshipAndDimensionCalloutRelationship(
+            Box<(
+                DimensionCalloutComponentRelationship,
+                DimensionCalloutRelationship,
+            )>,
+        ),
+        #[holder(complex)]
+        DimensionCalloutComponentRelationshipAndDimensionPair(
+            Box<(DimensionCalloutComponentRelationship, DimensionPair)>,
+        ),
+        #[holder(complex)]
+        DimensionCalloutRelationshipAndDimensionPair(
+            Box<(DimensionCalloutRelationship, DimensionPair)>,
+        ),
+        #[holder(complex)]
+        DimensionCalloutComponentRelationshipAndDimensionCalloutRelationshipAndDimensionPair(
+            Box<(
+                DimensionCalloutComponentRelationship,
+                DimensionCalloutRelationship,
+                DimensionPair,
+            )>,
+        ),
     }
     impl Into<DraughtingCalloutRelationshipAny> for DraughtingCalloutRelationship {
         fn into(self) -> DraughtingCalloutRelationshipAny {
@@ -4211,14 +4454,7 @@ pub mod explicit_draughting {
     }
     impl AsRef<DraughtingCalloutRelationship> for DraughtingCalloutRelationshipAny {
         fn as_ref(&self) -> &DraughtingCalloutRelationship {
-            match self {
-                DraughtingCalloutRelationshipAny::DraughtingCalloutRelationship(x) => x.as_ref(),
-                DraughtingCalloutRelationshipAny::DimensionCalloutComponentRelationship(x) => {
-                    (**x).as_ref()
-                }
-                DraughtingCalloutRelationshipAny::DimensionCalloutRelationship(x) => (**x).as_ref(),
-                DraughtingCalloutRelationshipAny::DimensionPair(x) => (**x).as_ref(),
-            }
+            match self { DraughtingCalloutRelationshipAny :: DraughtingCalloutRelationship (x) => x . as_ref () , DraughtingCalloutRelationshipAny :: DimensionCalloutComponentRelationship (x) => (* * x) . as_ref () , DraughtingCalloutRelationshipAny :: DimensionCalloutRelationship (x) => (* * x) . as_ref () , DraughtingCalloutRelationshipAny :: DimensionPair (x) => (* * x) . as_ref () , DraughtingCalloutRelationshipAny :: DimensionCalloutComponentRelationshipAndDimensionCalloutRelationship (x) => x . 0 . as_ref () , DraughtingCalloutRelationshipAny :: DimensionCalloutComponentRelationshipAndDimensionPair (x) => x . 0 . as_ref () , DraughtingCalloutRelationshipAny :: DimensionCalloutRelationshipAndDimensionPair (x) => x . 0 . as_ref () , DraughtingCalloutRelationshipAny :: DimensionCalloutComponentRelationshipAndDimensionCalloutRelationshipAndDimensionPair (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(
@@ -4233,6 +4469,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub contract_assignment: ContractAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ContractedItem>,
@@ -4249,6 +4486,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub drawing_revision: DrawingRevision,
     }
     #[derive(
@@ -4263,6 +4501,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(
@@ -4277,6 +4516,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub group_assignment: GroupAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<DraughtingGroupedItem>,
@@ -4293,6 +4533,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(
@@ -4307,6 +4548,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub organization_assignment: OrganizationAssignment,
         #[holder(use_place_holder)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
@@ -4323,6 +4565,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub person_and_organization_assignment: PersonAndOrganizationAssignment,
         #[holder(use_place_holder)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
@@ -4339,6 +4582,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub person_assignment: PersonAssignment,
         #[holder(use_place_holder)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
@@ -4355,6 +4599,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_colour: PreDefinedColour,
     }
     #[derive(
@@ -4369,6 +4614,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_curve_font: PreDefinedCurveFont,
     }
     #[derive(
@@ -4383,6 +4629,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_text_font: PreDefinedTextFont,
     }
     #[derive(
@@ -4397,6 +4644,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presented_item: PresentedItem,
         #[holder(use_place_holder)]
         pub items: Vec<DraughtingPresentedItemSelect>,
@@ -4413,6 +4661,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub security_classification_assignment: SecurityClassificationAssignment,
         #[holder(use_place_holder)]
         pub assigned_items: Vec<ClassifiedItem>,
@@ -4429,6 +4678,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub document_reference: DocumentReference,
         #[holder(use_place_holder)]
         pub specified_items: Vec<SpecifiedItem>,
@@ -4445,6 +4695,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub symbol_representation: SymbolRepresentation,
     }
     #[derive(
@@ -4459,6 +4710,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub symbol_representation: SymbolRepresentation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -4512,6 +4764,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal_with_delineation: TextLiteralWithDelineation,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -4548,6 +4801,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_set: PresentationSet,
         #[holder(use_place_holder)]
         pub revision_identifier: Identifier,
@@ -4607,6 +4861,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_symbol_representation: DraughtingSymbolRepresentation,
     }
     #[derive(
@@ -4621,6 +4876,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_area: PresentationArea,
         #[holder(use_place_holder)]
         pub revision_identifier: Identifier,
@@ -4637,6 +4893,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub area_in_set: AreaInSet,
         #[holder(use_place_holder)]
         pub sheet_number: Identifier,
@@ -4653,6 +4910,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub semi_axis_1: PositiveLengthMeasure,
@@ -4679,6 +4937,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
@@ -4689,10 +4948,12 @@ pub mod explicit_draughting {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -4721,6 +4982,181 @@ pub mod explicit_draughting {
         ExternallyDefinedTextFont(Box<ExternallyDefinedTextFont>),
         #[holder(use_place_holder)]
         ExternallyDefinedTileStyle(Box<ExternallyDefinedTileStyle>),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedHatchStyle(
+            Box<(ExternallyDefinedCurveFont, ExternallyDefinedHatchStyle)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedSymbol(
+            Box<(ExternallyDefinedCurveFont, ExternallyDefinedSymbol)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedTextFont(
+            Box<(ExternallyDefinedCurveFont, ExternallyDefinedTextFont)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedTileStyle(
+            Box<(ExternallyDefinedCurveFont, ExternallyDefinedTileStyle)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedHatchStyleAndExternallyDefinedSymbol(
+            Box<(ExternallyDefinedHatchStyle, ExternallyDefinedSymbol)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedHatchStyleAndExternallyDefinedTextFont(
+            Box<(ExternallyDefinedHatchStyle, ExternallyDefinedTextFont)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedHatchStyleAndExternallyDefinedTileStyle(
+            Box<(ExternallyDefinedHatchStyle, ExternallyDefinedTileStyle)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedSymbolAndExternallyDefinedTextFont(
+            Box<(ExternallyDefinedSymbol, ExternallyDefinedTextFont)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedSymbolAndExternallyDefinedTileStyle(
+            Box<(ExternallyDefinedSymbol, ExternallyDefinedTileStyle)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedTextFontAndExternallyDefinedTileStyle(
+            Box<(ExternallyDefinedTextFont, ExternallyDefinedTileStyle)>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedSymbol(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedSymbol,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedTextFont(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedTextFont,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedSymbolAndExternallyDefinedTextFont(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTextFont,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedSymbolAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedTextFontAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedTextFont,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTextFont(
+            Box<(
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTextFont,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedHatchStyleAndExternallyDefinedTextFontAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedTextFont,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedSymbolAndExternallyDefinedTextFontAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTextFont,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTextFont(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTextFont,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedTextFontAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedTextFont,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedSymbolAndExternallyDefinedTextFontAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTextFont,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTextFontAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTextFont,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
+        #[holder(complex)]
+        ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTextFontAndExternallyDefinedTileStyle(
+            Box<(
+                ExternallyDefinedCurveFont,
+                ExternallyDefinedHatchStyle,
+                ExternallyDefinedSymbol,
+                ExternallyDefinedTextFont,
+                ExternallyDefinedTileStyle,
+            )>,
+        ),
     }
     impl Into<ExternallyDefinedItemAny> for ExternallyDefinedItem {
         fn into(self) -> ExternallyDefinedItemAny {
@@ -4754,14 +5190,7 @@ pub mod explicit_draughting {
     }
     impl AsRef<ExternallyDefinedItem> for ExternallyDefinedItemAny {
         fn as_ref(&self) -> &ExternallyDefinedItem {
-            match self {
-                ExternallyDefinedItemAny::ExternallyDefinedItem(x) => x.as_ref(),
-                ExternallyDefinedItemAny::ExternallyDefinedCurveFont(x) => (**x).as_ref(),
-                ExternallyDefinedItemAny::ExternallyDefinedHatchStyle(x) => (**x).as_ref(),
-                ExternallyDefinedItemAny::ExternallyDefinedSymbol(x) => (**x).as_ref(),
-                ExternallyDefinedItemAny::ExternallyDefinedTextFont(x) => (**x).as_ref(),
-                ExternallyDefinedItemAny::ExternallyDefinedTileStyle(x) => (**x).as_ref(),
-            }
+            match self { ExternallyDefinedItemAny :: ExternallyDefinedItem (x) => x . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFont (x) => (* * x) . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedHatchStyle (x) => (* * x) . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedSymbol (x) => (* * x) . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedTextFont (x) => (* * x) . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedTileStyle (x) => (* * x) . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedHatchStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedSymbol (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedTextFont (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedHatchStyleAndExternallyDefinedSymbol (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedHatchStyleAndExternallyDefinedTextFont (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedHatchStyleAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedSymbolAndExternallyDefinedTextFont (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedSymbolAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedTextFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedSymbol (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedTextFont (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedSymbolAndExternallyDefinedTextFont (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedSymbolAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedTextFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTextFont (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedHatchStyleAndExternallyDefinedTextFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedSymbolAndExternallyDefinedTextFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTextFont (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedTextFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedSymbolAndExternallyDefinedTextFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTextFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , ExternallyDefinedItemAny :: ExternallyDefinedCurveFontAndExternallyDefinedHatchStyleAndExternallyDefinedSymbolAndExternallyDefinedTextFontAndExternallyDefinedTileStyle (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(
@@ -4776,6 +5205,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
     #[derive(
@@ -4790,6 +5220,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
@@ -4800,10 +5231,12 @@ pub mod explicit_draughting {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -4838,6 +5271,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub hatch_line_appearance: CurveStyle,
@@ -4862,6 +5296,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub symbol: AnnotationSymbolOccurrenceAny,
@@ -4878,6 +5313,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub tiling_pattern: TwoDirectionRepeatFactor,
@@ -4898,6 +5334,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_set: GeometricSet,
     }
     #[derive(
@@ -4912,6 +5349,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub coordinate_space_dimension: DimensionCount,
@@ -4928,6 +5366,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -5192,6 +5631,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub elements: Vec<GeometricSetSelect>,
@@ -5245,6 +5685,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(
@@ -5259,6 +5700,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
     #[derive(
@@ -5273,6 +5715,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub units: Vec<Unit>,
@@ -5348,6 +5791,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub semi_axis: PositiveLengthMeasure,
@@ -5401,6 +5845,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
     #[derive(
@@ -5415,6 +5860,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -5468,6 +5914,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub leader_directed_callout: LeaderDirectedCallout,
     }
     #[derive(
@@ -5482,6 +5929,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub terminator_symbol: TerminatorSymbol,
     }
     #[derive(
@@ -5496,6 +5944,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
     #[derive(
@@ -5510,6 +5959,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
     #[derive(
@@ -5524,6 +5974,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub pnt: CartesianPoint,
@@ -5542,6 +5993,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
     #[derive(
@@ -5556,6 +6008,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
         #[holder(use_place_holder)]
         pub mapping_source: RepresentationMapAny,
@@ -5574,6 +6027,16 @@ pub mod explicit_draughting {
         AnnotationText(Box<AnnotationText>),
         #[holder(use_place_holder)]
         CameraImage(Box<CameraImageAny>),
+        #[holder(complex)]
+        AnnotationSymbolAndAnnotationText(Box<(AnnotationSymbol, AnnotationText)>),
+        #[holder(complex)]
+        AnnotationSymbolAndCameraImage(Box<(AnnotationSymbol, CameraImageAny)>),
+        #[holder(complex)]
+        AnnotationTextAndCameraImage(Box<(AnnotationText, CameraImageAny)>),
+        #[holder(complex)]
+        AnnotationSymbolAndAnnotationTextAndCameraImage(
+            Box<(AnnotationSymbol, AnnotationText, CameraImageAny)>,
+        ),
     }
     impl Into<MappedItemAny> for MappedItem {
         fn into(self) -> MappedItemAny {
@@ -5602,6 +6065,10 @@ pub mod explicit_draughting {
                 MappedItemAny::AnnotationSymbol(x) => (**x).as_ref(),
                 MappedItemAny::AnnotationText(x) => (**x).as_ref(),
                 MappedItemAny::CameraImage(x) => (**x).as_ref(),
+                MappedItemAny::AnnotationSymbolAndAnnotationText(x) => x.0.as_ref(),
+                MappedItemAny::AnnotationSymbolAndCameraImage(x) => x.0.as_ref(),
+                MappedItemAny::AnnotationTextAndCameraImage(x) => x.0.as_ref(),
+                MappedItemAny::AnnotationSymbolAndAnnotationTextAndCameraImage(x) => x.0.as_ref(),
             }
         }
     }
@@ -5616,6 +6083,18 @@ pub mod explicit_draughting {
                     AsRef::<MappedItem>::as_ref(x.as_ref()).as_ref()
                 }
                 MappedItemAny::CameraImage(x) => AsRef::<MappedItem>::as_ref(x.as_ref()).as_ref(),
+                MappedItemAny::AnnotationSymbolAndAnnotationText(x) => {
+                    AsRef::<MappedItem>::as_ref(&x.0).as_ref()
+                }
+                MappedItemAny::AnnotationSymbolAndCameraImage(x) => {
+                    AsRef::<MappedItem>::as_ref(&x.0).as_ref()
+                }
+                MappedItemAny::AnnotationTextAndCameraImage(x) => {
+                    AsRef::<MappedItem>::as_ref(&x.0).as_ref()
+                }
+                MappedItemAny::AnnotationSymbolAndAnnotationTextAndCameraImage(x) => {
+                    AsRef::<MappedItem>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -5686,6 +6165,14 @@ pub mod explicit_draughting {
         PlaneAngleUnit(Box<PlaneAngleUnit>),
         #[holder(use_place_holder)]
         SiUnit(Box<SiUnit>),
+        #[holder(complex)]
+        ConversionBasedUnitAndLengthUnit(Box<(ConversionBasedUnit, LengthUnit)>),
+        #[holder(complex)]
+        ConversionBasedUnitAndPlaneAngleUnit(Box<(ConversionBasedUnit, PlaneAngleUnit)>),
+        #[holder(complex)]
+        LengthUnitAndSiUnit(Box<(LengthUnit, SiUnit)>),
+        #[holder(complex)]
+        PlaneAngleUnitAndSiUnit(Box<(PlaneAngleUnit, SiUnit)>),
     }
     impl Into<NamedUnitAny> for NamedUnit {
         fn into(self) -> NamedUnitAny {
@@ -5720,6 +6207,10 @@ pub mod explicit_draughting {
                 NamedUnitAny::LengthUnit(x) => (**x).as_ref(),
                 NamedUnitAny::PlaneAngleUnit(x) => (**x).as_ref(),
                 NamedUnitAny::SiUnit(x) => (**x).as_ref(),
+                NamedUnitAny::ConversionBasedUnitAndLengthUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ConversionBasedUnitAndPlaneAngleUnit(x) => x.0.as_ref(),
+                NamedUnitAny::LengthUnitAndSiUnit(x) => x.0.as_ref(),
+                NamedUnitAny::PlaneAngleUnitAndSiUnit(x) => x.0.as_ref(),
             }
         }
     }
@@ -5735,6 +6226,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -5754,6 +6246,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub repeat_factor: Vector,
@@ -5809,6 +6302,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub projection_directed_callout: ProjectionDirectedCallout,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -5880,6 +6374,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub address: Address,
         #[holder(use_place_holder)]
         pub organizations: Vec<Organization>,
@@ -5898,6 +6393,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub focal_dist: LengthMeasure,
@@ -6038,6 +6534,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub address: Address,
         #[holder(use_place_holder)]
         pub people: Vec<Person>,
@@ -6056,6 +6553,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub location: CartesianPoint,
@@ -6109,6 +6607,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub planar_extent: PlanarExtent,
         #[holder(use_place_holder)]
         pub placement: Axis2Placement,
@@ -6125,6 +6624,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub size_in_x: LengthMeasure,
@@ -6178,6 +6678,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
     #[derive(
@@ -6192,6 +6693,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
     #[derive(
@@ -6206,6 +6708,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6264,6 +6767,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -6282,6 +6786,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub points: Vec<CartesianPoint>,
@@ -6294,10 +6799,12 @@ pub mod explicit_draughting {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_item: PreDefinedItem,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub colour: Colour,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6363,6 +6870,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_item: PreDefinedItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6416,6 +6924,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
     #[derive(
@@ -6430,6 +6939,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -6454,6 +6964,61 @@ pub mod explicit_draughting {
         PreDefinedSymbol(Box<PreDefinedSymbolAny>),
         #[holder(use_place_holder)]
         PreDefinedTextFont(Box<PreDefinedTextFontAny>),
+        #[holder(complex)]
+        PreDefinedColourAndPreDefinedCurveFont(Box<(PreDefinedColourAny, PreDefinedCurveFontAny)>),
+        #[holder(complex)]
+        PreDefinedColourAndPreDefinedSymbol(Box<(PreDefinedColourAny, PreDefinedSymbolAny)>),
+        #[holder(complex)]
+        PreDefinedColourAndPreDefinedTextFont(Box<(PreDefinedColourAny, PreDefinedTextFontAny)>),
+        #[holder(complex)]
+        PreDefinedCurveFontAndPreDefinedSymbol(Box<(PreDefinedCurveFontAny, PreDefinedSymbolAny)>),
+        #[holder(complex)]
+        PreDefinedCurveFontAndPreDefinedTextFont(
+            Box<(PreDefinedCurveFontAny, PreDefinedTextFontAny)>,
+        ),
+        #[holder(complex)]
+        PreDefinedSymbolAndPreDefinedTextFont(Box<(PreDefinedSymbolAny, PreDefinedTextFontAny)>),
+        #[holder(complex)]
+        PreDefinedColourAndPreDefinedCurveFontAndPreDefinedSymbol(
+            Box<(
+                PreDefinedColourAny,
+                PreDefinedCurveFontAny,
+                PreDefinedSymbolAny,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedColourAndPreDefinedCurveFontAndPreDefinedTextFont(
+            Box<(
+                PreDefinedColourAny,
+                PreDefinedCurveFontAny,
+                PreDefinedTextFontAny,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedColourAndPreDefinedSymbolAndPreDefinedTextFont(
+            Box<(
+                PreDefinedColourAny,
+                PreDefinedSymbolAny,
+                PreDefinedTextFontAny,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedCurveFontAndPreDefinedSymbolAndPreDefinedTextFont(
+            Box<(
+                PreDefinedCurveFontAny,
+                PreDefinedSymbolAny,
+                PreDefinedTextFontAny,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedColourAndPreDefinedCurveFontAndPreDefinedSymbolAndPreDefinedTextFont(
+            Box<(
+                PreDefinedColourAny,
+                PreDefinedCurveFontAny,
+                PreDefinedSymbolAny,
+                PreDefinedTextFontAny,
+            )>,
+        ),
     }
     impl Into<PreDefinedItemAny> for PreDefinedItem {
         fn into(self) -> PreDefinedItemAny {
@@ -6482,13 +7047,7 @@ pub mod explicit_draughting {
     }
     impl AsRef<PreDefinedItem> for PreDefinedItemAny {
         fn as_ref(&self) -> &PreDefinedItem {
-            match self {
-                PreDefinedItemAny::PreDefinedItem(x) => x.as_ref(),
-                PreDefinedItemAny::PreDefinedColour(x) => (**x).as_ref(),
-                PreDefinedItemAny::PreDefinedCurveFont(x) => (**x).as_ref(),
-                PreDefinedItemAny::PreDefinedSymbol(x) => (**x).as_ref(),
-                PreDefinedItemAny::PreDefinedTextFont(x) => (**x).as_ref(),
-            }
+            match self { PreDefinedItemAny :: PreDefinedItem (x) => x . as_ref () , PreDefinedItemAny :: PreDefinedColour (x) => (* * x) . as_ref () , PreDefinedItemAny :: PreDefinedCurveFont (x) => (* * x) . as_ref () , PreDefinedItemAny :: PreDefinedSymbol (x) => (* * x) . as_ref () , PreDefinedItemAny :: PreDefinedTextFont (x) => (* * x) . as_ref () , PreDefinedItemAny :: PreDefinedColourAndPreDefinedCurveFont (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedColourAndPreDefinedSymbol (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedColourAndPreDefinedTextFont (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedCurveFontAndPreDefinedSymbol (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedCurveFontAndPreDefinedTextFont (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedSymbolAndPreDefinedTextFont (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedColourAndPreDefinedCurveFontAndPreDefinedSymbol (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedColourAndPreDefinedCurveFontAndPreDefinedTextFont (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedColourAndPreDefinedSymbolAndPreDefinedTextFont (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedCurveFontAndPreDefinedSymbolAndPreDefinedTextFont (x) => x . 0 . as_ref () , PreDefinedItemAny :: PreDefinedColourAndPreDefinedCurveFontAndPreDefinedSymbolAndPreDefinedTextFont (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(
@@ -6503,6 +7062,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
     #[derive(
@@ -6517,6 +7077,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_item: PreDefinedItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6533,6 +7094,80 @@ pub mod explicit_draughting {
         PreDefinedPointMarkerSymbol(Box<PreDefinedPointMarkerSymbol>),
         #[holder(use_place_holder)]
         PreDefinedTerminatorSymbol(Box<PreDefinedTerminatorSymbol>),
+        #[holder(complex)]
+        PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbol(
+            Box<(
+                PreDefinedDimensionSymbol,
+                PreDefinedGeometricalToleranceSymbol,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedDimensionSymbolAndPreDefinedPointMarkerSymbol(
+            Box<(PreDefinedDimensionSymbol, PreDefinedPointMarkerSymbol)>,
+        ),
+        #[holder(complex)]
+        PreDefinedDimensionSymbolAndPreDefinedTerminatorSymbol(
+            Box<(PreDefinedDimensionSymbol, PreDefinedTerminatorSymbol)>,
+        ),
+        #[holder(complex)]
+        PreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbol(
+            Box<(
+                PreDefinedGeometricalToleranceSymbol,
+                PreDefinedPointMarkerSymbol,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedGeometricalToleranceSymbolAndPreDefinedTerminatorSymbol(
+            Box<(
+                PreDefinedGeometricalToleranceSymbol,
+                PreDefinedTerminatorSymbol,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol(
+            Box<(PreDefinedPointMarkerSymbol, PreDefinedTerminatorSymbol)>,
+        ),
+        #[holder(complex)]
+        PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbol(
+            Box<(
+                PreDefinedDimensionSymbol,
+                PreDefinedGeometricalToleranceSymbol,
+                PreDefinedPointMarkerSymbol,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedTerminatorSymbol(
+            Box<(
+                PreDefinedDimensionSymbol,
+                PreDefinedGeometricalToleranceSymbol,
+                PreDefinedTerminatorSymbol,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedDimensionSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol(
+            Box<(
+                PreDefinedDimensionSymbol,
+                PreDefinedPointMarkerSymbol,
+                PreDefinedTerminatorSymbol,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol(
+            Box<(
+                PreDefinedGeometricalToleranceSymbol,
+                PreDefinedPointMarkerSymbol,
+                PreDefinedTerminatorSymbol,
+            )>,
+        ),
+        #[holder(complex)]
+        PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol(
+            Box<(
+                PreDefinedDimensionSymbol,
+                PreDefinedGeometricalToleranceSymbol,
+                PreDefinedPointMarkerSymbol,
+                PreDefinedTerminatorSymbol,
+            )>,
+        ),
     }
     impl Into<PreDefinedSymbolAny> for PreDefinedSymbol {
         fn into(self) -> PreDefinedSymbolAny {
@@ -6561,34 +7196,12 @@ pub mod explicit_draughting {
     }
     impl AsRef<PreDefinedSymbol> for PreDefinedSymbolAny {
         fn as_ref(&self) -> &PreDefinedSymbol {
-            match self {
-                PreDefinedSymbolAny::PreDefinedSymbol(x) => x.as_ref(),
-                PreDefinedSymbolAny::PreDefinedDimensionSymbol(x) => (**x).as_ref(),
-                PreDefinedSymbolAny::PreDefinedGeometricalToleranceSymbol(x) => (**x).as_ref(),
-                PreDefinedSymbolAny::PreDefinedPointMarkerSymbol(x) => (**x).as_ref(),
-                PreDefinedSymbolAny::PreDefinedTerminatorSymbol(x) => (**x).as_ref(),
-            }
+            match self { PreDefinedSymbolAny :: PreDefinedSymbol (x) => x . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbol (x) => (* * x) . as_ref () , PreDefinedSymbolAny :: PreDefinedGeometricalToleranceSymbol (x) => (* * x) . as_ref () , PreDefinedSymbolAny :: PreDefinedPointMarkerSymbol (x) => (* * x) . as_ref () , PreDefinedSymbolAny :: PreDefinedTerminatorSymbol (x) => (* * x) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedPointMarkerSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedTerminatorSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedGeometricalToleranceSymbolAndPreDefinedTerminatorSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedTerminatorSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol (x) => x . 0 . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol (x) => x . 0 . as_ref () , }
         }
     }
     impl AsRef<PreDefinedItem> for PreDefinedSymbolAny {
         fn as_ref(&self) -> &PreDefinedItem {
-            match self {
-                PreDefinedSymbolAny::PreDefinedSymbol(x) => {
-                    AsRef::<PreDefinedSymbol>::as_ref(x).as_ref()
-                }
-                PreDefinedSymbolAny::PreDefinedDimensionSymbol(x) => {
-                    AsRef::<PreDefinedSymbol>::as_ref(x.as_ref()).as_ref()
-                }
-                PreDefinedSymbolAny::PreDefinedGeometricalToleranceSymbol(x) => {
-                    AsRef::<PreDefinedSymbol>::as_ref(x.as_ref()).as_ref()
-                }
-                PreDefinedSymbolAny::PreDefinedPointMarkerSymbol(x) => {
-                    AsRef::<PreDefinedSymbol>::as_ref(x.as_ref()).as_ref()
-                }
-                PreDefinedSymbolAny::PreDefinedTerminatorSymbol(x) => {
-                    AsRef::<PreDefinedSymbol>::as_ref(x.as_ref()).as_ref()
-                }
-            }
+            match self { PreDefinedSymbolAny :: PreDefinedSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (x) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (x . as_ref ()) . as_ref () , PreDefinedSymbolAny :: PreDefinedGeometricalToleranceSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (x . as_ref ()) . as_ref () , PreDefinedSymbolAny :: PreDefinedPointMarkerSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (x . as_ref ()) . as_ref () , PreDefinedSymbolAny :: PreDefinedTerminatorSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (x . as_ref ()) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedPointMarkerSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedTerminatorSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedGeometricalToleranceSymbolAndPreDefinedTerminatorSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedTerminatorSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , PreDefinedSymbolAny :: PreDefinedDimensionSymbolAndPreDefinedGeometricalToleranceSymbolAndPreDefinedPointMarkerSymbolAndPreDefinedTerminatorSymbol (x) => AsRef :: < PreDefinedSymbol > :: as_ref (& x . 0) . as_ref () , }
         }
     }
     #[derive(
@@ -6603,6 +7216,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
     #[derive(
@@ -6617,6 +7231,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_item: PreDefinedItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6670,6 +7285,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_representation: PresentationRepresentation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6745,6 +7361,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6757,6 +7374,8 @@ pub mod explicit_draughting {
         PresentationArea(Box<PresentationAreaAny>),
         #[holder(use_place_holder)]
         PresentationView(Box<PresentationView>),
+        #[holder(complex)]
+        PresentationAreaAndPresentationView(Box<(PresentationAreaAny, PresentationView)>),
     }
     impl Into<PresentationRepresentationAny> for PresentationRepresentation {
         fn into(self) -> PresentationRepresentationAny {
@@ -6779,6 +7398,9 @@ pub mod explicit_draughting {
                 PresentationRepresentationAny::PresentationRepresentation(x) => x.as_ref(),
                 PresentationRepresentationAny::PresentationArea(x) => (**x).as_ref(),
                 PresentationRepresentationAny::PresentationView(x) => (**x).as_ref(),
+                PresentationRepresentationAny::PresentationAreaAndPresentationView(x) => {
+                    x.0.as_ref()
+                }
             }
         }
     }
@@ -6794,6 +7416,9 @@ pub mod explicit_draughting {
                 PresentationRepresentationAny::PresentationView(x) => {
                     AsRef::<PresentationRepresentation>::as_ref(x.as_ref()).as_ref()
                 }
+                PresentationRepresentationAny::PresentationAreaAndPresentationView(x) => {
+                    AsRef::<PresentationRepresentation>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -6886,6 +7511,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_style_assignment: PresentationStyleAssignment,
         #[holder(use_place_holder)]
         pub style_context: StyleContextSelect,
@@ -6902,6 +7528,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_representation: PresentationRepresentation,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -6972,6 +7599,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub discipline_type: Label,
@@ -7002,6 +7630,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub life_cycle_stage: Label,
@@ -7030,6 +7659,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub property_definition: PropertyDefinition,
     }
     #[derive(
@@ -7044,6 +7674,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
     #[derive(
@@ -7058,6 +7689,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -7193,6 +7825,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
     #[derive(
@@ -7207,6 +7840,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
     #[derive(
@@ -7221,6 +7855,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
         pub weights_data: Vec<f64>,
     }
@@ -7250,6 +7885,67 @@ pub mod explicit_draughting {
         ShapeRepresentation(Box<ShapeRepresentationAny>),
         #[holder(use_place_holder)]
         SymbolRepresentation(Box<SymbolRepresentationAny>),
+        #[holder(complex)]
+        DraughtingModelAndPresentationRepresentation(
+            Box<(DraughtingModel, PresentationRepresentationAny)>,
+        ),
+        #[holder(complex)]
+        DraughtingModelAndShapeRepresentation(Box<(DraughtingModel, ShapeRepresentationAny)>),
+        #[holder(complex)]
+        DraughtingModelAndSymbolRepresentation(Box<(DraughtingModel, SymbolRepresentationAny)>),
+        #[holder(complex)]
+        PresentationRepresentationAndShapeRepresentation(
+            Box<(PresentationRepresentationAny, ShapeRepresentationAny)>,
+        ),
+        #[holder(complex)]
+        PresentationRepresentationAndSymbolRepresentation(
+            Box<(PresentationRepresentationAny, SymbolRepresentationAny)>,
+        ),
+        #[holder(complex)]
+        ShapeRepresentationAndSymbolRepresentation(
+            Box<(ShapeRepresentationAny, SymbolRepresentationAny)>,
+        ),
+        #[holder(complex)]
+        DraughtingModelAndPresentationRepresentationAndShapeRepresentation(
+            Box<(
+                DraughtingModel,
+                PresentationRepresentationAny,
+                ShapeRepresentationAny,
+            )>,
+        ),
+        #[holder(complex)]
+        DraughtingModelAndPresentationRepresentationAndSymbolRepresentation(
+            Box<(
+                DraughtingModel,
+                PresentationRepresentationAny,
+                SymbolRepresentationAny,
+            )>,
+        ),
+        #[holder(complex)]
+        DraughtingModelAndShapeRepresentationAndSymbolRepresentation(
+            Box<(
+                DraughtingModel,
+                ShapeRepresentationAny,
+                SymbolRepresentationAny,
+            )>,
+        ),
+        #[holder(complex)]
+        PresentationRepresentationAndShapeRepresentationAndSymbolRepresentation(
+            Box<(
+                PresentationRepresentationAny,
+                ShapeRepresentationAny,
+                SymbolRepresentationAny,
+            )>,
+        ),
+        #[holder(complex)]
+        DraughtingModelAndPresentationRepresentationAndShapeRepresentationAndSymbolRepresentation(
+            Box<(
+                DraughtingModel,
+                PresentationRepresentationAny,
+                ShapeRepresentationAny,
+                SymbolRepresentationAny,
+            )>,
+        ),
     }
     impl Into<RepresentationAny> for Representation {
         fn into(self) -> RepresentationAny {
@@ -7278,13 +7974,7 @@ pub mod explicit_draughting {
     }
     impl AsRef<Representation> for RepresentationAny {
         fn as_ref(&self) -> &Representation {
-            match self {
-                RepresentationAny::Representation(x) => x.as_ref(),
-                RepresentationAny::DraughtingModel(x) => (**x).as_ref(),
-                RepresentationAny::PresentationRepresentation(x) => (**x).as_ref(),
-                RepresentationAny::ShapeRepresentation(x) => (**x).as_ref(),
-                RepresentationAny::SymbolRepresentation(x) => (**x).as_ref(),
-            }
+            match self { RepresentationAny :: Representation (x) => x . as_ref () , RepresentationAny :: DraughtingModel (x) => (* * x) . as_ref () , RepresentationAny :: PresentationRepresentation (x) => (* * x) . as_ref () , RepresentationAny :: ShapeRepresentation (x) => (* * x) . as_ref () , RepresentationAny :: SymbolRepresentation (x) => (* * x) . as_ref () , RepresentationAny :: DraughtingModelAndPresentationRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: DraughtingModelAndShapeRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: DraughtingModelAndSymbolRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: PresentationRepresentationAndShapeRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: PresentationRepresentationAndSymbolRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: ShapeRepresentationAndSymbolRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: DraughtingModelAndPresentationRepresentationAndShapeRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: DraughtingModelAndPresentationRepresentationAndSymbolRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: DraughtingModelAndShapeRepresentationAndSymbolRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: PresentationRepresentationAndShapeRepresentationAndSymbolRepresentation (x) => x . 0 . as_ref () , RepresentationAny :: DraughtingModelAndPresentationRepresentationAndShapeRepresentationAndSymbolRepresentation (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -7307,6 +7997,10 @@ pub mod explicit_draughting {
         GeometricRepresentationContext(Box<GeometricRepresentationContext>),
         #[holder(use_place_holder)]
         GlobalUnitAssignedContext(Box<GlobalUnitAssignedContext>),
+        #[holder(complex)]
+        GeometricRepresentationContextAndGlobalUnitAssignedContext(
+            Box<(GeometricRepresentationContext, GlobalUnitAssignedContext)>,
+        ),
     }
     impl Into<RepresentationContextAny> for RepresentationContext {
         fn into(self) -> RepresentationContextAny {
@@ -7325,11 +8019,7 @@ pub mod explicit_draughting {
     }
     impl AsRef<RepresentationContext> for RepresentationContextAny {
         fn as_ref(&self) -> &RepresentationContext {
-            match self {
-                RepresentationContextAny::RepresentationContext(x) => x.as_ref(),
-                RepresentationContextAny::GeometricRepresentationContext(x) => (**x).as_ref(),
-                RepresentationContextAny::GlobalUnitAssignedContext(x) => (**x).as_ref(),
-            }
+            match self { RepresentationContextAny :: RepresentationContext (x) => x . as_ref () , RepresentationContextAny :: GeometricRepresentationContext (x) => (* * x) . as_ref () , RepresentationContextAny :: GlobalUnitAssignedContext (x) => (* * x) . as_ref () , RepresentationContextAny :: GeometricRepresentationContextAndGlobalUnitAssignedContext (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -7352,6 +8042,20 @@ pub mod explicit_draughting {
         MappedItem(Box<MappedItemAny>),
         #[holder(use_place_holder)]
         StyledItem(Box<StyledItemAny>),
+        #[holder(complex)]
+        GeometricRepresentationItemAndMappedItem(
+            Box<(GeometricRepresentationItemAny, MappedItemAny)>,
+        ),
+        #[holder(complex)]
+        GeometricRepresentationItemAndStyledItem(
+            Box<(GeometricRepresentationItemAny, StyledItemAny)>,
+        ),
+        #[holder(complex)]
+        MappedItemAndStyledItem(Box<(MappedItemAny, StyledItemAny)>),
+        #[holder(complex)]
+        GeometricRepresentationItemAndMappedItemAndStyledItem(
+            Box<(GeometricRepresentationItemAny, MappedItemAny, StyledItemAny)>,
+        ),
     }
     impl Into<RepresentationItemAny> for RepresentationItem {
         fn into(self) -> RepresentationItemAny {
@@ -7380,6 +8084,12 @@ pub mod explicit_draughting {
                 RepresentationItemAny::GeometricRepresentationItem(x) => (**x).as_ref(),
                 RepresentationItemAny::MappedItem(x) => (**x).as_ref(),
                 RepresentationItemAny::StyledItem(x) => (**x).as_ref(),
+                RepresentationItemAny::GeometricRepresentationItemAndMappedItem(x) => x.0.as_ref(),
+                RepresentationItemAny::GeometricRepresentationItemAndStyledItem(x) => x.0.as_ref(),
+                RepresentationItemAny::MappedItemAndStyledItem(x) => x.0.as_ref(),
+                RepresentationItemAny::GeometricRepresentationItemAndMappedItemAndStyledItem(x) => {
+                    x.0.as_ref()
+                }
             }
         }
     }
@@ -7403,6 +8113,8 @@ pub mod explicit_draughting {
         CameraUsage(Box<CameraUsage>),
         #[holder(use_place_holder)]
         SymbolRepresentationMap(Box<SymbolRepresentationMap>),
+        #[holder(complex)]
+        CameraUsageAndSymbolRepresentationMap(Box<(CameraUsage, SymbolRepresentationMap)>),
     }
     impl Into<RepresentationMapAny> for RepresentationMap {
         fn into(self) -> RepresentationMapAny {
@@ -7425,6 +8137,7 @@ pub mod explicit_draughting {
                 RepresentationMapAny::RepresentationMap(x) => x.as_ref(),
                 RepresentationMapAny::CameraUsage(x) => (**x).as_ref(),
                 RepresentationMapAny::SymbolRepresentationMap(x) => (**x).as_ref(),
+                RepresentationMapAny::CameraUsageAndSymbolRepresentationMap(x) => x.0.as_ref(),
             }
         }
     }
@@ -7501,6 +8214,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub property_definition_representation: PropertyDefinitionRepresentation,
     }
     #[derive(
@@ -7515,6 +8229,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -7574,6 +8289,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         pub prefix: Option<SiPrefix>,
         pub name: SiUnitName,
@@ -7590,6 +8306,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(
@@ -7604,6 +8321,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
         #[holder(use_place_holder)]
         pub styles: Vec<PresentationStyleAssignmentAny>,
@@ -7667,6 +8385,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -7679,6 +8398,13 @@ pub mod explicit_draughting {
         DraughtingSubfigureRepresentation(Box<DraughtingSubfigureRepresentation>),
         #[holder(use_place_holder)]
         DraughtingSymbolRepresentation(Box<DraughtingSymbolRepresentationAny>),
+        #[holder(complex)]
+        DraughtingSubfigureRepresentationAndDraughtingSymbolRepresentation(
+            Box<(
+                DraughtingSubfigureRepresentation,
+                DraughtingSymbolRepresentationAny,
+            )>,
+        ),
     }
     impl Into<SymbolRepresentationAny> for SymbolRepresentation {
         fn into(self) -> SymbolRepresentationAny {
@@ -7697,26 +8423,12 @@ pub mod explicit_draughting {
     }
     impl AsRef<SymbolRepresentation> for SymbolRepresentationAny {
         fn as_ref(&self) -> &SymbolRepresentation {
-            match self {
-                SymbolRepresentationAny::SymbolRepresentation(x) => x.as_ref(),
-                SymbolRepresentationAny::DraughtingSubfigureRepresentation(x) => (**x).as_ref(),
-                SymbolRepresentationAny::DraughtingSymbolRepresentation(x) => (**x).as_ref(),
-            }
+            match self { SymbolRepresentationAny :: SymbolRepresentation (x) => x . as_ref () , SymbolRepresentationAny :: DraughtingSubfigureRepresentation (x) => (* * x) . as_ref () , SymbolRepresentationAny :: DraughtingSymbolRepresentation (x) => (* * x) . as_ref () , SymbolRepresentationAny :: DraughtingSubfigureRepresentationAndDraughtingSymbolRepresentation (x) => x . 0 . as_ref () , }
         }
     }
     impl AsRef<Representation> for SymbolRepresentationAny {
         fn as_ref(&self) -> &Representation {
-            match self {
-                SymbolRepresentationAny::SymbolRepresentation(x) => {
-                    AsRef::<SymbolRepresentation>::as_ref(x).as_ref()
-                }
-                SymbolRepresentationAny::DraughtingSubfigureRepresentation(x) => {
-                    AsRef::<SymbolRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-                SymbolRepresentationAny::DraughtingSymbolRepresentation(x) => {
-                    AsRef::<SymbolRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-            }
+            match self { SymbolRepresentationAny :: SymbolRepresentation (x) => AsRef :: < SymbolRepresentation > :: as_ref (x) . as_ref () , SymbolRepresentationAny :: DraughtingSubfigureRepresentation (x) => AsRef :: < SymbolRepresentation > :: as_ref (x . as_ref ()) . as_ref () , SymbolRepresentationAny :: DraughtingSymbolRepresentation (x) => AsRef :: < SymbolRepresentation > :: as_ref (x . as_ref ()) . as_ref () , SymbolRepresentationAny :: DraughtingSubfigureRepresentationAndDraughtingSymbolRepresentation (x) => AsRef :: < SymbolRepresentation > :: as_ref (& x . 0) . as_ref () , }
         }
     }
     #[derive(
@@ -7731,6 +8443,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_map: RepresentationMap,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -7755,6 +8468,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub placement: Axis2Placement,
@@ -7775,6 +8489,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_symbol_occurrence: AnnotationSymbolOccurrence,
         #[holder(use_place_holder)]
         pub annotated_curve: AnnotationCurveOccurrenceAny,
@@ -7789,6 +8504,10 @@ pub mod explicit_draughting {
         DimensionCurveTerminator(Box<DimensionCurveTerminator>),
         #[holder(use_place_holder)]
         LeaderTerminator(Box<LeaderTerminator>),
+        #[holder(complex)]
+        DimensionCurveTerminatorAndLeaderTerminator(
+            Box<(DimensionCurveTerminator, LeaderTerminator)>,
+        ),
     }
     impl Into<TerminatorSymbolAny> for TerminatorSymbol {
         fn into(self) -> TerminatorSymbolAny {
@@ -7811,6 +8530,7 @@ pub mod explicit_draughting {
                 TerminatorSymbolAny::TerminatorSymbol(x) => x.as_ref(),
                 TerminatorSymbolAny::DimensionCurveTerminator(x) => (**x).as_ref(),
                 TerminatorSymbolAny::LeaderTerminator(x) => (**x).as_ref(),
+                TerminatorSymbolAny::DimensionCurveTerminatorAndLeaderTerminator(x) => x.0.as_ref(),
             }
         }
     }
@@ -7826,6 +8546,9 @@ pub mod explicit_draughting {
                 TerminatorSymbolAny::LeaderTerminator(x) => {
                     AsRef::<TerminatorSymbol>::as_ref(x.as_ref()).as_ref()
                 }
+                TerminatorSymbolAny::DimensionCurveTerminatorAndLeaderTerminator(x) => {
+                    AsRef::<TerminatorSymbol>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -7841,6 +8564,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub literal: PresentableText,
@@ -7866,6 +8590,74 @@ pub mod explicit_draughting {
         TextLiteralWithDelineation(Box<TextLiteralWithDelineationAny>),
         #[holder(use_place_holder)]
         TextLiteralWithExtent(Box<TextLiteralWithExtent>),
+        #[holder(complex)]
+        TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBox(
+            Box<(TextLiteralWithAssociatedCurves, TextLiteralWithBlankingBox)>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithAssociatedCurvesAndTextLiteralWithDelineation(
+            Box<(
+                TextLiteralWithAssociatedCurves,
+                TextLiteralWithDelineationAny,
+            )>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithAssociatedCurvesAndTextLiteralWithExtent(
+            Box<(TextLiteralWithAssociatedCurves, TextLiteralWithExtent)>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithBlankingBoxAndTextLiteralWithDelineation(
+            Box<(TextLiteralWithBlankingBox, TextLiteralWithDelineationAny)>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithBlankingBoxAndTextLiteralWithExtent(
+            Box<(TextLiteralWithBlankingBox, TextLiteralWithExtent)>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithDelineationAndTextLiteralWithExtent(
+            Box<(TextLiteralWithDelineationAny, TextLiteralWithExtent)>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithDelineation(
+            Box<(
+                TextLiteralWithAssociatedCurves,
+                TextLiteralWithBlankingBox,
+                TextLiteralWithDelineationAny,
+            )>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithExtent(
+            Box<(
+                TextLiteralWithAssociatedCurves,
+                TextLiteralWithBlankingBox,
+                TextLiteralWithExtent,
+            )>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithAssociatedCurvesAndTextLiteralWithDelineationAndTextLiteralWithExtent(
+            Box<(
+                TextLiteralWithAssociatedCurves,
+                TextLiteralWithDelineationAny,
+                TextLiteralWithExtent,
+            )>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithBlankingBoxAndTextLiteralWithDelineationAndTextLiteralWithExtent(
+            Box<(
+                TextLiteralWithBlankingBox,
+                TextLiteralWithDelineationAny,
+                TextLiteralWithExtent,
+            )>,
+        ),
+        #[holder(complex)]
+        TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithDelineationAndTextLiteralWithExtent(
+            Box<(
+                TextLiteralWithAssociatedCurves,
+                TextLiteralWithBlankingBox,
+                TextLiteralWithDelineationAny,
+                TextLiteralWithExtent,
+            )>,
+        ),
     }
     impl Into<TextLiteralAny> for TextLiteral {
         fn into(self) -> TextLiteralAny {
@@ -7894,32 +8686,12 @@ pub mod explicit_draughting {
     }
     impl AsRef<TextLiteral> for TextLiteralAny {
         fn as_ref(&self) -> &TextLiteral {
-            match self {
-                TextLiteralAny::TextLiteral(x) => x.as_ref(),
-                TextLiteralAny::TextLiteralWithAssociatedCurves(x) => (**x).as_ref(),
-                TextLiteralAny::TextLiteralWithBlankingBox(x) => (**x).as_ref(),
-                TextLiteralAny::TextLiteralWithDelineation(x) => (**x).as_ref(),
-                TextLiteralAny::TextLiteralWithExtent(x) => (**x).as_ref(),
-            }
+            match self { TextLiteralAny :: TextLiteral (x) => x . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurves (x) => (* * x) . as_ref () , TextLiteralAny :: TextLiteralWithBlankingBox (x) => (* * x) . as_ref () , TextLiteralAny :: TextLiteralWithDelineation (x) => (* * x) . as_ref () , TextLiteralAny :: TextLiteralWithExtent (x) => (* * x) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBox (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithDelineation (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithExtent (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithBlankingBoxAndTextLiteralWithDelineation (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithBlankingBoxAndTextLiteralWithExtent (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithDelineationAndTextLiteralWithExtent (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithDelineation (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithExtent (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithDelineationAndTextLiteralWithExtent (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithBlankingBoxAndTextLiteralWithDelineationAndTextLiteralWithExtent (x) => x . 0 . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithDelineationAndTextLiteralWithExtent (x) => x . 0 . as_ref () , }
         }
     }
     impl AsRef<GeometricRepresentationItem> for TextLiteralAny {
         fn as_ref(&self) -> &GeometricRepresentationItem {
-            match self {
-                TextLiteralAny::TextLiteral(x) => AsRef::<TextLiteral>::as_ref(x).as_ref(),
-                TextLiteralAny::TextLiteralWithAssociatedCurves(x) => {
-                    AsRef::<TextLiteral>::as_ref(x.as_ref()).as_ref()
-                }
-                TextLiteralAny::TextLiteralWithBlankingBox(x) => {
-                    AsRef::<TextLiteral>::as_ref(x.as_ref()).as_ref()
-                }
-                TextLiteralAny::TextLiteralWithDelineation(x) => {
-                    AsRef::<TextLiteral>::as_ref(x.as_ref()).as_ref()
-                }
-                TextLiteralAny::TextLiteralWithExtent(x) => {
-                    AsRef::<TextLiteral>::as_ref(x.as_ref()).as_ref()
-                }
-            }
+            match self { TextLiteralAny :: TextLiteral (x) => AsRef :: < TextLiteral > :: as_ref (x) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurves (x) => AsRef :: < TextLiteral > :: as_ref (x . as_ref ()) . as_ref () , TextLiteralAny :: TextLiteralWithBlankingBox (x) => AsRef :: < TextLiteral > :: as_ref (x . as_ref ()) . as_ref () , TextLiteralAny :: TextLiteralWithDelineation (x) => AsRef :: < TextLiteral > :: as_ref (x . as_ref ()) . as_ref () , TextLiteralAny :: TextLiteralWithExtent (x) => AsRef :: < TextLiteral > :: as_ref (x . as_ref ()) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBox (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithDelineation (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithExtent (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithBlankingBoxAndTextLiteralWithDelineation (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithBlankingBoxAndTextLiteralWithExtent (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithDelineationAndTextLiteralWithExtent (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithDelineation (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithExtent (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithDelineationAndTextLiteralWithExtent (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithBlankingBoxAndTextLiteralWithDelineationAndTextLiteralWithExtent (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , TextLiteralAny :: TextLiteralWithAssociatedCurvesAndTextLiteralWithBlankingBoxAndTextLiteralWithDelineationAndTextLiteralWithExtent (x) => AsRef :: < TextLiteral > :: as_ref (& x . 0) . as_ref () , }
         }
     }
     #[derive(
@@ -7934,6 +8706,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
         pub associated_curves: Vec<CurveAny>,
@@ -7950,6 +8723,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
         pub blanking: PlanarBox,
@@ -7966,6 +8740,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
         pub delineation: TextDelineation,
@@ -8025,6 +8800,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
         pub extent: PlanarExtentAny,
@@ -8049,6 +8825,10 @@ pub mod explicit_draughting {
         TextStyleWithBoxCharacteristics(Box<TextStyleWithBoxCharacteristics>),
         #[holder(use_place_holder)]
         TextStyleWithMirror(Box<TextStyleWithMirror>),
+        #[holder(complex)]
+        TextStyleWithBoxCharacteristicsAndTextStyleWithMirror(
+            Box<(TextStyleWithBoxCharacteristics, TextStyleWithMirror)>,
+        ),
     }
     impl Into<TextStyleAny> for TextStyle {
         fn into(self) -> TextStyleAny {
@@ -8071,6 +8851,9 @@ pub mod explicit_draughting {
                 TextStyleAny::TextStyle(x) => x.as_ref(),
                 TextStyleAny::TextStyleWithBoxCharacteristics(x) => (**x).as_ref(),
                 TextStyleAny::TextStyleWithMirror(x) => (**x).as_ref(),
+                TextStyleAny::TextStyleWithBoxCharacteristicsAndTextStyleWithMirror(x) => {
+                    x.0.as_ref()
+                }
             }
         }
     }
@@ -8094,6 +8877,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_style: TextStyle,
         #[holder(use_place_holder)]
         pub characteristics: Vec<BoxCharacteristicSelect>,
@@ -8110,6 +8894,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_style: TextStyle,
         #[holder(use_place_holder)]
         pub mirror_placement: Axis2Placement,
@@ -8126,6 +8911,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -8148,6 +8934,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub one_direction_repeat_factor: OneDirectionRepeatFactor,
         #[holder(use_place_holder)]
         pub second_repeat_factor: Vector,
@@ -8164,6 +8951,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
     #[derive(
@@ -8178,6 +8966,7 @@ pub mod explicit_draughting {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub orientation: Direction,
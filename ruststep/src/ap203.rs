@@ -1522,7 +1522,7 @@ pub mod config_control_design {
             &self.year_number
         }
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum AheadOrBehind {
         Ahead,
         Behind,
@@ -1570,7 +1570,7 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         Axis2Placement3D(Box<Axis2Placement3D>),
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum BSplineCurveForm {
         PolylineForm,
         CircularArc,
@@ -1579,7 +1579,7 @@ pub mod config_control_design {
         HyperbolicArc,
         Unspecified,
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum BSplineSurfaceForm {
         PlaneSurf,
         CylindricalSurf,
@@ -1776,7 +1776,7 @@ pub mod config_control_design {
     # [holder (field = identifier)]
     #[holder(generate_deserialize)]
     pub struct Identifier(pub String);
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum KnotType {
         UniformKnots,
         Unspecified,
@@ -1929,7 +1929,7 @@ pub mod config_control_design {
     # [holder (field = positive_plane_angle_measure)]
     #[holder(generate_deserialize)]
     pub struct PositivePlaneAngleMeasure(#[holder(use_place_holder)] pub PlaneAngleMeasure);
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum PreferredSurfaceCurveRepresentation {
         Curve3D,
         PcurveS1,
@@ -2003,7 +2003,7 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ClosedShell(ClosedShellAny),
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum SiPrefix {
         Exa,
         Peta,
@@ -2022,7 +2022,7 @@ pub mod config_control_design {
         Femto,
         Atto,
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum SiUnitName {
         Metre,
         Gram,
@@ -2060,7 +2060,7 @@ pub mod config_control_design {
     # [holder (field = solid_angle_measure)]
     #[holder(generate_deserialize)]
     pub struct SolidAngleMeasure(pub f64);
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum Source {
         Made,
         Bought,
@@ -2116,14 +2116,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         FunctionallyDefinedTransformation(FunctionallyDefinedTransformationAny),
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum TransitionCode {
         Discontinuous,
         Continuous,
         ContSameGradient,
         ContSameGradientSameCurvature,
     }
-    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+    #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize, :: serde :: Serialize)]
     pub enum TrimmingPreference {
         Cartesian,
         Parameter,
@@ -2248,6 +2248,8 @@ pub mod config_control_design {
         Change(Box<Change>),
         #[holder(use_place_holder)]
         StartWork(Box<StartWork>),
+        #[holder(complex)]
+        ChangeAndStartWork(Box<(Change, StartWork)>),
     }
     impl Into<ActionAssignmentAny> for ActionAssignment {
         fn into(self) -> ActionAssignmentAny {
@@ -2270,6 +2272,7 @@ pub mod config_control_design {
                 ActionAssignmentAny::ActionAssignment(x) => x.as_ref(),
                 ActionAssignmentAny::Change(x) => (**x).as_ref(),
                 ActionAssignmentAny::StartWork(x) => (**x).as_ref(),
+                ActionAssignmentAny::ChangeAndStartWork(x) => x.0.as_ref(),
             }
         }
     }
@@ -2321,6 +2324,8 @@ pub mod config_control_design {
         ChangeRequest(Box<ChangeRequest>),
         #[holder(use_place_holder)]
         StartRequest(Box<StartRequest>),
+        #[holder(complex)]
+        ChangeRequestAndStartRequest(Box<(ChangeRequest, StartRequest)>),
     }
     impl Into<ActionRequestAssignmentAny> for ActionRequestAssignment {
         fn into(self) -> ActionRequestAssignmentAny {
@@ -2343,6 +2348,7 @@ pub mod config_control_design {
                 ActionRequestAssignmentAny::ActionRequestAssignment(x) => x.as_ref(),
                 ActionRequestAssignmentAny::ChangeRequest(x) => (**x).as_ref(),
                 ActionRequestAssignmentAny::StartRequest(x) => (**x).as_ref(),
+                ActionRequestAssignmentAny::ChangeRequestAndStartRequest(x) => x.0.as_ref(),
             }
         }
     }
@@ -2416,6 +2422,8 @@ pub mod config_control_design {
         OrganizationalAddress(Box<OrganizationalAddress>),
         #[holder(use_place_holder)]
         PersonalAddress(Box<PersonalAddress>),
+        #[holder(complex)]
+        OrganizationalAddressAndPersonalAddress(Box<(OrganizationalAddress, PersonalAddress)>),
     }
     impl Into<AddressAny> for Address {
         fn into(self) -> AddressAny {
@@ -2438,6 +2446,7 @@ pub mod config_control_design {
                 AddressAny::Address(x) => x.as_ref(),
                 AddressAny::OrganizationalAddress(x) => (**x).as_ref(),
                 AddressAny::PersonalAddress(x) => (**x).as_ref(),
+                AddressAny::OrganizationalAddressAndPersonalAddress(x) => x.0.as_ref(),
             }
         }
     }
@@ -2453,6 +2462,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
     #[derive(
@@ -2467,6 +2477,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub face_surface: FaceSurface,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -2669,6 +2680,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
     #[derive(
@@ -2683,6 +2695,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
     #[derive(
@@ -2697,6 +2710,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_usage: ProductDefinitionUsage,
         #[holder(use_place_holder)]
         pub reference_designator: Option<Identifier>,
@@ -2715,6 +2729,24 @@ pub mod config_control_design {
         QuantifiedAssemblyComponentUsage(Box<QuantifiedAssemblyComponentUsage>),
         #[holder(use_place_holder)]
         SpecifiedHigherUsageOccurrence(Box<SpecifiedHigherUsageOccurrence>),
+        #[holder(complex)]
+        NextAssemblyUsageOccurrenceAndQuantifiedAssemblyComponentUsage(
+            Box<(
+                NextAssemblyUsageOccurrence,
+                QuantifiedAssemblyComponentUsage,
+            )>,
+        ),
+        #[holder(complex)]
+        PromissoryUsageOccurrenceAndQuantifiedAssemblyComponentUsage(
+            Box<(PromissoryUsageOccurrence, QuantifiedAssemblyComponentUsage)>,
+        ),
+        #[holder(complex)]
+        QuantifiedAssemblyComponentUsageAndSpecifiedHigherUsageOccurrence(
+            Box<(
+                QuantifiedAssemblyComponentUsage,
+                SpecifiedHigherUsageOccurrence,
+            )>,
+        ),
     }
     impl Into<AssemblyComponentUsageAny> for AssemblyComponentUsage {
         fn into(self) -> AssemblyComponentUsageAny {
@@ -2743,34 +2775,12 @@ pub mod config_control_design {
     }
     impl AsRef<AssemblyComponentUsage> for AssemblyComponentUsageAny {
         fn as_ref(&self) -> &AssemblyComponentUsage {
-            match self {
-                AssemblyComponentUsageAny::AssemblyComponentUsage(x) => x.as_ref(),
-                AssemblyComponentUsageAny::NextAssemblyUsageOccurrence(x) => (**x).as_ref(),
-                AssemblyComponentUsageAny::PromissoryUsageOccurrence(x) => (**x).as_ref(),
-                AssemblyComponentUsageAny::QuantifiedAssemblyComponentUsage(x) => (**x).as_ref(),
-                AssemblyComponentUsageAny::SpecifiedHigherUsageOccurrence(x) => (**x).as_ref(),
-            }
+            match self { AssemblyComponentUsageAny :: AssemblyComponentUsage (x) => x . as_ref () , AssemblyComponentUsageAny :: NextAssemblyUsageOccurrence (x) => (* * x) . as_ref () , AssemblyComponentUsageAny :: PromissoryUsageOccurrence (x) => (* * x) . as_ref () , AssemblyComponentUsageAny :: QuantifiedAssemblyComponentUsage (x) => (* * x) . as_ref () , AssemblyComponentUsageAny :: SpecifiedHigherUsageOccurrence (x) => (* * x) . as_ref () , AssemblyComponentUsageAny :: NextAssemblyUsageOccurrenceAndQuantifiedAssemblyComponentUsage (x) => x . 0 . as_ref () , AssemblyComponentUsageAny :: PromissoryUsageOccurrenceAndQuantifiedAssemblyComponentUsage (x) => x . 0 . as_ref () , AssemblyComponentUsageAny :: QuantifiedAssemblyComponentUsageAndSpecifiedHigherUsageOccurrence (x) => x . 0 . as_ref () , }
         }
     }
     impl AsRef<ProductDefinitionUsage> for AssemblyComponentUsageAny {
         fn as_ref(&self) -> &ProductDefinitionUsage {
-            match self {
-                AssemblyComponentUsageAny::AssemblyComponentUsage(x) => {
-                    AsRef::<AssemblyComponentUsage>::as_ref(x).as_ref()
-                }
-                AssemblyComponentUsageAny::NextAssemblyUsageOccurrence(x) => {
-                    AsRef::<AssemblyComponentUsage>::as_ref(x.as_ref()).as_ref()
-                }
-                AssemblyComponentUsageAny::PromissoryUsageOccurrence(x) => {
-                    AsRef::<AssemblyComponentUsage>::as_ref(x.as_ref()).as_ref()
-                }
-                AssemblyComponentUsageAny::QuantifiedAssemblyComponentUsage(x) => {
-                    AsRef::<AssemblyComponentUsage>::as_ref(x.as_ref()).as_ref()
-                }
-                AssemblyComponentUsageAny::SpecifiedHigherUsageOccurrence(x) => {
-                    AsRef::<AssemblyComponentUsage>::as_ref(x.as_ref()).as_ref()
-                }
-            }
+            match self { AssemblyComponentUsageAny :: AssemblyComponentUsage (x) => AsRef :: < AssemblyComponentUsage > :: as_ref (x) . as_ref () , AssemblyComponentUsageAny :: NextAssemblyUsageOccurrence (x) => AsRef :: < AssemblyComponentUsage > :: as_ref (x . as_ref ()) . as_ref () , AssemblyComponentUsageAny :: PromissoryUsageOccurrence (x) => AsRef :: < AssemblyComponentUsage > :: as_ref (x . as_ref ()) . as_ref () , AssemblyComponentUsageAny :: QuantifiedAssemblyComponentUsage (x) => AsRef :: < AssemblyComponentUsage > :: as_ref (x . as_ref ()) . as_ref () , AssemblyComponentUsageAny :: SpecifiedHigherUsageOccurrence (x) => AsRef :: < AssemblyComponentUsage > :: as_ref (x . as_ref ()) . as_ref () , AssemblyComponentUsageAny :: NextAssemblyUsageOccurrenceAndQuantifiedAssemblyComponentUsage (x) => AsRef :: < AssemblyComponentUsage > :: as_ref (& x . 0) . as_ref () , AssemblyComponentUsageAny :: PromissoryUsageOccurrenceAndQuantifiedAssemblyComponentUsage (x) => AsRef :: < AssemblyComponentUsage > :: as_ref (& x . 0) . as_ref () , AssemblyComponentUsageAny :: QuantifiedAssemblyComponentUsageAndSpecifiedHigherUsageOccurrence (x) => AsRef :: < AssemblyComponentUsage > :: as_ref (& x . 0) . as_ref () , }
         }
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -2799,6 +2809,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub placement: Placement,
         #[holder(use_place_holder)]
         pub axis: Option<Direction>,
@@ -2815,6 +2826,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub placement: Placement,
         #[holder(use_place_holder)]
         pub ref_direction: Option<Direction>,
@@ -2831,6 +2843,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub placement: Placement,
         #[holder(use_place_holder)]
         pub axis: Option<Direction>,
@@ -2849,6 +2862,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         pub degree: i64,
         #[holder(use_place_holder)]
@@ -2873,6 +2887,16 @@ pub mod config_control_design {
         RationalBSplineCurve(Box<RationalBSplineCurve>),
         #[holder(use_place_holder)]
         UniformCurve(Box<UniformCurve>),
+        #[holder(complex)]
+        BSplineCurveWithKnotsAndRationalBSplineCurve(
+            Box<(BSplineCurveWithKnots, RationalBSplineCurve)>,
+        ),
+        #[holder(complex)]
+        BezierCurveAndRationalBSplineCurve(Box<(BezierCurve, RationalBSplineCurve)>),
+        #[holder(complex)]
+        QuasiUniformCurveAndRationalBSplineCurve(Box<(QuasiUniformCurve, RationalBSplineCurve)>),
+        #[holder(complex)]
+        RationalBSplineCurveAndUniformCurve(Box<(RationalBSplineCurve, UniformCurve)>),
     }
     impl Into<BSplineCurveAny> for BSplineCurve {
         fn into(self) -> BSplineCurveAny {
@@ -2913,6 +2937,10 @@ pub mod config_control_design {
                 BSplineCurveAny::QuasiUniformCurve(x) => (**x).as_ref(),
                 BSplineCurveAny::RationalBSplineCurve(x) => (**x).as_ref(),
                 BSplineCurveAny::UniformCurve(x) => (**x).as_ref(),
+                BSplineCurveAny::BSplineCurveWithKnotsAndRationalBSplineCurve(x) => x.0.as_ref(),
+                BSplineCurveAny::BezierCurveAndRationalBSplineCurve(x) => x.0.as_ref(),
+                BSplineCurveAny::QuasiUniformCurveAndRationalBSplineCurve(x) => x.0.as_ref(),
+                BSplineCurveAny::RationalBSplineCurveAndUniformCurve(x) => x.0.as_ref(),
             }
         }
     }
@@ -2935,6 +2963,18 @@ pub mod config_control_design {
                 BSplineCurveAny::UniformCurve(x) => {
                     AsRef::<BSplineCurve>::as_ref(x.as_ref()).as_ref()
                 }
+                BSplineCurveAny::BSplineCurveWithKnotsAndRationalBSplineCurve(x) => {
+                    AsRef::<BSplineCurve>::as_ref(&x.0).as_ref()
+                }
+                BSplineCurveAny::BezierCurveAndRationalBSplineCurve(x) => {
+                    AsRef::<BSplineCurve>::as_ref(&x.0).as_ref()
+                }
+                BSplineCurveAny::QuasiUniformCurveAndRationalBSplineCurve(x) => {
+                    AsRef::<BSplineCurve>::as_ref(&x.0).as_ref()
+                }
+                BSplineCurveAny::RationalBSplineCurveAndUniformCurve(x) => {
+                    AsRef::<BSplineCurve>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -2950,6 +2990,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
         pub knot_multiplicities: Vec<i64>,
         #[holder(use_place_holder)]
@@ -2968,6 +3009,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_surface: BoundedSurface,
         pub u_degree: i64,
         pub v_degree: i64,
@@ -2994,6 +3036,18 @@ pub mod config_control_design {
         RationalBSplineSurface(Box<RationalBSplineSurface>),
         #[holder(use_place_holder)]
         UniformSurface(Box<UniformSurface>),
+        #[holder(complex)]
+        BSplineSurfaceWithKnotsAndRationalBSplineSurface(
+            Box<(BSplineSurfaceWithKnots, RationalBSplineSurface)>,
+        ),
+        #[holder(complex)]
+        BezierSurfaceAndRationalBSplineSurface(Box<(BezierSurface, RationalBSplineSurface)>),
+        #[holder(complex)]
+        QuasiUniformSurfaceAndRationalBSplineSurface(
+            Box<(QuasiUniformSurface, RationalBSplineSurface)>,
+        ),
+        #[holder(complex)]
+        RationalBSplineSurfaceAndUniformSurface(Box<(RationalBSplineSurface, UniformSurface)>),
     }
     impl Into<BSplineSurfaceAny> for BSplineSurface {
         fn into(self) -> BSplineSurfaceAny {
@@ -3034,6 +3088,12 @@ pub mod config_control_design {
                 BSplineSurfaceAny::QuasiUniformSurface(x) => (**x).as_ref(),
                 BSplineSurfaceAny::RationalBSplineSurface(x) => (**x).as_ref(),
                 BSplineSurfaceAny::UniformSurface(x) => (**x).as_ref(),
+                BSplineSurfaceAny::BSplineSurfaceWithKnotsAndRationalBSplineSurface(x) => {
+                    x.0.as_ref()
+                }
+                BSplineSurfaceAny::BezierSurfaceAndRationalBSplineSurface(x) => x.0.as_ref(),
+                BSplineSurfaceAny::QuasiUniformSurfaceAndRationalBSplineSurface(x) => x.0.as_ref(),
+                BSplineSurfaceAny::RationalBSplineSurfaceAndUniformSurface(x) => x.0.as_ref(),
             }
         }
     }
@@ -3056,6 +3116,18 @@ pub mod config_control_design {
                 BSplineSurfaceAny::UniformSurface(x) => {
                     AsRef::<BSplineSurface>::as_ref(x.as_ref()).as_ref()
                 }
+                BSplineSurfaceAny::BSplineSurfaceWithKnotsAndRationalBSplineSurface(x) => {
+                    AsRef::<BSplineSurface>::as_ref(&x.0).as_ref()
+                }
+                BSplineSurfaceAny::BezierSurfaceAndRationalBSplineSurface(x) => {
+                    AsRef::<BSplineSurface>::as_ref(&x.0).as_ref()
+                }
+                BSplineSurfaceAny::QuasiUniformSurfaceAndRationalBSplineSurface(x) => {
+                    AsRef::<BSplineSurface>::as_ref(&x.0).as_ref()
+                }
+                BSplineSurfaceAny::RationalBSplineSurfaceAndUniformSurface(x) => {
+                    AsRef::<BSplineSurface>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -3071,6 +3143,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
         pub u_multiplicities: Vec<i64>,
         pub v_multiplicities: Vec<i64>,
@@ -3092,6 +3165,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
     #[derive(
@@ -3106,6 +3180,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
     }
     #[derive(
@@ -3120,6 +3195,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_curve_on_surface: CompositeCurveOnSurface,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3171,6 +3247,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3271,10 +3348,12 @@ pub mod config_control_design {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pcurve: Pcurve,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
     }
     #[derive(
@@ -3289,6 +3368,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3369,10 +3449,12 @@ pub mod config_control_design {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface_curve: SurfaceCurve,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
     }
     #[derive(
@@ -3387,6 +3469,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub manifold_solid_brep: ManifoldSolidBrep,
         #[holder(use_place_holder)]
         pub voids: Vec<OrientedClosedShell>,
@@ -3403,6 +3486,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date: Date,
         #[holder(use_place_holder)]
         pub day_component: DayInMonthNumber,
@@ -3421,6 +3505,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub coordinates: Vec<LengthMeasure>,
@@ -3433,10 +3518,12 @@ pub mod config_control_design {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub functionally_defined_transformation: FunctionallyDefinedTransformation,
         #[holder(use_place_holder)]
         pub axis1: Option<Direction>,
@@ -3515,6 +3602,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub cartesian_transformation_operator: CartesianTransformationOperator,
         #[holder(use_place_holder)]
         pub axis3: Option<Direction>,
@@ -3531,6 +3619,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub approval_assignment: ApprovalAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ApprovedItem>,
@@ -3547,6 +3636,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub certification_assignment: CertificationAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<CertifiedItem>,
@@ -3563,6 +3653,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub contract_assignment: ContractAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ContractedItem>,
@@ -3579,6 +3670,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date_and_time_assignment: DateAndTimeAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<DateTimeItem>,
@@ -3595,6 +3687,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub person_and_organization_assignment: PersonAndOrganizationAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<PersonOrganizationItem>,
@@ -3611,6 +3704,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub security_classification_assignment: SecurityClassificationAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ClassifiedItem>,
@@ -3627,6 +3721,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub document_reference: DocumentReference,
         #[holder(use_place_holder)]
         pub items: Vec<SpecifiedItem>,
@@ -3698,6 +3793,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action_assignment: ActionAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<WorkItem>,
@@ -3714,6 +3810,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action_request_assignment: ActionRequestAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ChangeRequestItem>,
@@ -3730,6 +3827,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
@@ -3746,6 +3844,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub connected_face_set: ConnectedFaceSet,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3797,6 +3896,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub segments: Vec<CompositeCurveSegmentAny>,
@@ -3851,6 +3951,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_curve: CompositeCurve,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3904,6 +4005,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub founded_item: FoundedItem,
         pub transition: TransitionCode,
         pub same_sense: bool,
@@ -3971,6 +4073,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_effectivity: ProductDefinitionEffectivity,
         #[holder(use_place_holder)]
         pub configuration: ConfigurationDesign,
@@ -4003,6 +4106,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub position: Axis2Placement,
@@ -4081,6 +4185,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
         #[holder(use_place_holder)]
         pub radius: LengthMeasure,
@@ -4099,6 +4204,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub ces_edges: Vec<EdgeAny>,
@@ -4115,6 +4221,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub cfs_faces: Vec<FaceAny>,
@@ -4191,6 +4298,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         #[holder(use_place_holder)]
         pub name: Label,
@@ -4262,6 +4370,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         #[holder(use_place_holder)]
         pub name: Label,
@@ -4291,6 +4400,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -4313,6 +4423,18 @@ pub mod config_control_design {
         Pcurve(Box<PcurveAny>),
         #[holder(use_place_holder)]
         SurfaceCurve(Box<SurfaceCurveAny>),
+        #[holder(complex)]
+        BoundedCurveAndConic(Box<(BoundedCurveAny, ConicAny)>),
+        #[holder(complex)]
+        BoundedCurveAndCurveReplica(Box<(BoundedCurveAny, CurveReplica)>),
+        #[holder(complex)]
+        BoundedCurveAndLine(Box<(BoundedCurveAny, Line)>),
+        #[holder(complex)]
+        BoundedCurveAndOffsetCurve3D(Box<(BoundedCurveAny, OffsetCurve3D)>),
+        #[holder(complex)]
+        BoundedCurveAndPcurve(Box<(BoundedCurveAny, PcurveAny)>),
+        #[holder(complex)]
+        BoundedCurveAndSurfaceCurve(Box<(BoundedCurveAny, SurfaceCurveAny)>),
     }
     impl Into<CurveAny> for Curve {
         fn into(self) -> CurveAny {
@@ -4365,6 +4487,12 @@ pub mod config_control_design {
                 CurveAny::OffsetCurve3D(x) => (**x).as_ref(),
                 CurveAny::Pcurve(x) => (**x).as_ref(),
                 CurveAny::SurfaceCurve(x) => (**x).as_ref(),
+                CurveAny::BoundedCurveAndConic(x) => x.0.as_ref(),
+                CurveAny::BoundedCurveAndCurveReplica(x) => x.0.as_ref(),
+                CurveAny::BoundedCurveAndLine(x) => x.0.as_ref(),
+                CurveAny::BoundedCurveAndOffsetCurve3D(x) => x.0.as_ref(),
+                CurveAny::BoundedCurveAndPcurve(x) => x.0.as_ref(),
+                CurveAny::BoundedCurveAndSurfaceCurve(x) => x.0.as_ref(),
             }
         }
     }
@@ -4379,6 +4507,12 @@ pub mod config_control_design {
                 CurveAny::OffsetCurve3D(x) => AsRef::<Curve>::as_ref(x.as_ref()).as_ref(),
                 CurveAny::Pcurve(x) => AsRef::<Curve>::as_ref(x.as_ref()).as_ref(),
                 CurveAny::SurfaceCurve(x) => AsRef::<Curve>::as_ref(x.as_ref()).as_ref(),
+                CurveAny::BoundedCurveAndConic(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
+                CurveAny::BoundedCurveAndCurveReplica(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
+                CurveAny::BoundedCurveAndLine(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
+                CurveAny::BoundedCurveAndOffsetCurve3D(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
+                CurveAny::BoundedCurveAndPcurve(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
+                CurveAny::BoundedCurveAndSurfaceCurve(x) => AsRef::<Curve>::as_ref(&x.0).as_ref(),
             }
         }
     }
@@ -4394,6 +4528,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_surface: BoundedSurface,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -4413,6 +4548,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub parent_curve: CurveAny,
@@ -4431,6 +4567,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
@@ -4553,6 +4690,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub effectivity: Effectivity,
         #[holder(use_place_holder)]
         pub effectivity_start_date: DateAndTime,
@@ -4571,6 +4709,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(
@@ -4585,6 +4724,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -4642,6 +4782,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub toroidal_surface: ToroidalSurface,
         pub select_outer: bool,
     }
@@ -4657,6 +4798,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_context: ProductDefinitionContext,
     }
     #[derive(
@@ -4671,6 +4813,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_relationship: ProductDefinitionRelationship,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -4698,6 +4841,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub executed_action: ExecutedAction,
         #[holder(use_place_holder)]
         pub directive: ActionDirective,
@@ -4714,6 +4858,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         pub direction_ratios: Vec<f64>,
     }
@@ -4841,6 +4986,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub document: Document,
         #[holder(use_place_holder)]
         pub class: Identifier,
@@ -4857,6 +5003,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub edge_start: VertexAny,
@@ -4919,6 +5066,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub ebwm_boundary: Vec<ConnectedEdgeSet>,
@@ -4935,6 +5083,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
@@ -4945,10 +5094,12 @@ pub mod config_control_design {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub edge: Edge,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub edge_geometry: CurveAny,
@@ -4962,10 +5113,12 @@ pub mod config_control_design {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub r#loop: Loop,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub path: Path,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -4990,6 +5143,18 @@ pub mod config_control_design {
         ProductDefinitionEffectivity(Box<ProductDefinitionEffectivityAny>),
         #[holder(use_place_holder)]
         SerialNumberedEffectivity(Box<SerialNumberedEffectivity>),
+        #[holder(complex)]
+        DatedEffectivityAndProductDefinitionEffectivity(
+            Box<(DatedEffectivity, ProductDefinitionEffectivityAny)>,
+        ),
+        #[holder(complex)]
+        LotEffectivityAndProductDefinitionEffectivity(
+            Box<(LotEffectivity, ProductDefinitionEffectivityAny)>,
+        ),
+        #[holder(complex)]
+        ProductDefinitionEffectivityAndSerialNumberedEffectivity(
+            Box<(ProductDefinitionEffectivityAny, SerialNumberedEffectivity)>,
+        ),
     }
     impl Into<EffectivityAny> for Effectivity {
         fn into(self) -> EffectivityAny {
@@ -5024,6 +5189,11 @@ pub mod config_control_design {
                 EffectivityAny::LotEffectivity(x) => (**x).as_ref(),
                 EffectivityAny::ProductDefinitionEffectivity(x) => (**x).as_ref(),
                 EffectivityAny::SerialNumberedEffectivity(x) => (**x).as_ref(),
+                EffectivityAny::DatedEffectivityAndProductDefinitionEffectivity(x) => x.0.as_ref(),
+                EffectivityAny::LotEffectivityAndProductDefinitionEffectivity(x) => x.0.as_ref(),
+                EffectivityAny::ProductDefinitionEffectivityAndSerialNumberedEffectivity(x) => {
+                    x.0.as_ref()
+                }
             }
         }
     }
@@ -5039,6 +5209,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
         #[holder(use_place_holder)]
         pub position: Axis2Placement3D,
@@ -5138,6 +5309,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub semi_axis_1: PositiveLengthMeasure,
@@ -5156,6 +5328,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub degenerate_pcurve: DegeneratePcurve,
         #[holder(use_place_holder)]
         pub equivalent_point: CartesianPoint,
@@ -5172,6 +5345,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action: Action,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -5223,6 +5397,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub bounds: Vec<FaceBoundAny>,
@@ -5283,6 +5458,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub bound: LoopAny,
@@ -5335,6 +5511,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub face_bound: FaceBound,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
@@ -5345,10 +5522,12 @@ pub mod config_control_design {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub face: Face,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub face_geometry: SurfaceAny,
@@ -5413,6 +5592,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub manifold_solid_brep: ManifoldSolidBrep,
     }
     #[derive(
@@ -5427,6 +5607,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -5444,6 +5625,8 @@ pub mod config_control_design {
         CompositeCurveSegment(Box<CompositeCurveSegmentAny>),
         #[holder(use_place_holder)]
         SurfacePatch(Box<SurfacePatch>),
+        #[holder(complex)]
+        CompositeCurveSegmentAndSurfacePatch(Box<(CompositeCurveSegmentAny, SurfacePatch)>),
     }
     impl Into<FoundedItemAny> for FoundedItem {
         fn into(self) -> FoundedItemAny {
@@ -5466,6 +5649,7 @@ pub mod config_control_design {
                 FoundedItemAny::FoundedItem(x) => x.as_ref(),
                 FoundedItemAny::CompositeCurveSegment(x) => (**x).as_ref(),
                 FoundedItemAny::SurfacePatch(x) => (**x).as_ref(),
+                FoundedItemAny::CompositeCurveSegmentAndSurfacePatch(x) => x.0.as_ref(),
             }
         }
     }
@@ -5524,6 +5708,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_set: GeometricSet,
     }
     #[derive(
@@ -5538,6 +5723,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub coordinate_space_dimension: DimensionCount,
@@ -5554,6 +5740,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -5774,6 +5961,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub elements: Vec<GeometricSetSelect>,
@@ -5827,6 +6015,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
     #[derive(
@@ -5841,6 +6030,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
     #[derive(
@@ -5855,6 +6045,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub uncertainty: Vec<UncertaintyMeasureWithUnit>,
@@ -5871,6 +6062,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub units: Vec<Unit>,
@@ -5887,6 +6079,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub semi_axis: PositiveLengthMeasure,
@@ -5905,6 +6098,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface_curve: SurfaceCurve,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -5933,6 +6127,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
     #[derive(
@@ -5947,6 +6142,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
     #[derive(
@@ -5961,6 +6157,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub pnt: CartesianPoint,
@@ -5993,6 +6190,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6060,6 +6258,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub effectivity: Effectivity,
         #[holder(use_place_holder)]
         pub effectivity_lot_id: Identifier,
@@ -6078,6 +6277,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub solid_model: SolidModel,
         #[holder(use_place_holder)]
         pub outer: ClosedShellAny,
@@ -6092,6 +6292,8 @@ pub mod config_control_design {
         BrepWithVoids(Box<BrepWithVoids>),
         #[holder(use_place_holder)]
         FacetedBrep(Box<FacetedBrep>),
+        #[holder(complex)]
+        BrepWithVoidsAndFacetedBrep(Box<(BrepWithVoids, FacetedBrep)>),
     }
     impl Into<ManifoldSolidBrepAny> for ManifoldSolidBrep {
         fn into(self) -> ManifoldSolidBrepAny {
@@ -6114,6 +6316,7 @@ pub mod config_control_design {
                 ManifoldSolidBrepAny::ManifoldSolidBrep(x) => x.as_ref(),
                 ManifoldSolidBrepAny::BrepWithVoids(x) => (**x).as_ref(),
                 ManifoldSolidBrepAny::FacetedBrep(x) => (**x).as_ref(),
+                ManifoldSolidBrepAny::BrepWithVoidsAndFacetedBrep(x) => x.0.as_ref(),
             }
         }
     }
@@ -6129,6 +6332,9 @@ pub mod config_control_design {
                 ManifoldSolidBrepAny::FacetedBrep(x) => {
                     AsRef::<ManifoldSolidBrep>::as_ref(x.as_ref()).as_ref()
                 }
+                ManifoldSolidBrepAny::BrepWithVoidsAndFacetedBrep(x) => {
+                    AsRef::<ManifoldSolidBrep>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -6144,6 +6350,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
     #[derive(
@@ -6158,6 +6365,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
         #[holder(use_place_holder)]
         pub mapping_source: RepresentationMap,
@@ -6176,6 +6384,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
     #[derive(
@@ -6190,6 +6399,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -6222,6 +6432,30 @@ pub mod config_control_design {
         UncertaintyMeasureWithUnit(Box<UncertaintyMeasureWithUnit>),
         #[holder(use_place_holder)]
         VolumeMeasureWithUnit(Box<VolumeMeasureWithUnit>),
+        #[holder(complex)]
+        AreaMeasureWithUnitAndUncertaintyMeasureWithUnit(
+            Box<(AreaMeasureWithUnit, UncertaintyMeasureWithUnit)>,
+        ),
+        #[holder(complex)]
+        LengthMeasureWithUnitAndUncertaintyMeasureWithUnit(
+            Box<(LengthMeasureWithUnit, UncertaintyMeasureWithUnit)>,
+        ),
+        #[holder(complex)]
+        MassMeasureWithUnitAndUncertaintyMeasureWithUnit(
+            Box<(MassMeasureWithUnit, UncertaintyMeasureWithUnit)>,
+        ),
+        #[holder(complex)]
+        PlaneAngleMeasureWithUnitAndUncertaintyMeasureWithUnit(
+            Box<(PlaneAngleMeasureWithUnit, UncertaintyMeasureWithUnit)>,
+        ),
+        #[holder(complex)]
+        SolidAngleMeasureWithUnitAndUncertaintyMeasureWithUnit(
+            Box<(SolidAngleMeasureWithUnit, UncertaintyMeasureWithUnit)>,
+        ),
+        #[holder(complex)]
+        UncertaintyMeasureWithUnitAndVolumeMeasureWithUnit(
+            Box<(UncertaintyMeasureWithUnit, VolumeMeasureWithUnit)>,
+        ),
     }
     impl Into<MeasureWithUnitAny> for MeasureWithUnit {
         fn into(self) -> MeasureWithUnitAny {
@@ -6274,6 +6508,24 @@ pub mod config_control_design {
                 MeasureWithUnitAny::SolidAngleMeasureWithUnit(x) => (**x).as_ref(),
                 MeasureWithUnitAny::UncertaintyMeasureWithUnit(x) => (**x).as_ref(),
                 MeasureWithUnitAny::VolumeMeasureWithUnit(x) => (**x).as_ref(),
+                MeasureWithUnitAny::AreaMeasureWithUnitAndUncertaintyMeasureWithUnit(x) => {
+                    x.0.as_ref()
+                }
+                MeasureWithUnitAny::LengthMeasureWithUnitAndUncertaintyMeasureWithUnit(x) => {
+                    x.0.as_ref()
+                }
+                MeasureWithUnitAny::MassMeasureWithUnitAndUncertaintyMeasureWithUnit(x) => {
+                    x.0.as_ref()
+                }
+                MeasureWithUnitAny::PlaneAngleMeasureWithUnitAndUncertaintyMeasureWithUnit(x) => {
+                    x.0.as_ref()
+                }
+                MeasureWithUnitAny::SolidAngleMeasureWithUnitAndUncertaintyMeasureWithUnit(x) => {
+                    x.0.as_ref()
+                }
+                MeasureWithUnitAny::UncertaintyMeasureWithUnitAndVolumeMeasureWithUnit(x) => {
+                    x.0.as_ref()
+                }
             }
         }
     }
@@ -6289,6 +6541,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_context: ProductContext,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -6323,6 +6576,42 @@ pub mod config_control_design {
         SolidAngleUnit(Box<SolidAngleUnit>),
         #[holder(use_place_holder)]
         VolumeUnit(Box<VolumeUnit>),
+        #[holder(complex)]
+        AreaUnitAndContextDependentUnit(Box<(AreaUnit, ContextDependentUnit)>),
+        #[holder(complex)]
+        AreaUnitAndConversionBasedUnit(Box<(AreaUnit, ConversionBasedUnit)>),
+        #[holder(complex)]
+        AreaUnitAndSiUnit(Box<(AreaUnit, SiUnit)>),
+        #[holder(complex)]
+        ContextDependentUnitAndLengthUnit(Box<(ContextDependentUnit, LengthUnit)>),
+        #[holder(complex)]
+        ContextDependentUnitAndMassUnit(Box<(ContextDependentUnit, MassUnit)>),
+        #[holder(complex)]
+        ContextDependentUnitAndPlaneAngleUnit(Box<(ContextDependentUnit, PlaneAngleUnit)>),
+        #[holder(complex)]
+        ContextDependentUnitAndSolidAngleUnit(Box<(ContextDependentUnit, SolidAngleUnit)>),
+        #[holder(complex)]
+        ContextDependentUnitAndVolumeUnit(Box<(ContextDependentUnit, VolumeUnit)>),
+        #[holder(complex)]
+        ConversionBasedUnitAndLengthUnit(Box<(ConversionBasedUnit, LengthUnit)>),
+        #[holder(complex)]
+        ConversionBasedUnitAndMassUnit(Box<(ConversionBasedUnit, MassUnit)>),
+        #[holder(complex)]
+        ConversionBasedUnitAndPlaneAngleUnit(Box<(ConversionBasedUnit, PlaneAngleUnit)>),
+        #[holder(complex)]
+        ConversionBasedUnitAndSolidAngleUnit(Box<(ConversionBasedUnit, SolidAngleUnit)>),
+        #[holder(complex)]
+        ConversionBasedUnitAndVolumeUnit(Box<(ConversionBasedUnit, VolumeUnit)>),
+        #[holder(complex)]
+        LengthUnitAndSiUnit(Box<(LengthUnit, SiUnit)>),
+        #[holder(complex)]
+        MassUnitAndSiUnit(Box<(MassUnit, SiUnit)>),
+        #[holder(complex)]
+        PlaneAngleUnitAndSiUnit(Box<(PlaneAngleUnit, SiUnit)>),
+        #[holder(complex)]
+        SiUnitAndSolidAngleUnit(Box<(SiUnit, SolidAngleUnit)>),
+        #[holder(complex)]
+        SiUnitAndVolumeUnit(Box<(SiUnit, VolumeUnit)>),
     }
     impl Into<NamedUnitAny> for NamedUnit {
         fn into(self) -> NamedUnitAny {
@@ -6387,6 +6676,24 @@ pub mod config_control_design {
                 NamedUnitAny::SiUnit(x) => (**x).as_ref(),
                 NamedUnitAny::SolidAngleUnit(x) => (**x).as_ref(),
                 NamedUnitAny::VolumeUnit(x) => (**x).as_ref(),
+                NamedUnitAny::AreaUnitAndContextDependentUnit(x) => x.0.as_ref(),
+                NamedUnitAny::AreaUnitAndConversionBasedUnit(x) => x.0.as_ref(),
+                NamedUnitAny::AreaUnitAndSiUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ContextDependentUnitAndLengthUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ContextDependentUnitAndMassUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ContextDependentUnitAndPlaneAngleUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ContextDependentUnitAndSolidAngleUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ContextDependentUnitAndVolumeUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ConversionBasedUnitAndLengthUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ConversionBasedUnitAndMassUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ConversionBasedUnitAndPlaneAngleUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ConversionBasedUnitAndSolidAngleUnit(x) => x.0.as_ref(),
+                NamedUnitAny::ConversionBasedUnitAndVolumeUnit(x) => x.0.as_ref(),
+                NamedUnitAny::LengthUnitAndSiUnit(x) => x.0.as_ref(),
+                NamedUnitAny::MassUnitAndSiUnit(x) => x.0.as_ref(),
+                NamedUnitAny::PlaneAngleUnitAndSiUnit(x) => x.0.as_ref(),
+                NamedUnitAny::SiUnitAndSolidAngleUnit(x) => x.0.as_ref(),
+                NamedUnitAny::SiUnitAndVolumeUnit(x) => x.0.as_ref(),
             }
         }
     }
@@ -6402,6 +6709,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub assembly_component_usage: AssemblyComponentUsage,
     }
     #[derive(
@@ -6416,6 +6724,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -6437,6 +6746,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -6456,6 +6766,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub connected_face_set: ConnectedFaceSet,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6507,6 +6818,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date: Date,
         #[holder(use_place_holder)]
         pub day_component: DayInYearNumber,
@@ -6549,6 +6861,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub address: Address,
         #[holder(use_place_holder)]
         pub organizations: Vec<Organization>,
@@ -6579,6 +6892,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub closed_shell: ClosedShell,
         #[holder(use_place_holder)]
         pub closed_shell_element: ClosedShellAny,
@@ -6596,6 +6910,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub edge: Edge,
         #[holder(use_place_holder)]
         pub edge_element: EdgeAny,
@@ -6613,6 +6928,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub face: Face,
         #[holder(use_place_holder)]
         pub face_element: FaceAny,
@@ -6630,6 +6946,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub open_shell: OpenShell,
         #[holder(use_place_holder)]
         pub open_shell_element: OpenShellAny,
@@ -6647,6 +6964,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub path: Path,
         #[holder(use_place_holder)]
         pub path_element: PathAny,
@@ -6664,6 +6982,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub boundary_curve: BoundaryCurve,
     }
     #[derive(
@@ -6678,6 +6997,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub focal_dist: LengthMeasure,
@@ -6694,6 +7014,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
     }
     #[derive(
@@ -6708,6 +7029,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub edge_list: Vec<OrientedEdge>,
@@ -6768,6 +7090,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -6900,6 +7223,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub address: Address,
         #[holder(use_place_holder)]
         pub people: Vec<Person>,
@@ -6918,6 +7242,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub location: CartesianPoint,
@@ -6991,6 +7316,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
     }
     #[derive(
@@ -7005,6 +7331,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
     #[derive(
@@ -7019,6 +7346,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
     #[derive(
@@ -7033,6 +7361,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -7118,6 +7447,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -7136,6 +7466,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -7156,6 +7487,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub parent_pt: PointAny,
@@ -7170,10 +7502,12 @@ pub mod config_control_design {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub r#loop: Loop,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub polygon: Vec<CartesianPoint>,
@@ -7190,6 +7524,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub points: Vec<CartesianPoint>,
@@ -7285,6 +7620,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub market_segment_type: Label,
@@ -7301,6 +7637,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub discipline_type: Label,
@@ -7395,6 +7732,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub life_cycle_stage: Label,
@@ -7450,6 +7788,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub effectivity: Effectivity,
         #[holder(use_place_holder)]
         pub usage: ProductDefinitionRelationshipAny,
@@ -7550,6 +7889,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_formation: ProductDefinitionFormation,
         pub make_or_buy: Source,
     }
@@ -7581,6 +7921,26 @@ pub mod config_control_design {
         ProductDefinitionUsage(Box<ProductDefinitionUsageAny>),
         #[holder(use_place_holder)]
         SuppliedPartRelationship(Box<SuppliedPartRelationship>),
+        #[holder(complex)]
+        DesignMakeFromRelationshipAndProductDefinitionUsage(
+            Box<(DesignMakeFromRelationship, ProductDefinitionUsageAny)>,
+        ),
+        #[holder(complex)]
+        DesignMakeFromRelationshipAndSuppliedPartRelationship(
+            Box<(DesignMakeFromRelationship, SuppliedPartRelationship)>,
+        ),
+        #[holder(complex)]
+        ProductDefinitionUsageAndSuppliedPartRelationship(
+            Box<(ProductDefinitionUsageAny, SuppliedPartRelationship)>,
+        ),
+        #[holder(complex)]
+        DesignMakeFromRelationshipAndProductDefinitionUsageAndSuppliedPartRelationship(
+            Box<(
+                DesignMakeFromRelationship,
+                ProductDefinitionUsageAny,
+                SuppliedPartRelationship,
+            )>,
+        ),
     }
     impl Into<ProductDefinitionRelationshipAny> for ProductDefinitionRelationship {
         fn into(self) -> ProductDefinitionRelationshipAny {
@@ -7604,12 +7964,7 @@ pub mod config_control_design {
     }
     impl AsRef<ProductDefinitionRelationship> for ProductDefinitionRelationshipAny {
         fn as_ref(&self) -> &ProductDefinitionRelationship {
-            match self {
-                ProductDefinitionRelationshipAny::ProductDefinitionRelationship(x) => x.as_ref(),
-                ProductDefinitionRelationshipAny::DesignMakeFromRelationship(x) => (**x).as_ref(),
-                ProductDefinitionRelationshipAny::ProductDefinitionUsage(x) => (**x).as_ref(),
-                ProductDefinitionRelationshipAny::SuppliedPartRelationship(x) => (**x).as_ref(),
-            }
+            match self { ProductDefinitionRelationshipAny :: ProductDefinitionRelationship (x) => x . as_ref () , ProductDefinitionRelationshipAny :: DesignMakeFromRelationship (x) => (* * x) . as_ref () , ProductDefinitionRelationshipAny :: ProductDefinitionUsage (x) => (* * x) . as_ref () , ProductDefinitionRelationshipAny :: SuppliedPartRelationship (x) => (* * x) . as_ref () , ProductDefinitionRelationshipAny :: DesignMakeFromRelationshipAndProductDefinitionUsage (x) => x . 0 . as_ref () , ProductDefinitionRelationshipAny :: DesignMakeFromRelationshipAndSuppliedPartRelationship (x) => x . 0 . as_ref () , ProductDefinitionRelationshipAny :: ProductDefinitionUsageAndSuppliedPartRelationship (x) => x . 0 . as_ref () , ProductDefinitionRelationshipAny :: DesignMakeFromRelationshipAndProductDefinitionUsageAndSuppliedPartRelationship (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(
@@ -7624,6 +7979,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub property_definition: PropertyDefinition,
     }
     #[derive(
@@ -7638,6 +7994,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_relationship: ProductDefinitionRelationship,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -7691,6 +8048,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition: ProductDefinition,
         #[holder(use_place_holder)]
         pub documentation_ids: Vec<DocumentAny>,
@@ -7707,6 +8065,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_category: ProductCategory,
         #[holder(use_place_holder)]
         pub products: Vec<Product>,
@@ -7723,6 +8082,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub assembly_component_usage: AssemblyComponentUsage,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -7819,6 +8179,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub assembly_component_usage: AssemblyComponentUsage,
         #[holder(use_place_holder)]
         pub quantity: MeasureWithUnitAny,
@@ -7835,6 +8196,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
     #[derive(
@@ -7849,6 +8211,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
     }
     #[derive(
@@ -7863,6 +8226,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
         pub weights_data: Vec<f64>,
     }
@@ -7878,6 +8242,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
         pub weights_data: Vec<Vec<f64>>,
     }
@@ -7893,6 +8258,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_surface: BoundedSurface,
         #[holder(use_place_holder)]
         pub segments: Vec<Vec<SurfacePatch>>,
@@ -7909,6 +8275,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_surface: BoundedSurface,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -7935,6 +8302,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_curve_segment: CompositeCurveSegment,
         #[holder(use_place_holder)]
         pub param_length: ParameterValue,
@@ -7961,6 +8329,10 @@ pub mod config_control_design {
         DefinitionalRepresentation(Box<DefinitionalRepresentation>),
         #[holder(use_place_holder)]
         ShapeRepresentation(Box<ShapeRepresentationAny>),
+        #[holder(complex)]
+        DefinitionalRepresentationAndShapeRepresentation(
+            Box<(DefinitionalRepresentation, ShapeRepresentationAny)>,
+        ),
     }
     impl Into<RepresentationAny> for Representation {
         fn into(self) -> RepresentationAny {
@@ -7983,6 +8355,9 @@ pub mod config_control_design {
                 RepresentationAny::Representation(x) => x.as_ref(),
                 RepresentationAny::DefinitionalRepresentation(x) => (**x).as_ref(),
                 RepresentationAny::ShapeRepresentation(x) => (**x).as_ref(),
+                RepresentationAny::DefinitionalRepresentationAndShapeRepresentation(x) => {
+                    x.0.as_ref()
+                }
             }
         }
     }
@@ -8010,6 +8385,80 @@ pub mod config_control_design {
         GlobalUnitAssignedContext(Box<GlobalUnitAssignedContext>),
         #[holder(use_place_holder)]
         ParametricRepresentationContext(Box<ParametricRepresentationContext>),
+        #[holder(complex)]
+        GeometricRepresentationContextAndGlobalUncertaintyAssignedContext(
+            Box<(
+                GeometricRepresentationContext,
+                GlobalUncertaintyAssignedContext,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricRepresentationContextAndGlobalUnitAssignedContext(
+            Box<(GeometricRepresentationContext, GlobalUnitAssignedContext)>,
+        ),
+        #[holder(complex)]
+        GeometricRepresentationContextAndParametricRepresentationContext(
+            Box<(
+                GeometricRepresentationContext,
+                ParametricRepresentationContext,
+            )>,
+        ),
+        #[holder(complex)]
+        GlobalUncertaintyAssignedContextAndGlobalUnitAssignedContext(
+            Box<(GlobalUncertaintyAssignedContext, GlobalUnitAssignedContext)>,
+        ),
+        #[holder(complex)]
+        GlobalUncertaintyAssignedContextAndParametricRepresentationContext(
+            Box<(
+                GlobalUncertaintyAssignedContext,
+                ParametricRepresentationContext,
+            )>,
+        ),
+        #[holder(complex)]
+        GlobalUnitAssignedContextAndParametricRepresentationContext(
+            Box<(GlobalUnitAssignedContext, ParametricRepresentationContext)>,
+        ),
+        #[holder(complex)]
+        GeometricRepresentationContextAndGlobalUncertaintyAssignedContextAndGlobalUnitAssignedContext(
+            Box<(
+                GeometricRepresentationContext,
+                GlobalUncertaintyAssignedContext,
+                GlobalUnitAssignedContext,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricRepresentationContextAndGlobalUncertaintyAssignedContextAndParametricRepresentationContext(
+            Box<(
+                GeometricRepresentationContext,
+                GlobalUncertaintyAssignedContext,
+                ParametricRepresentationContext,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricRepresentationContextAndGlobalUnitAssignedContextAndParametricRepresentationContext(
+            Box<(
+                GeometricRepresentationContext,
+                GlobalUnitAssignedContext,
+                ParametricRepresentationContext,
+            )>,
+        ),
+        #[holder(complex)]
+        GlobalUncertaintyAssignedContextAndGlobalUnitAssignedContextAndParametricRepresentationContext(
+            Box<(
+                GlobalUncertaintyAssignedContext,
+                GlobalUnitAssignedContext,
+                ParametricRepresentationContext,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricRepresentationContextAndGlobalUncertaintyAssignedContextAndGlobalUnitAssignedContextAndParametricRepresentationContext(
+            Box<(
+                GeometricRepresentationContext,
+                GlobalUncertaintyAssignedContext,
+                GlobalUnitAssignedContext,
+                ParametricRepresentationContext,
+            )>,
+        ),
     }
     impl Into<RepresentationContextAny> for RepresentationContext {
         fn into(self) -> RepresentationContextAny {
@@ -8038,13 +8487,7 @@ pub mod config_control_design {
     }
     impl AsRef<RepresentationContext> for RepresentationContextAny {
         fn as_ref(&self) -> &RepresentationContext {
-            match self {
-                RepresentationContextAny::RepresentationContext(x) => x.as_ref(),
-                RepresentationContextAny::GeometricRepresentationContext(x) => (**x).as_ref(),
-                RepresentationContextAny::GlobalUncertaintyAssignedContext(x) => (**x).as_ref(),
-                RepresentationContextAny::GlobalUnitAssignedContext(x) => (**x).as_ref(),
-                RepresentationContextAny::ParametricRepresentationContext(x) => (**x).as_ref(),
-            }
+            match self { RepresentationContextAny :: RepresentationContext (x) => x . as_ref () , RepresentationContextAny :: GeometricRepresentationContext (x) => (* * x) . as_ref () , RepresentationContextAny :: GlobalUncertaintyAssignedContext (x) => (* * x) . as_ref () , RepresentationContextAny :: GlobalUnitAssignedContext (x) => (* * x) . as_ref () , RepresentationContextAny :: ParametricRepresentationContext (x) => (* * x) . as_ref () , RepresentationContextAny :: GeometricRepresentationContextAndGlobalUncertaintyAssignedContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GeometricRepresentationContextAndGlobalUnitAssignedContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GeometricRepresentationContextAndParametricRepresentationContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GlobalUncertaintyAssignedContextAndGlobalUnitAssignedContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GlobalUncertaintyAssignedContextAndParametricRepresentationContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GlobalUnitAssignedContextAndParametricRepresentationContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GeometricRepresentationContextAndGlobalUncertaintyAssignedContextAndGlobalUnitAssignedContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GeometricRepresentationContextAndGlobalUncertaintyAssignedContextAndParametricRepresentationContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GeometricRepresentationContextAndGlobalUnitAssignedContextAndParametricRepresentationContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GlobalUncertaintyAssignedContextAndGlobalUnitAssignedContextAndParametricRepresentationContext (x) => x . 0 . as_ref () , RepresentationContextAny :: GeometricRepresentationContextAndGlobalUncertaintyAssignedContextAndGlobalUnitAssignedContextAndParametricRepresentationContext (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -8067,6 +8510,27 @@ pub mod config_control_design {
         MappedItem(Box<MappedItem>),
         #[holder(use_place_holder)]
         TopologicalRepresentationItem(Box<TopologicalRepresentationItemAny>),
+        #[holder(complex)]
+        GeometricRepresentationItemAndMappedItem(Box<(GeometricRepresentationItemAny, MappedItem)>),
+        #[holder(complex)]
+        GeometricRepresentationItemAndTopologicalRepresentationItem(
+            Box<(
+                GeometricRepresentationItemAny,
+                TopologicalRepresentationItemAny,
+            )>,
+        ),
+        #[holder(complex)]
+        MappedItemAndTopologicalRepresentationItem(
+            Box<(MappedItem, TopologicalRepresentationItemAny)>,
+        ),
+        #[holder(complex)]
+        GeometricRepresentationItemAndMappedItemAndTopologicalRepresentationItem(
+            Box<(
+                GeometricRepresentationItemAny,
+                MappedItem,
+                TopologicalRepresentationItemAny,
+            )>,
+        ),
     }
     impl Into<RepresentationItemAny> for RepresentationItem {
         fn into(self) -> RepresentationItemAny {
@@ -8090,12 +8554,7 @@ pub mod config_control_design {
     }
     impl AsRef<RepresentationItem> for RepresentationItemAny {
         fn as_ref(&self) -> &RepresentationItem {
-            match self {
-                RepresentationItemAny::RepresentationItem(x) => x.as_ref(),
-                RepresentationItemAny::GeometricRepresentationItem(x) => (**x).as_ref(),
-                RepresentationItemAny::MappedItem(x) => (**x).as_ref(),
-                RepresentationItemAny::TopologicalRepresentationItem(x) => (**x).as_ref(),
-            }
+            match self { RepresentationItemAny :: RepresentationItem (x) => x . as_ref () , RepresentationItemAny :: GeometricRepresentationItem (x) => (* * x) . as_ref () , RepresentationItemAny :: MappedItem (x) => (* * x) . as_ref () , RepresentationItemAny :: TopologicalRepresentationItem (x) => (* * x) . as_ref () , RepresentationItemAny :: GeometricRepresentationItemAndMappedItem (x) => x . 0 . as_ref () , RepresentationItemAny :: GeometricRepresentationItemAndTopologicalRepresentationItem (x) => x . 0 . as_ref () , RepresentationItemAny :: MappedItemAndTopologicalRepresentationItem (x) => x . 0 . as_ref () , RepresentationItemAny :: GeometricRepresentationItemAndMappedItemAndTopologicalRepresentationItem (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -8134,6 +8593,13 @@ pub mod config_control_design {
         ),
         #[holder(use_place_holder)]
         ShapeRepresentationRelationship(Box<ShapeRepresentationRelationship>),
+        #[holder(complex)]
+        RepresentationRelationshipWithTransformationAndShapeRepresentationRelationship(
+            Box<(
+                RepresentationRelationshipWithTransformation,
+                ShapeRepresentationRelationship,
+            )>,
+        ),
     }
     impl Into<RepresentationRelationshipAny> for RepresentationRelationship {
         fn into(self) -> RepresentationRelationshipAny {
@@ -8154,13 +8620,7 @@ pub mod config_control_design {
     }
     impl AsRef<RepresentationRelationship> for RepresentationRelationshipAny {
         fn as_ref(&self) -> &RepresentationRelationship {
-            match self {
-                RepresentationRelationshipAny::RepresentationRelationship(x) => x.as_ref(),
-                RepresentationRelationshipAny::RepresentationRelationshipWithTransformation(x) => {
-                    (**x).as_ref()
-                }
-                RepresentationRelationshipAny::ShapeRepresentationRelationship(x) => (**x).as_ref(),
-            }
+            match self { RepresentationRelationshipAny :: RepresentationRelationship (x) => x . as_ref () , RepresentationRelationshipAny :: RepresentationRelationshipWithTransformation (x) => (* * x) . as_ref () , RepresentationRelationshipAny :: ShapeRepresentationRelationship (x) => (* * x) . as_ref () , RepresentationRelationshipAny :: RepresentationRelationshipWithTransformationAndShapeRepresentationRelationship (x) => x . 0 . as_ref () , }
         }
     }
     #[derive(
@@ -8175,6 +8635,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_relationship: RepresentationRelationship,
         #[holder(use_place_holder)]
         pub transformation_operator: Transformation,
@@ -8191,6 +8652,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface_curve: SurfaceCurve,
     }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
@@ -8266,6 +8728,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub effectivity: Effectivity,
         #[holder(use_place_holder)]
         pub effectivity_start_id: Identifier,
@@ -8311,6 +8774,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub property_definition_representation: PropertyDefinitionRepresentation,
     }
     #[derive(
@@ -8325,6 +8789,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -8351,6 +8816,1047 @@ pub mod config_control_design {
         ManifoldSurfaceShapeRepresentation(Box<ManifoldSurfaceShapeRepresentation>),
         #[holder(use_place_holder)]
         ShellBasedWireframeShapeRepresentation(Box<ShellBasedWireframeShapeRepresentation>),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        ManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
+        #[holder(complex)]
+        AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation(
+            Box<(
+                AdvancedBrepShapeRepresentation,
+                EdgeBasedWireframeShapeRepresentation,
+                FacetedBrepShapeRepresentation,
+                GeometricallyBoundedSurfaceShapeRepresentation,
+                GeometricallyBoundedWireframeShapeRepresentation,
+                ManifoldSurfaceShapeRepresentation,
+                ShellBasedWireframeShapeRepresentation,
+            )>,
+        ),
     }
     impl Into<ShapeRepresentationAny> for ShapeRepresentation {
         fn into(self) -> ShapeRepresentationAny {
@@ -8398,50 +9904,12 @@ pub mod config_control_design {
     }
     impl AsRef<ShapeRepresentation> for ShapeRepresentationAny {
         fn as_ref(&self) -> &ShapeRepresentation {
-            match self {
-                ShapeRepresentationAny::ShapeRepresentation(x) => x.as_ref(),
-                ShapeRepresentationAny::AdvancedBrepShapeRepresentation(x) => (**x).as_ref(),
-                ShapeRepresentationAny::EdgeBasedWireframeShapeRepresentation(x) => (**x).as_ref(),
-                ShapeRepresentationAny::FacetedBrepShapeRepresentation(x) => (**x).as_ref(),
-                ShapeRepresentationAny::GeometricallyBoundedSurfaceShapeRepresentation(x) => {
-                    (**x).as_ref()
-                }
-                ShapeRepresentationAny::GeometricallyBoundedWireframeShapeRepresentation(x) => {
-                    (**x).as_ref()
-                }
-                ShapeRepresentationAny::ManifoldSurfaceShapeRepresentation(x) => (**x).as_ref(),
-                ShapeRepresentationAny::ShellBasedWireframeShapeRepresentation(x) => (**x).as_ref(),
-            }
+            match self { ShapeRepresentationAny :: ShapeRepresentation (x) => x . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentation (x) => (* * x) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentation (x) => (* * x) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentation (x) => (* * x) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentation (x) => (* * x) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedWireframeShapeRepresentation (x) => (* * x) . as_ref () , ShapeRepresentationAny :: ManifoldSurfaceShapeRepresentation (x) => (* * x) . as_ref () , ShapeRepresentationAny :: ShellBasedWireframeShapeRepresentation (x) => (* * x) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: ManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => x . 0 . as_ref () , }
         }
     }
     impl AsRef<Representation> for ShapeRepresentationAny {
         fn as_ref(&self) -> &Representation {
-            match self {
-                ShapeRepresentationAny::ShapeRepresentation(x) => {
-                    AsRef::<ShapeRepresentation>::as_ref(x).as_ref()
-                }
-                ShapeRepresentationAny::AdvancedBrepShapeRepresentation(x) => {
-                    AsRef::<ShapeRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-                ShapeRepresentationAny::EdgeBasedWireframeShapeRepresentation(x) => {
-                    AsRef::<ShapeRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-                ShapeRepresentationAny::FacetedBrepShapeRepresentation(x) => {
-                    AsRef::<ShapeRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-                ShapeRepresentationAny::GeometricallyBoundedSurfaceShapeRepresentation(x) => {
-                    AsRef::<ShapeRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-                ShapeRepresentationAny::GeometricallyBoundedWireframeShapeRepresentation(x) => {
-                    AsRef::<ShapeRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-                ShapeRepresentationAny::ManifoldSurfaceShapeRepresentation(x) => {
-                    AsRef::<ShapeRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-                ShapeRepresentationAny::ShellBasedWireframeShapeRepresentation(x) => {
-                    AsRef::<ShapeRepresentation>::as_ref(x.as_ref()).as_ref()
-                }
-            }
+            match self { ShapeRepresentationAny :: ShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (x) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (x . as_ref ()) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (x . as_ref ()) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (x . as_ref ()) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (x . as_ref ()) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (x . as_ref ()) . as_ref () , ShapeRepresentationAny :: ManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (x . as_ref ()) . as_ref () , ShapeRepresentationAny :: ShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (x . as_ref ()) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: ManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: GeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: FacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: EdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , ShapeRepresentationAny :: AdvancedBrepShapeRepresentationAndEdgeBasedWireframeShapeRepresentationAndFacetedBrepShapeRepresentationAndGeometricallyBoundedSurfaceShapeRepresentationAndGeometricallyBoundedWireframeShapeRepresentationAndManifoldSurfaceShapeRepresentationAndShellBasedWireframeShapeRepresentation (x) => AsRef :: < ShapeRepresentation > :: as_ref (& x . 0) . as_ref () , }
         }
     }
     #[derive(
@@ -8456,6 +9924,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_relationship: RepresentationRelationship,
     }
     #[derive(
@@ -8470,6 +9939,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub sbsm_boundary: Vec<Shell>,
@@ -8486,6 +9956,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub sbwm_boundary: Vec<Shell>,
@@ -8502,6 +9973,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
     #[derive(
@@ -8516,6 +9988,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         pub prefix: Option<SiPrefix>,
         pub name: SiUnitName,
@@ -8532,6 +10005,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
     #[derive(
@@ -8546,6 +10020,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
     #[derive(
@@ -8560,6 +10035,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -8611,6 +10087,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub assembly_component_usage: AssemblyComponentUsage,
         #[holder(use_place_holder)]
         pub upper_usage: AssemblyComponentUsageAny,
@@ -8629,6 +10106,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
@@ -8645,6 +10123,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action_request_assignment: ActionRequestAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<StartRequestItem>,
@@ -8661,6 +10140,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action_assignment: ActionAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<WorkItem>,
@@ -8677,6 +10157,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_relationship: ProductDefinitionRelationship,
     }
     #[derive(
@@ -8691,6 +10172,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -8776,6 +10258,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub curve_3d: CurveAny,
@@ -8795,6 +10278,10 @@ pub mod config_control_design {
         IntersectionCurve(Box<IntersectionCurve>),
         #[holder(use_place_holder)]
         SeamCurve(Box<SeamCurve>),
+        #[holder(complex)]
+        BoundedSurfaceCurveAndIntersectionCurve(Box<(BoundedSurfaceCurve, IntersectionCurve)>),
+        #[holder(complex)]
+        BoundedSurfaceCurveAndSeamCurve(Box<(BoundedSurfaceCurve, SeamCurve)>),
     }
     impl Into<SurfaceCurveAny> for SurfaceCurve {
         fn into(self) -> SurfaceCurveAny {
@@ -8823,6 +10310,8 @@ pub mod config_control_design {
                 SurfaceCurveAny::BoundedSurfaceCurve(x) => (**x).as_ref(),
                 SurfaceCurveAny::IntersectionCurve(x) => (**x).as_ref(),
                 SurfaceCurveAny::SeamCurve(x) => (**x).as_ref(),
+                SurfaceCurveAny::BoundedSurfaceCurveAndIntersectionCurve(x) => x.0.as_ref(),
+                SurfaceCurveAny::BoundedSurfaceCurveAndSeamCurve(x) => x.0.as_ref(),
             }
         }
     }
@@ -8837,6 +10326,12 @@ pub mod config_control_design {
                     AsRef::<SurfaceCurve>::as_ref(x.as_ref()).as_ref()
                 }
                 SurfaceCurveAny::SeamCurve(x) => AsRef::<SurfaceCurve>::as_ref(x.as_ref()).as_ref(),
+                SurfaceCurveAny::BoundedSurfaceCurveAndIntersectionCurve(x) => {
+                    AsRef::<SurfaceCurve>::as_ref(&x.0).as_ref()
+                }
+                SurfaceCurveAny::BoundedSurfaceCurveAndSeamCurve(x) => {
+                    AsRef::<SurfaceCurve>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -8852,6 +10347,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub swept_surface: SweptSurface,
         #[holder(use_place_holder)]
         pub extrusion_axis: Vector,
@@ -8868,6 +10364,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub swept_surface: SweptSurface,
         #[holder(use_place_holder)]
         pub axis_position: Axis1Placement,
@@ -8884,6 +10381,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub founded_item: FoundedItem,
         #[holder(use_place_holder)]
         pub parent_surface: BoundedSurfaceAny,
@@ -8904,6 +10402,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
         #[holder(use_place_holder)]
         pub parent_surface: SurfaceAny,
@@ -8922,6 +10421,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
         #[holder(use_place_holder)]
         pub swept_curve: CurveAny,
@@ -8986,6 +10486,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -9014,6 +10515,8 @@ pub mod config_control_design {
         VertexShell(Box<VertexShell>),
         #[holder(use_place_holder)]
         WireShell(Box<WireShell>),
+        #[holder(complex)]
+        LoopAndPath(Box<(LoopAny, PathAny)>),
     }
     impl Into<TopologicalRepresentationItemAny> for TopologicalRepresentationItem {
         fn into(self) -> TopologicalRepresentationItemAny {
@@ -9084,6 +10587,7 @@ pub mod config_control_design {
                 TopologicalRepresentationItemAny::Vertex(x) => (**x).as_ref(),
                 TopologicalRepresentationItemAny::VertexShell(x) => (**x).as_ref(),
                 TopologicalRepresentationItemAny::WireShell(x) => (**x).as_ref(),
+                TopologicalRepresentationItemAny::LoopAndPath(x) => x.0.as_ref(),
             }
         }
     }
@@ -9123,6 +10627,9 @@ pub mod config_control_design {
                 TopologicalRepresentationItemAny::WireShell(x) => {
                     AsRef::<TopologicalRepresentationItem>::as_ref(x.as_ref()).as_ref()
                 }
+                TopologicalRepresentationItemAny::LoopAndPath(x) => {
+                    AsRef::<TopologicalRepresentationItem>::as_ref(&x.0).as_ref()
+                }
             }
         }
     }
@@ -9138,6 +10645,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
         #[holder(use_place_holder)]
         pub major_radius: PositiveLengthMeasure,
@@ -9195,6 +10703,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -9217,6 +10726,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
         #[holder(use_place_holder)]
         pub name: Label,
@@ -9235,6 +10745,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
     #[derive(
@@ -9249,6 +10760,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
     }
     #[derive(
@@ -9263,6 +10775,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub orientation: Direction,
@@ -9295,6 +10808,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -9344,6 +10858,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub r#loop: Loop,
         #[holder(use_place_holder)]
         pub loop_vertex: VertexAny,
@@ -9356,10 +10871,12 @@ pub mod config_control_design {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub vertex: Vertex,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub vertex_geometry: PointAny,
@@ -9376,6 +10893,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub vertex_shell_extent: VertexLoop,
@@ -9392,6 +10910,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
     #[derive(
@@ -9406,6 +10925,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
     #[derive(
@@ -9420,6 +10940,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date: Date,
         #[holder(use_place_holder)]
         pub week_component: WeekInYearNumber,
@@ -9438,6 +10959,7 @@ pub mod config_control_design {
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub wire_shell_extent: Vec<LoopAny>,
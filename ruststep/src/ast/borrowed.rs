@@ -0,0 +1,378 @@
+//! Zero-copy AST borrowing the input string
+//!
+//! Each component in [ast](super) owns its keywords and strings as [String],
+//! which causes many small allocations while parsing large exchange structures.
+//! The types in this module borrow them from the input string instead:
+//!
+//! - Keywords and enumeration values are `&'a str` slices of the input, and tokenized without allocation.
+//!   Keywords in a [DataSection] are interned into its [KeywordTable],
+//!   i.e. equal keywords are the same slice, and numbered densely by [KeywordTable::id].
+//! - String literals are [StringLiteral], which is borrowed unless decoding is required.
+//!
+//! They are tokenized by [parser::borrowed](crate::parser::borrowed),
+//! and [serde::Deserializer] is implemented as same as for owned AST in [ast::de](super::de).
+//!
+//! ```
+//! use ruststep::ast::{self, borrowed};
+//! use serde::Deserialize;
+//!
+//! let input = "A(1, 'ruststep')";
+//! let record = borrowed::Record::parse(input).unwrap();
+//! assert_eq!(record.name, "A");
+//!
+//! // Deserialize fields borrowing the input
+//! #[derive(Debug, PartialEq, Deserialize)]
+//! #[serde(rename = "A")]
+//! struct A<'a> {
+//!     x: i64,
+//!     name: &'a str,
+//! }
+//! assert_eq!(A::deserialize(&record).unwrap(), A { x: 1, name: "ruststep" });
+//!
+//! // Convert into owned AST
+//! let owned: ast::Record = record.into_owned();
+//! assert_eq!(owned, input.parse().unwrap());
+//! ```
+
+use crate::{ast::Name, parser, primitive::Binary};
+use std::{borrow::Cow, collections::HashMap, ops::Deref};
+
+macro_rules! derive_borrowed_parse {
+    ($ast:ident, $parse:path) => {
+        impl<'a> $ast<'a> {
+            /// Tokenize entire input string
+            pub fn parse(input: &'a str) -> crate::error::Result<Self> {
+                use nom::Finish;
+                let input = input.trim();
+                let (residual, value) = $parse(input)
+                    .finish()
+                    .map_err(|err| crate::parser::tokenize_failed(input, err))?;
+                if !residual.is_empty() {
+                    return Err(crate::error::Error::ExtraInputRemaining(
+                        residual.to_string(),
+                    ));
+                }
+                Ok(value)
+            }
+        }
+    };
+}
+
+/// Borrowed version of [ast::Parameter](super::Parameter)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Parameter<'a> {
+    Typed {
+        keyword: &'a str,
+        parameter: Box<Parameter<'a>>,
+    },
+    Integer(i64),
+    Real(f64),
+    String(StringLiteral<'a>),
+    Enumeration(&'a str),
+    List(Vec<Parameter<'a>>),
+    Ref(Name),
+    Binary(Binary),
+    NotProvided,
+    Omitted,
+}
+derive_borrowed_parse!(Parameter, parser::borrowed::parameter);
+
+impl<'a> Parameter<'a> {
+    /// Convert into owned [ast::Parameter](super::Parameter)
+    pub fn into_owned(self) -> super::Parameter {
+        (&self).into()
+    }
+}
+
+impl<'a> From<&Parameter<'a>> for super::Parameter {
+    fn from(p: &Parameter<'a>) -> Self {
+        match p {
+            Parameter::Typed { keyword, parameter } => super::Parameter::Typed {
+                keyword: keyword.to_string(),
+                parameter: Box::new(parameter.as_ref().into()),
+            },
+            Parameter::Integer(i) => super::Parameter::Integer(*i),
+            Parameter::Real(x) => super::Parameter::Real(*x),
+            Parameter::String(s) => super::Parameter::String(s.into()),
+            Parameter::Enumeration(e) => super::Parameter::Enumeration(e.to_string()),
+            Parameter::List(list) => super::Parameter::List(list.iter().map(Into::into).collect()),
+            Parameter::Ref(name) => super::Parameter::Ref(name.clone()),
+            Parameter::Binary(b) => super::Parameter::Binary(b.clone()),
+            Parameter::NotProvided => super::Parameter::NotProvided,
+            Parameter::Omitted => super::Parameter::Omitted,
+        }
+    }
+}
+
+impl<'a> FromIterator<Parameter<'a>> for Parameter<'a> {
+    fn from_iter<Iter: IntoIterator<Item = Parameter<'a>>>(iter: Iter) -> Self {
+        Parameter::List(iter.into_iter().collect())
+    }
+}
+
+/// Borrowed version of [ast::StringLiteral](super::StringLiteral)
+///
+/// The decoded content borrows the input unless decoding is required.
+#[derive(Debug, Clone)]
+pub struct StringLiteral<'a> {
+    raw: &'a str,
+    decoded: Cow<'a, str>,
+}
+
+impl<'a> StringLiteral<'a> {
+    /// Decode the content of a string literal, i.e. the part between the apostrophes
+    pub fn from_raw(raw: &'a str) -> crate::error::Result<Self> {
+        let decoded = if parser::string::is_plain(raw) {
+            Cow::Borrowed(raw)
+        } else {
+            Cow::Owned(parser::string::decode(raw)?)
+        };
+        Ok(StringLiteral { raw, decoded })
+    }
+
+    /// Encoded form between the apostrophes in the input
+    pub fn raw(&self) -> &'a str {
+        self.raw
+    }
+
+    /// Decoded content
+    pub fn decoded(&self) -> &Cow<'a, str> {
+        &self.decoded
+    }
+}
+
+impl<'a> Deref for StringLiteral<'a> {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.decoded
+    }
+}
+
+impl<'a> PartialEq for StringLiteral<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.decoded == other.decoded
+    }
+}
+
+impl<'a> PartialEq<&str> for StringLiteral<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.decoded == *other
+    }
+}
+
+impl<'a> From<&StringLiteral<'a>> for super::StringLiteral {
+    fn from(s: &StringLiteral<'a>) -> Self {
+        super::StringLiteral::from_parts(s.decoded.to_string(), s.raw)
+    }
+}
+
+/// Borrowed version of [ast::Record](super::Record)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record<'a> {
+    pub name: &'a str,
+    pub parameter: Parameter<'a>,
+}
+derive_borrowed_parse!(Record, parser::borrowed::simple_record);
+
+impl<'a> Record<'a> {
+    /// Convert into owned [ast::Record](super::Record)
+    pub fn into_owned(self) -> super::Record {
+        (&self).into()
+    }
+}
+
+impl<'a> From<&Record<'a>> for super::Record {
+    fn from(record: &Record<'a>) -> Self {
+        super::Record {
+            name: record.name.to_string(),
+            parameter: (&record.parameter).into(),
+        }
+    }
+}
+
+/// Borrowed version of [ast::SubSuperRecord](super::SubSuperRecord)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubSuperRecord<'a>(pub Vec<Record<'a>>);
+derive_borrowed_parse!(SubSuperRecord, parser::borrowed::subsuper_record);
+
+impl<'a> SubSuperRecord<'a> {
+    /// Convert into owned [ast::SubSuperRecord](super::SubSuperRecord)
+    pub fn into_owned(self) -> super::SubSuperRecord {
+        (&self).into()
+    }
+}
+
+impl<'a> From<&SubSuperRecord<'a>> for super::SubSuperRecord {
+    fn from(subsuper: &SubSuperRecord<'a>) -> Self {
+        subsuper.0.iter().map(super::Record::from).collect()
+    }
+}
+
+/// Borrowed version of [ast::EntityInstance](super::EntityInstance)
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntityInstance<'a> {
+    Simple {
+        id: u64,
+        record: Record<'a>,
+    },
+    Complex {
+        id: u64,
+        subsuper: SubSuperRecord<'a>,
+    },
+}
+derive_borrowed_parse!(EntityInstance, parser::borrowed::entity_instance);
+
+impl<'a> EntityInstance<'a> {
+    /// Entity instance name, e.g. `1` for `#1 = A(...);`
+    pub fn id(&self) -> u64 {
+        match self {
+            EntityInstance::Simple { id, .. } | EntityInstance::Complex { id, .. } => *id,
+        }
+    }
+
+    /// Convert into owned [ast::EntityInstance](super::EntityInstance)
+    pub fn into_owned(self) -> super::EntityInstance {
+        (&self).into()
+    }
+}
+
+impl<'a> From<&EntityInstance<'a>> for super::EntityInstance {
+    fn from(instance: &EntityInstance<'a>) -> Self {
+        match instance {
+            EntityInstance::Simple { id, record } => super::EntityInstance::Simple {
+                id: *id,
+                record: record.into(),
+            },
+            EntityInstance::Complex { id, subsuper } => super::EntityInstance::Complex {
+                id: *id,
+                subsuper: subsuper.into(),
+            },
+        }
+    }
+}
+
+/// Borrowed version of [ast::DataSection](super::DataSection)
+///
+/// ```
+/// use ruststep::ast::borrowed::{DataSection, EntityInstance};
+///
+/// let section = DataSection::parse("DATA; #1 = A(1.0); #2 = B(A(2.0)); #3 = A(3.0); ENDSEC;").unwrap();
+/// assert_eq!(section.keywords.iter().collect::<Vec<_>>(), ["A", "B"]);
+/// assert_eq!(section.keywords.id("B"), Some(1));
+///
+/// // Record names of `#1` and `#3` are the same slice in the keyword table
+/// match (&section.entities[0], &section.entities[2]) {
+///     (EntityInstance::Simple { record: a1, .. }, EntityInstance::Simple { record: a3, .. }) => {
+///         assert_eq!(a1.name.as_ptr(), a3.name.as_ptr());
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataSection<'a> {
+    pub meta: Vec<Parameter<'a>>,
+    pub entities: Vec<EntityInstance<'a>>,
+    /// Keywords of the records and typed parameters in `entities`
+    pub keywords: KeywordTable<'a>,
+}
+derive_borrowed_parse!(DataSection, parser::borrowed::data_section);
+
+impl<'a> DataSection<'a> {
+    /// Create a DATA section interning the keywords in `entities`
+    pub fn new(meta: Vec<Parameter<'a>>, mut entities: Vec<EntityInstance<'a>>) -> Self {
+        let mut keywords = KeywordTable::default();
+        for entity in &mut entities {
+            match entity {
+                EntityInstance::Simple { record, .. } => keywords.intern_record(record),
+                EntityInstance::Complex { subsuper, .. } => {
+                    for record in &mut subsuper.0 {
+                        keywords.intern_record(record);
+                    }
+                }
+            }
+        }
+        DataSection {
+            meta,
+            entities,
+            keywords,
+        }
+    }
+
+    /// Convert into owned [ast::DataSection](super::DataSection)
+    pub fn into_owned(self) -> super::DataSection {
+        (&self).into()
+    }
+}
+
+/// Keywords appearing in a [DataSection], each of them stored once
+///
+/// Interned keywords are numbered in order of first appearance,
+/// which can be used as an index instead of comparing strings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeywordTable<'a> {
+    keywords: Vec<&'a str>,
+    ids: HashMap<&'a str, usize>,
+}
+
+impl<'a> KeywordTable<'a> {
+    /// Register a keyword, and returns the slice of its first appearance
+    pub fn intern(&mut self, keyword: &'a str) -> &'a str {
+        match self.ids.get(keyword) {
+            Some(&id) => self.keywords[id],
+            None => {
+                self.ids.insert(keyword, self.keywords.len());
+                self.keywords.push(keyword);
+                keyword
+            }
+        }
+    }
+
+    /// Intern the name of record and the keywords of typed parameters in it
+    pub fn intern_record(&mut self, record: &mut Record<'a>) {
+        record.name = self.intern(record.name);
+        self.intern_parameter(&mut record.parameter);
+    }
+
+    fn intern_parameter(&mut self, parameter: &mut Parameter<'a>) {
+        match parameter {
+            Parameter::Typed { keyword, parameter } => {
+                *keyword = self.intern(keyword);
+                self.intern_parameter(parameter);
+            }
+            Parameter::List(list) => list.iter_mut().for_each(|p| self.intern_parameter(p)),
+            _ => {}
+        }
+    }
+
+    /// Index of a keyword in order of first appearance
+    pub fn id(&self, keyword: &str) -> Option<usize> {
+        self.ids.get(keyword).copied()
+    }
+
+    /// Keyword of an index
+    pub fn get(&self, id: usize) -> Option<&'a str> {
+        self.keywords.get(id).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keywords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keywords.is_empty()
+    }
+
+    /// Keywords in order of first appearance
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.keywords.iter().copied()
+    }
+}
+
+impl<'a> From<&DataSection<'a>> for super::DataSection {
+    fn from(section: &DataSection<'a>) -> Self {
+        super::DataSection {
+            meta: section.meta.iter().map(Into::into).collect(),
+            entities: section.entities.iter().map(Into::into).collect(),
+        }
+    }
+}
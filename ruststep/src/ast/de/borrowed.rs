@@ -0,0 +1,384 @@
+//! [serde::Deserializer] for [ast::borrowed](crate::ast::borrowed)
+//!
+//! Same as the implementations for owned AST,
+//! but keywords and strings are passed to the visitor by `visit_borrowed_str`
+//! so that deserialized values can borrow the input string, e.g. `&'de str` fields.
+
+use crate::ast::borrowed::*;
+use inflector::Inflector;
+use serde::{
+    de::{self, value::BorrowedStrDeserializer, IntoDeserializer},
+    forward_to_deserialize_any,
+};
+use std::borrow::Cow;
+
+impl<'de, 'p> de::Deserializer<'de> for &'p Parameter<'de> {
+    type Error = crate::error::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self {
+            Parameter::Typed { keyword, parameter } => {
+                visitor.visit_map(RecordDeserializer::new(keyword, parameter))
+            }
+            Parameter::Integer(val) => visitor.visit_i64(*val),
+            Parameter::Real(val) => visitor.visit_f64(*val),
+            Parameter::String(s) => match s.decoded() {
+                Cow::Borrowed(val) => visitor.visit_borrowed_str(val),
+                Cow::Owned(val) => visitor.visit_str(val),
+            },
+            Parameter::List(params) => visitor.visit_seq(SeqDeserializer::new(params)),
+            Parameter::Ref(name) => visitor.visit_enum(name),
+            Parameter::Binary(b) => visitor.visit_str(&b.to_string()),
+            Parameter::NotProvided | Parameter::Omitted => visitor.visit_none(),
+            Parameter::Enumeration(variant) => {
+                visitor.visit_enum(variant.to_class_case().into_deserializer())
+            }
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if let Parameter::Enumeration(variant) = self {
+            match *variant {
+                "T" => visitor.visit_bool(true),
+                "TRUE" => visitor.visit_bool(true),
+                "F" => visitor.visit_bool(false),
+                "FALSE" => visitor.visit_bool(false),
+                _ => visitor.visit_enum(variant.to_class_case().into_deserializer()),
+            }
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if matches!(self, Parameter::NotProvided | Parameter::Omitted) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        struct tuple_struct map enum identifier ignored_any
+    }
+}
+
+impl<'de, 'record> de::Deserializer<'de> for &'record Record<'de> {
+    type Error = crate::error::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_map(RecordDeserializer::new(self.name, &self.parameter))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if name == self.name {
+            if let Parameter::List(ref parameters) = self.parameter {
+                return visitor.visit_map(RecordStructDeserializer::new(fields, parameters));
+            }
+        }
+        Self::deserialize_any(self, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+impl<'de, 'record> de::Deserializer<'de> for &'record SubSuperRecord<'de> {
+    type Error = crate::error::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_map(SubSuperRecordDeserializer::new(self.0.as_slice()))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        struct tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Borrowed version of [ast::de::SeqDeserializer](super::SeqDeserializer)
+#[derive(Debug)]
+pub struct SeqDeserializer<'de, 'p> {
+    cursor: usize,
+    parameters: &'p [Parameter<'de>],
+}
+
+impl<'de, 'p> SeqDeserializer<'de, 'p> {
+    pub fn new(parameters: &'p [Parameter<'de>]) -> Self {
+        SeqDeserializer {
+            cursor: 0,
+            parameters,
+        }
+    }
+}
+
+impl<'de, 'p> de::Deserializer<'de> for SeqDeserializer<'de, 'p> {
+    type Error = crate::error::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_seq(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        struct tuple_struct map enum identifier ignored_any
+    }
+}
+
+impl<'de, 'p> de::SeqAccess<'de> for SeqDeserializer<'de, 'p> {
+    type Error = crate::error::Error;
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.parameters.len())
+    }
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.cursor < self.parameters.len() {
+            let value = seed.deserialize(&self.parameters[self.cursor])?;
+            self.cursor += 1;
+            Ok(Some(value))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Borrowed version of [ast::de::RecordDeserializer](super::RecordDeserializer)
+#[derive(Debug)]
+pub struct RecordDeserializer<'de, 'record> {
+    key: Option<&'de str>,
+    value: &'record Parameter<'de>,
+}
+
+impl<'de, 'record> RecordDeserializer<'de, 'record> {
+    pub fn new(key: &'de str, value: &'record Parameter<'de>) -> Self {
+        RecordDeserializer {
+            key: Some(key),
+            value,
+        }
+    }
+}
+
+impl<'de, 'record> de::Deserializer<'de> for RecordDeserializer<'de, 'record> {
+    type Error = crate::error::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_map(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        struct tuple_struct map enum identifier ignored_any
+    }
+}
+
+impl<'de, 'record> de::MapAccess<'de> for RecordDeserializer<'de, 'record> {
+    type Error = crate::error::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if let Some(key) = self.key.take() {
+            let key: BorrowedStrDeserializer<Self::Error> = BorrowedStrDeserializer::new(key);
+            let key: K::Value = seed.deserialize(key)?;
+            Ok(Some(key))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let value: V::Value = seed.deserialize(self.value)?;
+        Ok(value)
+    }
+}
+
+/// Borrowed version of [ast::de::RecordStructDeserializer](super::RecordStructDeserializer)
+#[derive(Debug)]
+pub struct RecordStructDeserializer<'de, 'a> {
+    cursor: usize,
+    fields: &'a [&'static str],
+    parameters: &'a [Parameter<'de>],
+}
+
+impl<'de, 'a> RecordStructDeserializer<'de, 'a> {
+    pub fn new(fields: &'a [&'static str], parameters: &'a [Parameter<'de>]) -> Self {
+        Self {
+            cursor: 0,
+            fields,
+            parameters,
+        }
+    }
+}
+
+impl<'de, 'a> de::Deserializer<'de> for RecordStructDeserializer<'de, 'a> {
+    type Error = crate::error::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_map(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        struct tuple_struct map enum identifier ignored_any
+    }
+}
+
+impl<'de, 'a> de::MapAccess<'de> for RecordStructDeserializer<'de, 'a> {
+    type Error = crate::error::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if self.cursor < self.fields.len() {
+            let key: de::value::StrDeserializer<Self::Error> =
+                self.fields[self.cursor].into_deserializer();
+            let key: K::Value = seed.deserialize(key)?;
+            Ok(Some(key))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        assert!(self.cursor < self.parameters.len());
+        let value: V::Value = seed.deserialize(&self.parameters[self.cursor])?;
+        self.cursor += 1;
+        Ok(value)
+    }
+}
+
+/// Borrowed version of the deserializer of [SubSuperRecord](crate::ast::SubSuperRecord)
+#[derive(Debug)]
+pub struct SubSuperRecordDeserializer<'de, 'record> {
+    keywords: Vec<&'de str>,
+    parameters: Vec<&'record Parameter<'de>>,
+}
+
+impl<'de, 'record> SubSuperRecordDeserializer<'de, 'record> {
+    pub fn new(records: &'record [Record<'de>]) -> Self {
+        Self {
+            keywords: records.iter().map(|x| x.name).collect(),
+            parameters: records.iter().map(|x| &x.parameter).collect(),
+        }
+    }
+}
+
+impl<'de, 'record> de::MapAccess<'de> for SubSuperRecordDeserializer<'de, 'record> {
+    type Error = crate::error::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if let Some(key) = self.keywords.pop() {
+            let key: BorrowedStrDeserializer<Self::Error> = BorrowedStrDeserializer::new(key);
+            let key: K::Value = seed.deserialize(key)?;
+            Ok(Some(key))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        if let Some(value) = self.parameters.pop() {
+            let value: V::Value = seed.deserialize(value)?;
+            Ok(value)
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::borrowed::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename = "A")]
+    struct A<'a> {
+        x: f64,
+        label: &'a str,
+    }
+
+    #[test]
+    fn borrow_str() {
+        let record = Record::parse("A(1.0, 'label')").unwrap();
+        let a = A::deserialize(&record).unwrap();
+        assert_eq!(
+            a,
+            A {
+                x: 1.0,
+                label: "label"
+            }
+        );
+    }
+
+    #[test]
+    fn same_as_owned() {
+        let input = "A(1.0, 2.0)";
+        let borrowed = Record::parse(input).unwrap();
+        let owned: crate::ast::Record = input.parse().unwrap();
+        let a: std::collections::HashMap<String, Vec<f64>> =
+            Deserialize::deserialize(&borrowed).unwrap();
+        let b: std::collections::HashMap<String, Vec<f64>> =
+            Deserialize::deserialize(&owned).unwrap();
+        assert_eq!(a, b);
+    }
+}
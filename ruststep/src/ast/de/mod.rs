@@ -1,3 +1,4 @@
+pub mod borrowed;
 mod name;
 mod parameter;
 mod record;
@@ -22,6 +22,7 @@ impl<'de, 'param> de::Deserializer<'de> for &'param Parameter {
             Parameter::String(val) => visitor.visit_str(val),
             Parameter::List(params) => visitor.visit_seq(SeqDeserializer::new(params)),
             Parameter::Ref(name) => visitor.visit_enum(name),
+            Parameter::Binary(b) => visitor.visit_str(&b.to_string()),
             Parameter::NotProvided | Parameter::Omitted => visitor.visit_none(),
             Parameter::Enumeration(variant) => {
                 visitor.visit_enum(variant.to_class_case().into_deserializer())
@@ -34,11 +34,17 @@
 //! └───────────────────┘
 //! ```
 
+pub mod borrowed;
 pub mod de;
 pub mod ser;
 
-use crate::parser;
-use std::str::FromStr;
+use crate::{parser, primitive::Binary};
+use std::{
+    borrow::Cow,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str::FromStr,
+};
 
 /// AST portion
 pub trait AST: FromStr<Err = crate::error::Error> {
@@ -54,7 +60,7 @@ macro_rules! derive_ast_from_str {
                 let input = input.trim();
                 let (residual, record) = AST::parse(input)
                     .finish()
-                    .map_err(|err| $crate::error::TokenizeFailed::new(input, err))?;
+                    .map_err(|err| $crate::parser::tokenize_failed(input, err))?;
                 if !residual.is_empty() {
                     return Err($crate::error::Error::ExtraInputRemaining(input.to_string()));
                 }
@@ -73,7 +79,7 @@ macro_rules! derive_ast_from_str {
 /// Name of an entity instance or a value
 ///
 /// Corresponding to [parser::token::rhs_occurrence_name] and [parser::token::lhs_occurrence_name]
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Name {
     /// Like `#11`, corresponds to [parser::token::entity_instance_name]
     Entity(u64),
@@ -598,10 +604,10 @@ pub enum Parameter {
     /// use ruststep::ast::Parameter;
     ///
     /// let p = Parameter::from_str("'EXAMPLE STRING'").unwrap();
-    /// assert_eq!(p, Parameter::String("EXAMPLE STRING".to_string()));
+    /// assert_eq!(p, Parameter::String("EXAMPLE STRING".into()));
     /// ```
     #[from]
-    String(String),
+    String(StringLiteral),
 
     /// Enumeration defined in EXPRESS schema, like `.TRUE.`
     ///
@@ -645,7 +651,7 @@ pub enum Parameter {
     /// assert_eq!(p, Parameter::List(vec![
     ///   Parameter::Real(1.0),
     ///   Parameter::Integer(2),
-    ///   Parameter::String("STRING".to_string()),
+    ///   Parameter::String("STRING".into()),
     /// ]));
     /// ```
     ///
@@ -698,6 +704,32 @@ pub enum Parameter {
     #[from]
     Ref(Name),
 
+    /// Binary literal, like `"0ABC"`
+    ///
+    /// FromStr
+    /// --------
+    /// ```
+    /// use std::str::FromStr;
+    /// use ruststep::{ast::Parameter, primitive::Binary};
+    ///
+    /// let p = Parameter::from_str(r#""0ABC""#).unwrap();
+    /// assert_eq!(p, Parameter::Binary(Binary::from_str("0ABC").unwrap()));
+    /// ```
+    ///
+    /// Deserialize
+    /// ------------
+    /// ```
+    /// use ruststep::{ast::*, primitive::Binary};
+    /// use serde::Deserialize;
+    /// use std::str::FromStr;
+    ///
+    /// let p = Parameter::from_str(r#""315""#).unwrap();
+    /// let b = Binary::deserialize(&p).unwrap();
+    /// assert_eq!(b, Binary::from_bits([true, false, true, false, true]));
+    /// ```
+    #[from]
+    Binary(Binary),
+
     /// The special token dollar sign (`$`) is used to represent
     /// an object whose value is not provided in the exchange structure.
     ///
@@ -736,7 +768,13 @@ impl Parameter {
     }
 
     pub fn string(s: &str) -> Self {
-        Parameter::String(s.to_string())
+        Parameter::String(s.into())
+    }
+}
+
+impl From<String> for Parameter {
+    fn from(s: String) -> Self {
+        Parameter::String(s.into())
     }
 }
 
@@ -754,6 +792,180 @@ impl<'a> std::iter::FromIterator<&'a Parameter> for Parameter {
 
 derive_ast_from_str!(Parameter, parser::exchange::parameter);
 
+impl Parameter {
+    /// Rewrite entity instance names in this parameter, e.g. `#2` into `#f(2)`
+    pub fn map_references(&mut self, f: &mut impl FnMut(u64) -> u64) {
+        match self {
+            Parameter::Typed { parameter, .. } => parameter.map_references(f),
+            Parameter::List(list) => list.iter_mut().for_each(|p| p.map_references(f)),
+            Parameter::Ref(Name::Entity(id)) => *id = f(*id),
+            _ => {}
+        }
+    }
+
+    /// Hash consistent with both [PartialEq] and [Parameter::same_bits]
+    pub(crate) fn hash_structure(&self, state: &mut impl Hasher) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Parameter::Typed { keyword, parameter } => {
+                keyword.hash(state);
+                parameter.hash_structure(state);
+            }
+            Parameter::Integer(i) => i.hash(state),
+            // `0.0 == -0.0` while their bits are different
+            Parameter::Real(x) => (if *x == 0.0 { 0 } else { x.to_bits() }).hash(state),
+            Parameter::String(s) => s.as_str().hash(state),
+            Parameter::Enumeration(e) => e.hash(state),
+            Parameter::List(list) => {
+                list.len().hash(state);
+                for p in list {
+                    p.hash_structure(state);
+                }
+            }
+            Parameter::Ref(name) => name.hash(state),
+            Parameter::Binary(b) => b.hash(state),
+            Parameter::NotProvided | Parameter::Omitted => {}
+        }
+    }
+
+    /// Same as [PartialEq], but reals are compared by their bits,
+    /// i.e. NaN equals to itself, and `0.0` and `-0.0` are distinct
+    pub(crate) fn same_bits(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Parameter::Real(x), Parameter::Real(y)) => x.to_bits() == y.to_bits(),
+            (
+                Parameter::Typed { keyword, parameter },
+                Parameter::Typed {
+                    keyword: other_keyword,
+                    parameter: other_parameter,
+                },
+            ) => keyword == other_keyword && parameter.same_bits(other_parameter),
+            (Parameter::List(list), Parameter::List(other)) => {
+                list.len() == other.len() && list.iter().zip(other).all(|(p, q)| p.same_bits(q))
+            }
+            (p, q) => p == q,
+        }
+    }
+}
+
+/// String literal, e.g. `'it''s'`
+///
+/// This is dereferenced to the decoded content by [parser::string::decode],
+/// and also keeps the encoded form read from the exchange structure
+/// to write it back as it is.
+/// Two literals are equal if their decoded contents are equal.
+///
+/// ```
+/// use ruststep::ast::StringLiteral;
+///
+/// let s = StringLiteral::from_raw(r"\X2\00E4\X0\").unwrap();
+/// assert_eq!(s, "ä");
+/// assert_eq!(s.raw(), r"\X2\00E4\X0\");
+///
+/// // Unknown reverse solidus is kept as it is
+/// let s = StringLiteral::from_raw(r"C:\work\a.stp").unwrap();
+/// assert_eq!(s, r"C:\work\a.stp");
+/// assert_eq!(s.raw(), r"C:\work\a.stp");
+///
+/// // Encoded by parser::string::encode if created from a decoded string
+/// let s = StringLiteral::from(r"ä\");
+/// assert_eq!(s.raw(), r"\X\E4\\");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StringLiteral {
+    decoded: String,
+    /// Encoded form if it differs from `decoded` encoded by [parser::string::encode]
+    raw: Option<String>,
+}
+
+impl StringLiteral {
+    /// Decode the content of a string literal, i.e. the part between the apostrophes
+    pub fn from_raw(raw: &str) -> crate::error::Result<Self> {
+        let decoded = parser::string::decode(raw)?;
+        Ok(Self::from_parts(decoded, raw))
+    }
+
+    /// Create from the decoded content and its encoded form
+    pub(crate) fn from_parts(decoded: String, raw: &str) -> Self {
+        // Keep `raw` unless it is same as re-encoded one
+        let raw = if parser::string::is_plain(raw) && raw.chars().all(|c| (' '..='~').contains(&c))
+        {
+            None
+        } else {
+            Some(raw.to_string())
+        };
+        StringLiteral { decoded, raw }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.decoded
+    }
+
+    pub fn into_string(self) -> String {
+        self.decoded
+    }
+
+    /// Encoded form between the apostrophes
+    ///
+    /// This is the form read from the exchange structure,
+    /// or encoded by [parser::string::encode] for a literal created from a string.
+    pub fn raw(&self) -> Cow<str> {
+        match &self.raw {
+            Some(raw) => Cow::Borrowed(raw),
+            None => parser::string::encode(&self.decoded).into(),
+        }
+    }
+}
+
+impl Deref for StringLiteral {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.decoded
+    }
+}
+
+impl AsRef<str> for StringLiteral {
+    fn as_ref(&self) -> &str {
+        &self.decoded
+    }
+}
+
+impl From<String> for StringLiteral {
+    fn from(decoded: String) -> Self {
+        StringLiteral { decoded, raw: None }
+    }
+}
+
+impl From<&str> for StringLiteral {
+    fn from(decoded: &str) -> Self {
+        decoded.to_string().into()
+    }
+}
+
+impl From<StringLiteral> for String {
+    fn from(s: StringLiteral) -> Self {
+        s.decoded
+    }
+}
+
+impl PartialEq for StringLiteral {
+    fn eq(&self, other: &Self) -> bool {
+        self.decoded == other.decoded
+    }
+}
+
+impl PartialEq<str> for StringLiteral {
+    fn eq(&self, other: &str) -> bool {
+        self.decoded == other
+    }
+}
+
+impl PartialEq<&str> for StringLiteral {
+    fn eq(&self, other: &&str) -> bool {
+        self.decoded == *other
+    }
+}
+
 /// Entire exchange structure
 #[derive(Debug, Clone, PartialEq)]
 pub struct Exchange {
@@ -778,6 +990,81 @@ pub enum EntityInstance {
 }
 derive_ast_from_str!(EntityInstance, parser::exchange::entity_instance);
 
+impl EntityInstance {
+    /// Entity instance name, e.g. `1` for `#1 = A(...);`
+    pub fn id(&self) -> u64 {
+        match self {
+            EntityInstance::Simple { id, .. } | EntityInstance::Complex { id, .. } => *id,
+        }
+    }
+
+    /// Record of simple instance, or partial records of complex instance
+    pub fn records(&self) -> &[Record] {
+        match self {
+            EntityInstance::Simple { record, .. } => std::slice::from_ref(record),
+            EntityInstance::Complex { subsuper, .. } => &subsuper.0,
+        }
+    }
+
+    pub fn records_mut(&mut self) -> &mut [Record] {
+        match self {
+            EntityInstance::Simple { record, .. } => std::slice::from_mut(record),
+            EntityInstance::Complex { subsuper, .. } => &mut subsuper.0,
+        }
+    }
+
+    /// Rewrite entity instance names referred from this instance, e.g. `#2` into `#f(2)`
+    pub fn map_references(&mut self, mut f: impl FnMut(u64) -> u64) {
+        for record in self.records_mut() {
+            record.parameter.map_references(&mut f);
+        }
+    }
+
+    /// Rewrite the name of this instance and the names referred from it
+    ///
+    /// ```
+    /// use ruststep::ast::EntityInstance;
+    /// use std::str::FromStr;
+    ///
+    /// let mut instance = EntityInstance::from_str("#10 = A(#20, (#30, B(#10)));").unwrap();
+    /// instance.renumber(|id| id / 10);
+    /// assert_eq!(instance, EntityInstance::from_str("#1 = A(#2, (#3, B(#1)));").unwrap());
+    /// ```
+    pub fn renumber(&mut self, mut f: impl FnMut(u64) -> u64) {
+        match self {
+            EntityInstance::Simple { id, .. } | EntityInstance::Complex { id, .. } => *id = f(*id),
+        }
+        self.map_references(f);
+    }
+
+    /// Entity instance names referred from this instance, in order of first appearance
+    ///
+    /// ```
+    /// use ruststep::ast::EntityInstance;
+    /// use std::str::FromStr;
+    ///
+    /// let instance = EntityInstance::from_str("#1 = A(#2, (#3, B(#2)), 'x');").unwrap();
+    /// assert_eq!(instance.references(), [2, 3]);
+    /// ```
+    pub fn references(&self) -> Vec<u64> {
+        fn collect(parameter: &Parameter, refs: &mut Vec<u64>) {
+            match parameter {
+                Parameter::Typed { parameter, .. } => collect(parameter, refs),
+                Parameter::List(list) => list.iter().for_each(|p| collect(p, refs)),
+                Parameter::Ref(Name::Entity(id)) => refs.push(*id),
+                _ => {}
+            }
+        }
+        let mut refs = Vec::new();
+        for record in self.records() {
+            collect(&record.parameter, &mut refs);
+        }
+        let mut found = std::collections::HashSet::new();
+        refs.retain(|id| found.insert(*id));
+        refs
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ReferenceEntry {
     pub name: Name,
@@ -800,13 +1087,55 @@ derive_ast_from_str!(Anchor, parser::exchange::anchor);
 pub enum AnchorItem {
     Integer(i64),
     Real(f64),
-    String(String),
+    String(StringLiteral),
     Enumeration(String),
     /// The special token dollar sign (`$`) is used to represent an object whose value is not provided in the exchange structure.
     NotProvided,
+    Binary(Binary),
     /// A reference to entity or value
     Name(Name),
     /// List of other parameters
     List(Vec<AnchorItem>),
 }
 derive_ast_from_str!(AnchorItem, parser::exchange::anchor_item);
+
+impl AnchorItem {
+    /// Entity instance names in this item
+    pub fn references(&self) -> Vec<u64> {
+        match self {
+            AnchorItem::Name(Name::Entity(id)) => vec![*id],
+            AnchorItem::List(list) => list.iter().flat_map(AnchorItem::references).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Rewrite entity instance names in this item, e.g. `#2` into `#f(2)`
+    pub fn map_references(&mut self, f: &mut impl FnMut(u64) -> u64) {
+        match self {
+            AnchorItem::Name(Name::Entity(id)) => *id = f(*id),
+            AnchorItem::List(list) => list.iter_mut().for_each(|item| item.map_references(f)),
+            _ => {}
+        }
+    }
+
+    /// Convert into a parameter, where names are replaced by `resolve_name`
+    pub(crate) fn to_parameter(
+        &self,
+        resolve_name: &mut impl FnMut(&Name) -> crate::error::Result<Parameter>,
+    ) -> crate::error::Result<Parameter> {
+        Ok(match self {
+            AnchorItem::Integer(i) => Parameter::Integer(*i),
+            AnchorItem::Real(x) => Parameter::Real(*x),
+            AnchorItem::String(s) => Parameter::String(s.clone()),
+            AnchorItem::Enumeration(e) => Parameter::Enumeration(e.clone()),
+            AnchorItem::NotProvided => Parameter::NotProvided,
+            AnchorItem::Binary(b) => Parameter::Binary(b.clone()),
+            AnchorItem::Name(name) => resolve_name(name)?,
+            AnchorItem::List(list) => Parameter::List(
+                list.iter()
+                    .map(|item| item.to_parameter(resolve_name))
+                    .collect::<crate::error::Result<_>>()?,
+            ),
+        })
+    }
+}
@@ -1,145 +1,454 @@
-use crate::{ast::*, error::*};
-use serde::ser;
-use std::convert::TryFrom;
+//! Serialize Rust values into exchange structure AST
+//!
+//! - [to_record] and [to_parameter] serialize a value into a single [Record] or [Parameter].
+//!   Nested structs are written as inline typed parameters, e.g. `B(3.0, A((1.0, 2.0)))`.
+//! - [DataSectionBuilder] serializes entities into [DataSection].
+//!   Nested structs are written as separate entity instances and referred by their entity ids,
+//!   e.g. `#1 = A(1.0, 2.0); #2 = B(3.0, #1);`.
+//!
+//! These are the inverse of [ast::de](crate::ast::de) in terms of serde data model:
+//!
+//! | serde data model                           | exchange structure     |
+//! |:-------------------------------------------|:-----------------------|
+//! | struct, tuple variant, struct variant      | `A(1.0, 2.0)`          |
+//! | single-key map `{ "A": [1.0, 2.0] }`       | `A(1.0, 2.0)`          |
+//! | seq, tuple, tuple struct                   | `(1.0, 2.0)`           |
+//! | unit variant                               | `.DARK_RED.`           |
+//! | bool                                       | `.T.`, `.F.`           |
+//! | bytes                                      | `"0ABCD"`              |
+//! | none, unit                                 | `$`                    |
+
+use crate::{ast::*, error::*, primitive::Binary};
+use inflector::Inflector;
+use serde::ser::{self, Serialize};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap},
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+/// Struct name used by `impl Serialize for Name` to tell the serializer it is a reference
+pub(crate) const NAME_TOKEN: &str = "$ruststep::Name";
+/// Struct name used by `impl Serialize for Binary` to tell the serializer it is a binary
+pub(crate) const BINARY_TOKEN: &str = "$ruststep::Binary";
+/// Struct name used by [Inline] to tell the serializer not to hoist the entity
+const INLINE_TOKEN: &str = "$ruststep::Inline";
 
 /// Serialize struct into STEP [Record]
-pub fn to_record(obj: &impl ser::Serialize) -> Result<Record> {
-    let mut ser = RecordSerializer::default();
-    obj.serialize(&mut ser)?;
-    assert!(ser.stack.is_empty()); // should panic because this must be bug, not a valid input
-    Ok(Record {
-        name: ser.name,
-        parameter: ser.parameters.iter().collect(),
-    })
+pub fn to_record(obj: &impl Serialize) -> Result<Record> {
+    into_record(obj.serialize(ParameterSerializer::new(None))?)
 }
 
-#[derive(Default, Debug)]
-struct RecordSerializer {
-    name: String,
-    parameters: Vec<Parameter>,
-    // For supporting nested record e.g. `B(3.0, A((1.0, 2.0)))`
-    stack: Vec<(String, Vec<Parameter>)>,
+/// Serialize value into STEP [Parameter]
+pub fn to_parameter(obj: &impl Serialize) -> Result<Parameter> {
+    obj.serialize(ParameterSerializer::new(None))
 }
 
-impl<'se> ser::Serializer for &'se mut RecordSerializer {
-    type Ok = ();
-    type Error = Error;
-    type SerializeSeq = Self;
-    type SerializeTuple = Self;
-    type SerializeTupleStruct = Self;
-    type SerializeTupleVariant = Self;
-    type SerializeMap = Self;
-    type SerializeStruct = Self;
-    type SerializeStructVariant = Self;
-
-    fn serialize_bool(self, v: bool) -> Result<()> {
-        if v {
-            self.parameters
-                .push(Parameter::Enumeration("TRUE".to_string()));
-        } else {
-            self.parameters
-                .push(Parameter::Enumeration("FALSE".to_string()));
+fn into_record(parameter: Parameter) -> Result<Record> {
+    match parameter {
+        Parameter::Typed { keyword, parameter } => Ok(Record {
+            name: keyword,
+            parameter: *parameter,
+        }),
+        parameter => Err(Error::SerializeFailed(format!(
+            "Only struct can be serialized into record: {}",
+            parameter
+        ))),
+    }
+}
+
+/// Serialize an entity always as an inline typed parameter,
+/// even while serializing into [DataSectionBuilder]
+///
+/// This is used for the supertype fields of generated subtype structs,
+/// since a supertype is a part of the subtype instance, not a reference to another instance.
+pub struct Inline<'a, T: ?Sized>(pub &'a T);
+
+impl<'a, T: ?Sized + Serialize> Serialize for Inline<'a, T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_newtype_struct(INLINE_TOKEN, self.0)
+    }
+}
+
+impl Serialize for Name {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_newtype_struct(NAME_TOKEN, &self.to_string())
+    }
+}
+
+/// Build [DataSection] by serializing entities
+///
+/// Entities appear in nested structs are inserted as separate entity instances
+/// with fresh entity ids, and they are replaced by references.
+/// Every serialized entity gets its own entity id even if it equals to another one,
+/// unless sharing is enabled by [DataSectionBuilder::share_equal].
+///
+/// ```
+/// use ruststep::ast::{ser::DataSectionBuilder, DataSection};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// #[serde(rename = "A")]
+/// struct A {
+///     x: f64,
+/// }
+///
+/// #[derive(Serialize)]
+/// #[serde(rename = "B")]
+/// struct B {
+///     a1: A,
+///     a2: A,
+/// }
+///
+/// let mut builder = DataSectionBuilder::new();
+/// // Keep entity id, e.g. read from an exchange structure
+/// builder.insert(10, &A { x: 2.0 }).unwrap();
+/// // Allocate new entity id
+/// let id = builder.push(&B { a1: A { x: 1.0 }, a2: A { x: 1.0 } }).unwrap();
+/// assert_eq!(id, 13);
+///
+/// let expected: DataSection = r#"
+/// DATA;
+///   #10 = A(2.0);
+///   #11 = A(1.0);
+///   #12 = A(1.0);
+///   #13 = B(#11, #12);
+/// ENDSEC;
+/// "#
+/// .parse()
+/// .unwrap();
+/// assert_eq!(builder.build(), expected);
+///
+/// // Equal entities are shared through a reference
+/// let mut builder = DataSectionBuilder::new().share_equal();
+/// builder.push(&B { a1: A { x: 1.0 }, a2: A { x: 1.0 } }).unwrap();
+///
+/// let expected: DataSection = r#"
+/// DATA;
+///   #1 = A(1.0);
+///   #2 = B(#1, #1);
+/// ENDSEC;
+/// "#
+/// .parse()
+/// .unwrap();
+/// assert_eq!(builder.build(), expected);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DataSectionBuilder {
+    entities: BTreeMap<u64, EntityInstance>,
+    /// Entity ids of simple records keyed by [record_hash], only used if sharing is enabled
+    shared: Option<HashMap<u64, Vec<u64>>>,
+    next_id: u64,
+}
+
+impl DataSectionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Share equal entities through a reference instead of inserting each of them
+    ///
+    /// A pushed entity is replaced by the entity id of an equal record inserted before.
+    /// Reals are compared by their bits, i.e. `0.0` and `-0.0` are not shared to keep the sign.
+    pub fn share_equal(mut self) -> Self {
+        self.shared.get_or_insert_with(HashMap::new);
+        self
+    }
+
+    /// Do not allocate these entity ids for new entities
+    ///
+    /// Use this before [DataSectionBuilder::push] when entities with known ids
+    /// will be inserted later by [DataSectionBuilder::insert].
+    pub fn reserve(&mut self, ids: impl IntoIterator<Item = u64>) {
+        if let Some(max) = ids.into_iter().max() {
+            self.next_id = self.next_id.max(max + 1);
+        }
+    }
+
+    /// Insert an entity with given entity id
+    ///
+    /// Errors
+    /// -------
+    /// - [Error::DuplicatedEntity] if the entity id is already used
+    /// - [Error::SerializeFailed] if `value` is not serialized into a record
+    ///
+    pub fn insert<T: ?Sized + Serialize>(&mut self, id: u64, value: &T) -> Result<()> {
+        if self.entities.contains_key(&id) {
+            return Err(Error::DuplicatedEntity(id));
+        }
+        // Nested entities must not take this id
+        self.reserve(Some(id));
+        let record = self.serialize_record(value)?;
+        if let Some(shared) = &mut self.shared {
+            shared.entry(record_hash(&record)).or_default().push(id);
+        }
+        self.entities
+            .insert(id, EntityInstance::Simple { id, record });
+        Ok(())
+    }
+
+    /// Insert a complex entity instance with given entity id
+    ///
+    /// Errors
+    /// -------
+    /// - [Error::DuplicatedEntity] if the entity id is already used
+    ///
+    pub fn insert_complex(&mut self, id: u64, subsuper: SubSuperRecord) -> Result<()> {
+        if self.entities.contains_key(&id) {
+            return Err(Error::DuplicatedEntity(id));
         }
+        self.reserve(Some(id));
+        self.entities
+            .insert(id, EntityInstance::Complex { id, subsuper });
         Ok(())
     }
 
-    fn serialize_i8(self, v: i8) -> Result<()> {
+    /// Serialize a value into [Record] without inserting it
+    ///
+    /// Entities nested in `value` are inserted into this builder as same as [DataSectionBuilder::push].
+    pub fn serialize_record<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<Record> {
+        into_record(value.serialize(ParameterSerializer::new(Some(self)))?)
+    }
+
+    /// Insert an entity with a new entity id, and returns the id
+    ///
+    /// If sharing is enabled by [DataSectionBuilder::share_equal] and an equal entity has been inserted,
+    /// this returns its id instead of inserting again.
+    pub fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<u64> {
+        let record = self.serialize_record(value)?;
+        Ok(self.push_record(record))
+    }
+
+    fn push_record(&mut self, record: Record) -> u64 {
+        let hash = self.shared.as_ref().map(|_| record_hash(&record));
+        if let (Some(shared), Some(hash)) = (&self.shared, hash) {
+            let entities = &self.entities;
+            let found = shared.get(&hash).into_iter().flatten().find(|id| {
+                matches!(&entities[id], EntityInstance::Simple { record: r, .. }
+                    if r.name == record.name && r.parameter.same_bits(&record.parameter))
+            });
+            if let Some(id) = found {
+                return *id;
+            }
+        }
+        let mut id = self.next_id.max(1);
+        while self.entities.contains_key(&id) {
+            id += 1;
+        }
+        self.next_id = id + 1;
+        if let (Some(shared), Some(hash)) = (&mut self.shared, hash) {
+            shared.entry(hash).or_default().push(id);
+        }
+        self.entities
+            .insert(id, EntityInstance::Simple { id, record });
+        id
+    }
+
+    /// Entity instances sorted by their ids
+    pub fn build(self) -> DataSection {
+        DataSection {
+            meta: Vec::new(),
+            entities: self.entities.into_values().collect(),
+        }
+    }
+}
+
+/// Hash of a record consistent with [Parameter::same_bits] used to share records
+fn record_hash(record: &Record) -> u64 {
+    let mut state = DefaultHasher::new();
+    record.name.hash(&mut state);
+    record.parameter.hash_structure(&mut state);
+    state.finish()
+}
+
+struct ParameterSerializer<'b> {
+    builder: Option<&'b mut DataSectionBuilder>,
+    /// Write struct into the builder and replace it by a reference
+    hoist: bool,
+}
+
+impl<'b> ParameterSerializer<'b> {
+    fn new(builder: Option<&'b mut DataSectionBuilder>) -> Self {
+        ParameterSerializer {
+            builder,
+            hoist: false,
+        }
+    }
+
+    /// Serializer for components, where nested entities are hoisted
+    fn component(&mut self) -> ParameterSerializer<'_> {
+        ParameterSerializer {
+            builder: self.builder.as_deref_mut(),
+            hoist: true,
+        }
+    }
+
+    /// Serializer for the value wrapped by newtype struct or variant
+    fn wrapped(&mut self, hoist: bool) -> ParameterSerializer<'_> {
+        ParameterSerializer {
+            builder: self.builder.as_deref_mut(),
+            hoist,
+        }
+    }
+
+    fn compound(self, kind: Kind) -> Compound<'b> {
+        Compound {
+            ser: self,
+            kind,
+            parameters: Vec::new(),
+        }
+    }
+}
+
+impl<'b> ser::Serializer for ParameterSerializer<'b> {
+    type Ok = Parameter;
+    type Error = Error;
+    type SerializeSeq = Compound<'b>;
+    type SerializeTuple = Compound<'b>;
+    type SerializeTupleStruct = Compound<'b>;
+    type SerializeTupleVariant = Compound<'b>;
+    type SerializeMap = Compound<'b>;
+    type SerializeStruct = Compound<'b>;
+    type SerializeStructVariant = Compound<'b>;
+
+    fn serialize_bool(self, v: bool) -> Result<Parameter> {
+        let value = if v { "T" } else { "F" };
+        Ok(Parameter::Enumeration(value.to_string()))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Parameter> {
         self.serialize_i64(i64::from(v))
     }
-    fn serialize_i16(self, v: i16) -> Result<()> {
+    fn serialize_i16(self, v: i16) -> Result<Parameter> {
         self.serialize_i64(i64::from(v))
     }
-    fn serialize_i32(self, v: i32) -> Result<()> {
+    fn serialize_i32(self, v: i32) -> Result<Parameter> {
         self.serialize_i64(i64::from(v))
     }
-    fn serialize_i64(self, v: i64) -> Result<()> {
-        self.parameters.push(Parameter::Integer(v));
-        Ok(())
+    fn serialize_i64(self, v: i64) -> Result<Parameter> {
+        Ok(Parameter::Integer(v))
     }
 
-    fn serialize_u8(self, v: u8) -> Result<()> {
+    fn serialize_u8(self, v: u8) -> Result<Parameter> {
         self.serialize_i64(i64::from(v))
     }
-    fn serialize_u16(self, v: u16) -> Result<()> {
+    fn serialize_u16(self, v: u16) -> Result<Parameter> {
         self.serialize_i64(i64::from(v))
     }
-    fn serialize_u32(self, v: u32) -> Result<()> {
+    fn serialize_u32(self, v: u32) -> Result<Parameter> {
         self.serialize_i64(i64::from(v))
     }
-    fn serialize_u64(self, v: u64) -> Result<()> {
-        self.serialize_i64(i64::try_from(v).expect("integer larger than i64::MAX is not supported"))
+    fn serialize_u64(self, v: u64) -> Result<Parameter> {
+        let v = i64::try_from(v).map_err(|_| {
+            Error::SerializeFailed(format!("Integer {} is larger than i64::MAX", v))
+        })?;
+        self.serialize_i64(v)
     }
 
-    fn serialize_f32(self, v: f32) -> Result<()> {
+    fn serialize_f32(self, v: f32) -> Result<Parameter> {
         self.serialize_f64(f64::from(v))
     }
-    fn serialize_f64(self, v: f64) -> Result<()> {
-        self.parameters.push(Parameter::Real(v));
-        Ok(())
+    fn serialize_f64(self, v: f64) -> Result<Parameter> {
+        Ok(Parameter::Real(v))
     }
 
-    fn serialize_char(self, v: char) -> Result<()> {
+    fn serialize_char(self, v: char) -> Result<Parameter> {
         self.serialize_str(&v.to_string())
     }
-    fn serialize_str(self, v: &str) -> Result<()> {
-        self.parameters.push(Parameter::String(v.to_string()));
-        Ok(())
+    fn serialize_str(self, v: &str) -> Result<Parameter> {
+        Ok(Parameter::String(v.into()))
     }
-    fn serialize_bytes(self, _v: &[u8]) -> Result<()> {
-        unimplemented!("Bytes is not supported yet")
+    fn serialize_bytes(self, v: &[u8]) -> Result<Parameter> {
+        Ok(Parameter::Binary(Binary::from(v)))
     }
 
-    fn serialize_none(self) -> Result<()> {
+    fn serialize_none(self) -> Result<Parameter> {
         self.serialize_unit()
     }
 
-    fn serialize_some<T>(self, value: &T) -> Result<()>
+    fn serialize_some<T>(self, value: &T) -> Result<Parameter>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
         value.serialize(self)
     }
 
-    fn serialize_unit(self) -> Result<()> {
-        self.parameters.push(Parameter::NotProvided);
-        Ok(())
+    fn serialize_unit(self) -> Result<Parameter> {
+        Ok(Parameter::NotProvided)
     }
 
-    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Parameter> {
         self.serialize_unit()
     }
 
     fn serialize_unit_variant(
         self,
-        _name: &'static str,
+        name: &'static str,
         _variant_index: u32,
         variant: &'static str,
-    ) -> Result<()> {
-        self.serialize_str(variant)
+    ) -> Result<Parameter> {
+        let value = match (name, variant) {
+            ("Logical", "True") => "T".to_string(),
+            ("Logical", "False") => "F".to_string(),
+            ("Logical", "Unknown") => "U".to_string(),
+            _ => variant.to_screaming_snake_case(),
+        };
+        Ok(Parameter::Enumeration(value))
     }
 
-    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    fn serialize_newtype_struct<T>(mut self, name: &'static str, value: &T) -> Result<Parameter>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        value.serialize(self)
+        match name {
+            NAME_TOKEN => match value.serialize(self)? {
+                Parameter::String(name) => Ok(Parameter::Ref(Name::from_str(&name)?)),
+                _ => unreachable!("Name is always serialized as string"),
+            },
+            BINARY_TOKEN => match value.serialize(self)? {
+                Parameter::String(b) => Ok(Parameter::Binary(
+                    Binary::from_str(&b).map_err(|e| Error::SerializeFailed(e.to_string()))?,
+                )),
+                _ => unreachable!("Binary is always serialized as string"),
+            },
+            INLINE_TOKEN => value.serialize(self.wrapped(false)),
+            _ => value.serialize(self),
+        }
     }
 
+    /// Variant of select type
+    ///
+    /// A value of an entity is serialized as it is,
+    /// and other values are serialized as typed parameters like `LABEL('ruststep')`.
     fn serialize_newtype_variant<T>(
-        self,
+        mut self,
         _name: &'static str,
         _variant_index: u32,
-        _variant: &'static str,
-        _value: &T,
-    ) -> Result<()>
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Parameter>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        unimplemented!("Newtype variant is not suuported yet.")
+        let hoist = self.hoist;
+        match value.serialize(self.wrapped(hoist))? {
+            parameter @ (Parameter::Typed { .. } | Parameter::Ref(_)) => Ok(parameter),
+            parameter => Ok(Parameter::Typed {
+                keyword: variant.to_string(),
+                parameter: Box::new(parameter),
+            }),
+        }
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
-        Ok(self)
+        Ok(self.compound(Kind::List))
     }
 
     fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
@@ -154,174 +463,439 @@ impl<'se> ser::Serializer for &'se mut RecordSerializer {
         self.serialize_seq(Some(len))
     }
 
+    /// Serialized as a record whose keyword is the variant name, e.g. `T(1, 2)`
     fn serialize_tuple_variant(
         self,
         _name: &'static str,
         _variant_index: u32,
-        _variant: &'static str,
+        variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeTupleVariant> {
-        unimplemented!("Tuple variant is not suuported yet.")
+        Ok(self.compound(Kind::Record(variant.to_string())))
     }
 
+    /// Single-key map `{ "A": [1.0, 2.0] }` is serialized as a record `A(1.0, 2.0)`
+    ///
+    /// This is the inverse of the deserialization of [Record] and [Parameter::Typed].
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
-        Ok(self)
+        Ok(self.compound(Kind::Map(None)))
     }
 
     fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
-        if self.name.is_empty() {
-            self.name = name.to_string();
-        } else {
-            // Entering sub struct e.g.
-            //
-            // ```
-            // B(3.0, A((1.0, 2.0)))
-            //        ^ here
-            // ```
-            //
-            // Put current parameters (`3.0` as above) onto the top of stack,
-            // and start serializing `A((1.0, 2.0))`.
-            // This stack will be popped in SerializeStruct::end()
-            //
-            let current_name = std::mem::replace(&mut self.name, name.to_string());
-            let current_params = std::mem::take(&mut self.parameters);
-            self.stack.push((current_name, current_params));
-        }
-        Ok(self)
+        Ok(self.compound(Kind::Record(name.to_string())))
     }
 
+    /// Serialized as a record whose keyword is the variant name, e.g. `S(1, 2)`
     fn serialize_struct_variant(
         self,
         _name: &'static str,
         _variant_index: u32,
-        _variant: &'static str,
+        variant: &'static str,
         _len: usize,
     ) -> Result<Self::SerializeStructVariant> {
-        unimplemented!("Struct variant is not suuported yet.")
+        Ok(self.compound(Kind::Record(variant.to_string())))
     }
 }
 
-impl<'se> ser::SerializeSeq for &'se mut RecordSerializer {
-    type Ok = ();
+enum Kind {
+    List,
+    /// Struct or variant with its keyword
+    Record(String),
+    /// Map with the last key
+    Map(Option<String>),
+}
+
+/// Serializer for list, struct, and map
+#[doc(hidden)]
+pub struct Compound<'b> {
+    ser: ParameterSerializer<'b>,
+    kind: Kind,
+    parameters: Vec<Parameter>,
+}
+
+impl<'b> Compound<'b> {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let parameter = value.serialize(self.ser.component())?;
+        self.parameters.push(parameter);
+        Ok(())
+    }
+
+    fn key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        match key.serialize(ParameterSerializer::new(None))? {
+            Parameter::String(key) => {
+                self.kind = Kind::Map(Some(key.into_string()));
+                Ok(())
+            }
+            key => Err(Error::SerializeFailed(format!(
+                "Key of map must be a string: {}",
+                key
+            ))),
+        }
+    }
+
+    fn value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let key = match &mut self.kind {
+            Kind::Map(key) => key
+                .take()
+                .expect("serialize_key must be called before value"),
+            _ => unreachable!(),
+        };
+        let parameter = value.serialize(self.ser.component())?;
+        self.parameters.push(Parameter::Typed {
+            keyword: key,
+            parameter: Box::new(parameter),
+        });
+        Ok(())
+    }
+
+    fn end(self) -> Result<Parameter> {
+        let Compound {
+            ser,
+            kind,
+            mut parameters,
+        } = self;
+        let record = match kind {
+            Kind::List => return Ok(Parameter::List(parameters)),
+            Kind::Record(name) => Record {
+                name,
+                parameter: Parameter::List(parameters),
+            },
+            Kind::Map(_) => {
+                if parameters.len() != 1 {
+                    return Err(Error::SerializeFailed(format!(
+                        "Only single-key map can be serialized into record, but {} keys found",
+                        parameters.len()
+                    )));
+                }
+                into_record(parameters.pop().unwrap())?
+            }
+        };
+        match ser.builder {
+            Some(builder) if ser.hoist => {
+                let id = builder.push_record(record);
+                Ok(Parameter::Ref(Name::Entity(id)))
+            }
+            _ => Ok(Parameter::Typed {
+                keyword: record.name,
+                parameter: Box::new(record.parameter),
+            }),
+        }
+    }
+}
+
+impl<'b> ser::SerializeSeq for Compound<'b> {
+    type Ok = Parameter;
     type Error = Error;
     fn serialize_element<T>(&mut self, value: &T) -> Result<()>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        value.serialize(&mut **self)
+        self.push(value)
     }
-    fn end(self) -> Result<()> {
-        Ok(())
+    fn end(self) -> Result<Parameter> {
+        Compound::end(self)
     }
 }
 
-impl<'se> ser::SerializeTuple for &'se mut RecordSerializer {
-    type Ok = ();
+impl<'b> ser::SerializeTuple for Compound<'b> {
+    type Ok = Parameter;
     type Error = Error;
     fn serialize_element<T>(&mut self, value: &T) -> Result<()>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        value.serialize(&mut **self)
+        self.push(value)
     }
 
-    fn end(self) -> Result<()> {
-        Ok(())
+    fn end(self) -> Result<Parameter> {
+        Compound::end(self)
     }
 }
 
-impl<'se> ser::SerializeTupleStruct for &'se mut RecordSerializer {
-    type Ok = ();
+impl<'b> ser::SerializeTupleStruct for Compound<'b> {
+    type Ok = Parameter;
     type Error = Error;
 
     fn serialize_field<T>(&mut self, value: &T) -> Result<()>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        value.serialize(&mut **self)
+        self.push(value)
     }
 
-    fn end(self) -> Result<()> {
-        Ok(())
+    fn end(self) -> Result<Parameter> {
+        Compound::end(self)
     }
 }
 
-impl<'se> ser::SerializeTupleVariant for &'se mut RecordSerializer {
-    type Ok = ();
+impl<'b> ser::SerializeTupleVariant for Compound<'b> {
+    type Ok = Parameter;
     type Error = Error;
 
     fn serialize_field<T>(&mut self, value: &T) -> Result<()>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        value.serialize(&mut **self)
+        self.push(value)
     }
 
-    fn end(self) -> Result<()> {
-        Ok(())
+    fn end(self) -> Result<Parameter> {
+        Compound::end(self)
     }
 }
 
-impl<'se> ser::SerializeMap for &'se mut RecordSerializer {
-    type Ok = ();
+impl<'b> ser::SerializeMap for Compound<'b> {
+    type Ok = Parameter;
     type Error = Error;
 
-    fn serialize_key<T>(&mut self, _key: &T) -> Result<()>
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        unimplemented!("Serialize Map to Record is not supported yet.")
+        self.key(key)
     }
 
-    fn serialize_value<T>(&mut self, _value: &T) -> Result<()>
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        unimplemented!("Serialize Map to Record is not supported yet.")
+        self.value(value)
     }
 
-    fn end(self) -> Result<()> {
-        unimplemented!("Serialize Map to Record is not supported yet.")
+    fn end(self) -> Result<Parameter> {
+        Compound::end(self)
     }
 }
 
-impl<'se> ser::SerializeStruct for &'se mut RecordSerializer {
-    type Ok = ();
+impl<'b> ser::SerializeStruct for Compound<'b> {
+    type Ok = Parameter;
     type Error = Error;
 
     fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        value.serialize(&mut **self)
+        self.push(value)
     }
 
-    fn end(self) -> Result<()> {
-        if let Some((name, params)) = self.stack.pop() {
-            // restore stacked state
-            let name = std::mem::replace(&mut self.name, name);
-            let params = std::mem::replace(&mut self.parameters, params);
-            self.parameters.push(Parameter::Typed {
-                keyword: name,
-                parameter: Box::new(params.into_iter().collect()),
-            });
-        }
-        Ok(())
+    fn end(self) -> Result<Parameter> {
+        Compound::end(self)
     }
 }
 
-impl<'se> ser::SerializeStructVariant for &'se mut RecordSerializer {
-    type Ok = ();
+impl<'b> ser::SerializeStructVariant for Compound<'b> {
+    type Ok = Parameter;
     type Error = Error;
 
     fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<()>
     where
-        T: ?Sized + ser::Serialize,
+        T: ?Sized + Serialize,
     {
-        value.serialize(&mut **self)
+        self.push(value)
     }
 
-    fn end(self) -> Result<()> {
-        Ok(())
+    fn end(self) -> Result<Parameter> {
+        Compound::end(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive::Logical;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    #[serde(rename = "A")]
+    struct A {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename = "B")]
+    struct B {
+        z: f64,
+        a: A,
+        base: Option<Name>,
+    }
+
+    #[derive(Serialize)]
+    enum Color {
+        DarkRed,
+    }
+
+    #[test]
+    fn record() {
+        let b = B {
+            z: 3.0,
+            a: A { x: 1.0, y: 2.0 },
+            base: Some(Name::Entity(1)),
+        };
+        assert_eq!(
+            to_record(&b).unwrap(),
+            Record::from_str("B(3.0, A((1.0, 2.0)), #1)").unwrap()
+        );
+    }
+
+    #[test]
+    fn enumeration() {
+        for (p, expected) in [
+            (to_parameter(&true), ".T."),
+            (to_parameter(&Logical::Unknown), ".U."),
+            (to_parameter(&Color::DarkRed), ".DARK_RED."),
+            (to_parameter(&None::<f64>), "$"),
+        ] {
+            assert_eq!(p.unwrap(), Parameter::from_str(expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn variants() {
+        #[derive(Serialize)]
+        enum E {
+            T(i32, i32),
+            S { x: f64 },
+        }
+        assert_eq!(
+            to_record(&E::T(1, 2)).unwrap(),
+            Record::from_str("T(1, 2)").unwrap()
+        );
+        assert_eq!(
+            to_record(&E::S { x: 1.0 }).unwrap(),
+            Record::from_str("S(1.0)").unwrap()
+        );
+    }
+
+    #[test]
+    fn map() {
+        let map = maplit::hashmap! { "A" => vec![1, 2] };
+        assert_eq!(
+            to_record(&map).unwrap(),
+            Record::from_str("A(1, 2)").unwrap()
+        );
+        assert_eq!(
+            to_parameter(&map).unwrap(),
+            Parameter::from_str("A((1, 2))").unwrap()
+        );
+
+        let map = maplit::btreemap! { "A" => 1, "B" => 2 };
+        assert!(matches!(to_record(&map), Err(Error::SerializeFailed(_))));
+        let map = maplit::hashmap! { 1 => 2 };
+        assert!(matches!(to_record(&map), Err(Error::SerializeFailed(_))));
+    }
+
+    #[test]
+    fn bytes() {
+        struct Bytes(&'static [u8]);
+        impl Serialize for Bytes {
+            fn serialize<S: ser::Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+                s.serialize_bytes(self.0)
+            }
+        }
+        assert_eq!(
+            to_parameter(&Bytes(&[0xAB, 0xCD])).unwrap(),
+            Parameter::from_str("\"0ABCD\"").unwrap()
+        );
+    }
+
+    #[test]
+    fn large_integer() {
+        assert_eq!(
+            to_parameter(&(i64::MAX as u64)).unwrap(),
+            Parameter::Integer(i64::MAX)
+        );
+        assert!(matches!(
+            to_parameter(&u64::MAX),
+            Err(Error::SerializeFailed(_))
+        ));
+    }
+
+    #[test]
+    fn not_record() {
+        assert!(matches!(to_record(&1.0), Err(Error::SerializeFailed(_))));
+    }
+
+    #[test]
+    fn builder() {
+        let mut builder = DataSectionBuilder::new();
+        builder.reserve([5]);
+        let b = B {
+            z: 3.0,
+            a: A { x: 1.0, y: 2.0 },
+            base: None,
+        };
+        assert_eq!(builder.push(&b).unwrap(), 7);
+        // Equal entities get their own ids
+        assert_eq!(builder.push(&A { x: 1.0, y: 2.0 }).unwrap(), 8);
+        builder.insert(5, &A { x: 0.0, y: 0.0 }).unwrap();
+        assert!(matches!(
+            builder.insert(5, &A { x: 0.0, y: 0.0 }),
+            Err(Error::DuplicatedEntity(5))
+        ));
+        assert_eq!(
+            builder.build(),
+            DataSection::from_str(
+                "DATA; #5 = A(0.0, 0.0); #6 = A(1.0, 2.0); #7 = B(3.0, #6, $); #8 = A(1.0, 2.0); ENDSEC;"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn builder_share_equal() {
+        let mut builder = DataSectionBuilder::new().share_equal();
+        builder.insert(5, &A { x: 0.0, y: 0.0 }).unwrap();
+        let b = B {
+            z: 3.0,
+            a: A { x: -0.0, y: 0.0 },
+            base: None,
+        };
+        // `-0.0` is not shared with `0.0` to keep the sign
+        assert_eq!(builder.push(&b).unwrap(), 7);
+        assert_eq!(builder.push(&A { x: 1.0, y: 2.0 }).unwrap(), 8);
+        assert_eq!(builder.push(&A { x: 1.0, y: 2.0 }).unwrap(), 8);
+        let section = builder.build();
+        assert_eq!(
+            section,
+            DataSection::from_str(
+                "DATA; #5 = A(0.0, 0.0); #6 = A(-0.0, 0.0); #7 = B(3.0, #6, $); #8 = A(1.0, 2.0); ENDSEC;"
+            )
+            .unwrap()
+        );
+        match &section.entities[1] {
+            EntityInstance::Simple { record, .. } => match &record.parameter {
+                Parameter::List(params) => {
+                    assert!(matches!(params[0], Parameter::Real(x) if x.is_sign_negative()))
+                }
+                _ => panic!("parameter list expected"),
+            },
+            _ => panic!("simple record expected"),
+        }
+    }
+
+    #[test]
+    fn inline() {
+        #[derive(Serialize)]
+        #[serde(rename = "SUB")]
+        struct Sub {
+            #[serde(serialize_with = "inline")]
+            base: A,
+            w: f64,
+        }
+        fn inline<S: ser::Serializer>(a: &A, s: S) -> std::result::Result<S::Ok, S::Error> {
+            Inline(a).serialize(s)
+        }
+
+        let mut builder = DataSectionBuilder::new();
+        let sub = Sub {
+            base: A { x: 1.0, y: 2.0 },
+            w: 3.0,
+        };
+        builder.push(&sub).unwrap();
+        assert_eq!(
+            builder.build(),
+            DataSection::from_str("DATA; #1 = SUB(A((1.0, 2.0)), 3.0); ENDSEC;").unwrap()
+        );
     }
 }
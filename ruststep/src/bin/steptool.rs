@@ -0,0 +1,172 @@
+//! Executable for manipulating STEP exchange structures
+//!
+//! This requires `cli` feature, e.g. `cargo run --features cli --bin steptool -- --help`
+
+use ruststep::{
+    ast::Exchange,
+    diff::Diff,
+    parser,
+    transform::{Dedup, Extract, Merge, Order, SHARED_CONTEXT},
+    writer::Writer,
+};
+use std::{fs, io, path::*};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Copy instances and instances referred from them into a new exchange structure
+    Extract {
+        #[structopt(
+            long = "root",
+            required = true,
+            help = "Entity id to extract, e.g. 12 for #12"
+        )]
+        roots: Vec<u64>,
+        #[structopt(
+            long = "renumber",
+            help = "Renumber extracted instances as #1, #2, ..."
+        )]
+        renumber: bool,
+        #[structopt(
+            short = "o",
+            long = "output",
+            parse(from_os_str),
+            help = "Output file, stdout if not specified"
+        )]
+        output: Option<PathBuf>,
+        #[structopt(parse(from_os_str))]
+        input: PathBuf,
+    },
+    /// Merge exchange structures into one, e.g. part files into an assembly
+    Merge {
+        #[structopt(long = "dedup", help = "Share equal units and contexts among inputs")]
+        dedup: bool,
+        #[structopt(long = "renumber", help = "Renumber merged instances as #1, #2, ...")]
+        renumber: bool,
+        #[structopt(
+            short = "o",
+            long = "output",
+            parse(from_os_str),
+            help = "Output file, stdout if not specified"
+        )]
+        output: Option<PathBuf>,
+        #[structopt(parse(from_os_str), required = true)]
+        inputs: Vec<PathBuf>,
+    },
+    /// Collapse identical instances into one, and renumber instances
+    Dedup {
+        #[structopt(
+            long = "renumber",
+            possible_values = &["original", "topological"],
+            parse(try_from_str = parse_order),
+            help = "Renumber instances as #1, #2, ... in ascending order of ids or referred instances first"
+        )]
+        renumber: Option<Order>,
+        #[structopt(
+            short = "o",
+            long = "output",
+            parse(from_os_str),
+            help = "Output file, stdout if not specified"
+        )]
+        output: Option<PathBuf>,
+        #[structopt(parse(from_os_str))]
+        input: PathBuf,
+    },
+    /// Compare instances of two exchange structures by their content instead of ids
+    Diff {
+        #[structopt(long = "json", help = "Output in JSON format")]
+        json: bool,
+        #[structopt(parse(from_os_str))]
+        old: PathBuf,
+        #[structopt(parse(from_os_str))]
+        new: PathBuf,
+    },
+}
+
+fn main() {
+    match Command::from_args() {
+        Command::Extract {
+            roots,
+            renumber,
+            output,
+            input,
+        } => {
+            let extracted = Extract::new(&roots)
+                .renumber(renumber)
+                .apply(&load(&input))
+                .expect("Failed to extract instances");
+            save(output, &extracted);
+        }
+        Command::Merge {
+            dedup,
+            renumber,
+            output,
+            inputs,
+        } => {
+            let exchanges: Vec<Exchange> = inputs.iter().map(|input| load(input)).collect();
+            let merge = if dedup {
+                Merge::new().dedup(SHARED_CONTEXT)
+            } else {
+                Merge::new()
+            };
+            let merged = merge
+                .renumber(renumber)
+                .apply(&exchanges)
+                .expect("Failed to merge STEP files");
+            save(output, &merged);
+        }
+        Command::Dedup {
+            renumber,
+            output,
+            input,
+        } => {
+            let dedup = match renumber {
+                Some(order) => Dedup::new().renumber(true).order(order),
+                None => Dedup::new(),
+            };
+            let deduped = dedup
+                .apply(&load(&input))
+                .expect("Failed to collapse instances");
+            save(output, &deduped);
+        }
+        Command::Diff { json, old, new } => {
+            let diff = Diff::new(&load(&old).data, &load(&new).data)
+                .expect("Failed to compare STEP files");
+            if json {
+                serde_json::to_writer_pretty(io::stdout().lock(), &diff)
+                    .expect("Failed to write JSON");
+                println!();
+            } else {
+                print!("{}", diff);
+            }
+            if !diff.is_empty() {
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn parse_order(order: &str) -> Result<Order, String> {
+    match order {
+        "original" => Ok(Order::Original),
+        "topological" => Ok(Order::Topological),
+        _ => Err(format!("Unknown order: {}", order)),
+    }
+}
+
+fn load(input: &Path) -> Exchange {
+    let src = fs::read_to_string(input).expect("Failed to load STEP file");
+    parser::parse(&src).expect("Failed to parse STEP file")
+}
+
+fn save(output: Option<PathBuf>, exchange: &Exchange) {
+    let writer = Writer::new().indent(2);
+    match output {
+        Some(output) => {
+            let file = fs::File::create(output).expect("Failed to create output file");
+            writer.write(io::BufWriter::new(file), exchange)
+        }
+        None => writer.write(io::BufWriter::new(io::stdout().lock()), exchange),
+    }
+    .expect("Failed to write STEP file");
+}
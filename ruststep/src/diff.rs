@@ -0,0 +1,513 @@
+//! Structural diff of DATA sections
+//!
+//! Two exchange structures of the same model, e.g. exported by different tools,
+//! usually number their entity instances differently, and a text diff is useless.
+//! [Diff] matches instances by their content and position in the reference graph instead of `#id`:
+//!
+//! 1. Instances are matched if they have the same content
+//!    after replacing references by the content of referred instances recursively.
+//!    Instances in a reference cycle are compared by the entity names of the other instances in the cycle.
+//! 2. Unmatched instances of the same entity are matched
+//!    if they are referred at the same position from matched instances,
+//!    or if they are the only instances of the entity referring matched instances.
+//!    This is repeated until no more instances are matched.
+//! 3. Matched instances whose parameters differ are reported as changed,
+//!    and the remaining unmatched instances as removed or added.
+//!
+//! [Diff] implements [std::fmt::Display] for human-readable output,
+//! and [serde::Serialize] for machine-readable output, e.g. JSON.
+//!
+//! ```
+//! use ruststep::{ast::*, diff::Diff};
+//! use std::str::FromStr;
+//!
+//! let old = DataSection::from_str(r#"
+//! DATA;
+//!   #1 = CPT(0.0, 0.0);
+//!   #2 = CPT(1.0, 0.0);
+//!   #3 = LINE(#1, #2);
+//!   #4 = CPT(2.0, 0.0);
+//! ENDSEC;
+//! "#).unwrap();
+//! let new = DataSection::from_str(r#"
+//! DATA;
+//!   #10 = LINE(#11, #12);
+//!   #11 = CPT(0.0, 0.0);
+//!   #12 = CPT(1.0, 0.5);
+//!   #13 = CPT(3.0, 0.0);
+//! ENDSEC;
+//! "#).unwrap();
+//!
+//! let diff = Diff::new(&[old], &[new]).unwrap();
+//! assert_eq!(diff.unchanged, [(1, 11), (3, 10)]);
+//! assert_eq!(
+//!     diff.to_string(),
+//!     r#"
+//! ~ #2 -> #12 CPT[1]: 0.0 -> 0.5
+//! - #4 = CPT(2.0, 0.0);
+//! + #13 = CPT(3.0, 0.0);
+//! "#.trim_start()
+//! );
+//! ```
+
+use crate::{ast::*, error::*, graph::Graph};
+use serde::Serialize;
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap, VecDeque},
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// Difference between old and new DATA sections
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diff {
+    /// Pairs of old and new ids of matched instances without changes, sorted by old ids
+    pub unchanged: Vec<(u64, u64)>,
+    /// Matched instances with changes, sorted by old ids
+    pub changed: Vec<Change>,
+    /// Instances only in old sections
+    pub removed: Vec<Statement>,
+    /// Instances only in new sections
+    pub added: Vec<Statement>,
+}
+
+/// Pair of matched instances with different parameters
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Change {
+    pub old: u64,
+    pub new: u64,
+    pub parameters: Vec<ParameterChange>,
+}
+
+/// Different parameter of matched instances
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParameterChange {
+    /// Entity name of the record including the parameter
+    pub record: String,
+    /// Indices from the record to the parameter, e.g. `[1, 0]` for `2` in `A(1, (2, 3))`.
+    /// Empty if entire records differ.
+    pub path: Vec<usize>,
+    /// Parameter in old instance, with old ids
+    pub old: String,
+    /// Parameter in new instance, with new ids
+    pub new: String,
+}
+
+/// Entity instance only in one side
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Statement {
+    pub id: u64,
+    /// Entity instance written in exchange structure, e.g. `#1 = A(1.0);`
+    pub text: String,
+}
+
+impl Diff {
+    /// Compare DATA sections of two exchange structures
+    ///
+    /// Returns [Error::DuplicatedEntity] if an id is defined twice in either side.
+    pub fn new(old: &[DataSection], new: &[DataSection]) -> Result<Self> {
+        let old = Graph::from_sections(old)?;
+        let new = Graph::from_sections(new)?;
+        let mut matching = Matching::default();
+
+        // Instances with the same content
+        let mut by_hash: BTreeMap<u64, (Vec<u64>, Vec<u64>)> = BTreeMap::new();
+        for (id, hash) in content_hashes(&old) {
+            by_hash.entry(hash).or_default().0.push(id);
+        }
+        for (id, hash) in content_hashes(&new) {
+            by_hash.entry(hash).or_default().1.push(id);
+        }
+
+        // Match unique ones first, since equal instances,
+        // e.g. `CARTESIAN_POINT('', (0.0, 0.0, 0.0))`, are distinguished by their neighbors
+        let mut queue = VecDeque::new();
+        for (old_ids, new_ids) in by_hash.values() {
+            if let ([a], [b]) = (old_ids.as_slice(), new_ids.as_slice()) {
+                matching.insert(*a, *b);
+                queue.push_back((*a, *b));
+            }
+        }
+        matching.propagate(&old, &new, queue);
+
+        let mut queue = VecDeque::new();
+        for (mut old_ids, mut new_ids) in by_hash.into_values() {
+            old_ids.retain(|a| !matching.forward.contains_key(a));
+            new_ids.retain(|b| !matching.backward.contains_key(b));
+            old_ids.sort_unstable();
+            new_ids.sort_unstable();
+            for (a, b) in old_ids.into_iter().zip(new_ids) {
+                matching.insert(a, b);
+                queue.push_back((a, b));
+            }
+        }
+        matching.propagate(&old, &new, queue);
+
+        let mut diff = Diff {
+            unchanged: Vec::new(),
+            changed: Vec::new(),
+            removed: Vec::new(),
+            added: Vec::new(),
+        };
+        for (&a, &b) in &matching.forward {
+            let parameters = compare(old.get(a).unwrap(), new.get(b).unwrap(), &matching);
+            if parameters.is_empty() {
+                diff.unchanged.push((a, b));
+            } else {
+                diff.changed.push(Change {
+                    old: a,
+                    new: b,
+                    parameters,
+                });
+            }
+        }
+        diff.removed = unmatched(&old, &matching.forward);
+        diff.added = unmatched(&new, &matching.backward);
+        Ok(diff)
+    }
+
+    /// No instances are changed, removed, nor added
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+impl fmt::Display for Diff {
+    /// Changed instances as `~ #old -> #new RECORD[path]: old -> new`,
+    /// removed as `- #id = ...;`, and added as `+ #id = ...;`, one per line
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changed {
+            for parameter in &change.parameters {
+                write!(
+                    f,
+                    "~ #{} -> #{} {}",
+                    change.old, change.new, parameter.record
+                )?;
+                for index in &parameter.path {
+                    write!(f, "[{}]", index)?;
+                }
+                writeln!(f, ": {} -> {}", parameter.old, parameter.new)?;
+            }
+        }
+        for statement in &self.removed {
+            writeln!(f, "- {}", statement.text)?;
+        }
+        for statement in &self.added {
+            writeln!(f, "+ {}", statement.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// One-to-one correspondence between old and new ids
+#[derive(Debug, Default)]
+struct Matching {
+    forward: BTreeMap<u64, u64>,
+    backward: BTreeMap<u64, u64>,
+}
+
+impl Matching {
+    fn insert(&mut self, a: u64, b: u64) {
+        self.forward.insert(a, b);
+        self.backward.insert(b, a);
+    }
+
+    /// Match neighbors of matched instances in `queue` repeatedly
+    fn propagate(&mut self, old: &Graph, new: &Graph, mut queue: VecDeque<(u64, u64)>) {
+        while let Some((a, b)) = queue.pop_front() {
+            // Instances referred at the same position
+            let (refs_a, refs_b) = (old.references(a), new.references(b));
+            if refs_a.len() == refs_b.len() {
+                for (&x, &y) in refs_a.iter().zip(refs_b) {
+                    if self.try_insert(old, new, x, y) {
+                        queue.push_back((x, y));
+                    }
+                }
+            }
+            // Unmatched instances referring them, grouped by entity names
+            let mut parents: BTreeMap<Vec<&str>, (Vec<u64>, Vec<u64>)> = BTreeMap::new();
+            for &x in old.referenced_by(a) {
+                if !self.forward.contains_key(&x) {
+                    parents.entry(entity_names(old, x)).or_default().0.push(x);
+                }
+            }
+            for &y in new.referenced_by(b) {
+                if !self.backward.contains_key(&y) {
+                    parents.entry(entity_names(new, y)).or_default().1.push(y);
+                }
+            }
+            for (xs, ys) in parents.values() {
+                if let ([x], [y]) = (xs.as_slice(), ys.as_slice()) {
+                    if self.try_insert(old, new, *x, *y) {
+                        queue.push_back((*x, *y));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Match unmatched instances of the same entity
+    fn try_insert(&mut self, old: &Graph, new: &Graph, a: u64, b: u64) -> bool {
+        if self.forward.contains_key(&a) || self.backward.contains_key(&b) {
+            return false;
+        }
+        match (old.get(a), new.get(b)) {
+            (Some(_), Some(_)) if entity_names(old, a) == entity_names(new, b) => {
+                self.insert(a, b);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn entity_names<'a>(graph: &Graph<'a>, id: u64) -> Vec<&'a str> {
+    graph
+        .get(id)
+        .map(|instance| {
+            instance
+                .records()
+                .iter()
+                .map(|record| record.name.as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn hash_of(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hash of each instance with references replaced by the hashes of referred instances
+///
+/// References in a cycle are replaced by the entity names of referred instances,
+/// and undefined references are ignored.
+fn content_hashes(graph: &Graph) -> HashMap<u64, u64> {
+    let mut cycle_of = HashMap::new();
+    for (i, cycle) in graph.cycles().into_iter().enumerate() {
+        for id in cycle {
+            cycle_of.insert(id, i);
+        }
+    }
+    let placeholder = |id: u64| hash_of(&entity_names(graph, id));
+
+    // Referred instances out of the cycle are hashed first
+    let mut hashes = HashMap::new();
+    for id in graph.post_order() {
+        let mut instance = graph.get(id).unwrap().clone();
+        let cycle = cycle_of.get(&id);
+        instance.renumber(|to| {
+            if to == id {
+                0
+            } else if !graph.contains(to) {
+                1
+            } else if cycle.is_some() && cycle_of.get(&to) == cycle {
+                placeholder(to)
+            } else {
+                hashes[&to]
+            }
+        });
+        hashes.insert(id, hash_of(&instance.to_string()));
+    }
+    hashes
+}
+
+/// Parameters of matched instances which differ
+fn compare(
+    old: &EntityInstance,
+    new: &EntityInstance,
+    matching: &Matching,
+) -> Vec<ParameterChange> {
+    let mut changes = Vec::new();
+    let (old_records, new_records) = (old.records(), new.records());
+    if old_records.len() != new_records.len()
+        || old_records
+            .iter()
+            .zip(new_records)
+            .any(|(a, b)| a.name != b.name)
+    {
+        changes.push(ParameterChange {
+            record: entity_names_of(old_records),
+            path: Vec::new(),
+            old: statement_body(old),
+            new: statement_body(new),
+        });
+        return changes;
+    }
+    for (a, b) in old_records.iter().zip(new_records) {
+        compare_parameter(
+            &a.name,
+            &mut Vec::new(),
+            &a.parameter,
+            &b.parameter,
+            matching,
+            &mut changes,
+        );
+    }
+    changes
+}
+
+fn compare_parameter(
+    record: &str,
+    path: &mut Vec<usize>,
+    old: &Parameter,
+    new: &Parameter,
+    matching: &Matching,
+    changes: &mut Vec<ParameterChange>,
+) {
+    let same = match (old, new) {
+        (Parameter::List(a), Parameter::List(b)) if a.len() == b.len() => {
+            for (i, (a, b)) in a.iter().zip(b).enumerate() {
+                path.push(i);
+                compare_parameter(record, path, a, b, matching, changes);
+                path.pop();
+            }
+            return;
+        }
+        (
+            Parameter::Typed {
+                keyword: ka,
+                parameter: a,
+            },
+            Parameter::Typed {
+                keyword: kb,
+                parameter: b,
+            },
+        ) if ka == kb => {
+            compare_parameter(record, path, a, b, matching, changes);
+            return;
+        }
+        (Parameter::Ref(Name::Entity(a)), Parameter::Ref(Name::Entity(b))) => {
+            matching.forward.get(a) == Some(b)
+        }
+        (a, b) => a == b,
+    };
+    if !same {
+        changes.push(ParameterChange {
+            record: record.to_string(),
+            path: path.clone(),
+            old: old.to_string(),
+            new: new.to_string(),
+        });
+    }
+}
+
+fn entity_names_of(records: &[Record]) -> String {
+    records
+        .iter()
+        .map(|record| record.name.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Right hand side of the statement, e.g. `A(1.0)` for `#1 = A(1.0);`
+fn statement_body(instance: &EntityInstance) -> String {
+    match instance {
+        EntityInstance::Simple { record, .. } => record.to_string(),
+        EntityInstance::Complex { subsuper, .. } => subsuper.to_string(),
+    }
+}
+
+/// Instances not in `matched`
+fn unmatched(graph: &Graph, matched: &BTreeMap<u64, u64>) -> Vec<Statement> {
+    graph
+        .ids()
+        .filter(|id| !matched.contains_key(id))
+        .map(|id| Statement {
+            id,
+            text: graph.get(id).unwrap().to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transform::Merge;
+    use std::str::FromStr;
+
+    fn sample() -> Exchange {
+        let step_str = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/steps/00000050_80d90bfdd2e74e709956122a_step_000.step"
+        ))
+        .unwrap();
+        Exchange::from_str(&step_str).unwrap()
+    }
+
+    #[test]
+    fn renumbered() {
+        let old = sample();
+        let new = Merge::new().renumber(true).apply(&[old.clone()]).unwrap();
+        assert_ne!(old.data, new.data);
+        let diff = Diff::new(&old.data, &new.data).unwrap();
+        assert!(diff.is_empty());
+        assert_eq!(diff.unchanged.len(), old.data[0].entities.len());
+    }
+
+    #[test]
+    fn changed_point() {
+        let old = sample();
+        let mut new = Merge::new().renumber(true).apply(&[old.clone()]).unwrap();
+        let point = new.data[0]
+            .entities
+            .iter_mut()
+            .find(|instance| instance.records()[0].name == "CARTESIAN_POINT")
+            .unwrap();
+        let id = point.id();
+        match &mut point.records_mut()[0].parameter {
+            Parameter::List(list) => list[1] = Parameter::from_iter(vec![Parameter::Real(1.0); 3]),
+            _ => unreachable!(),
+        }
+
+        let diff = Diff::new(&old.data, &new.data).unwrap();
+        assert!(diff.removed.is_empty());
+        assert!(diff.added.is_empty());
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].new, id);
+        // Each coordinate is reported
+        let parameters = &diff.changed[0].parameters;
+        assert_eq!(parameters.len(), 3);
+        assert_eq!(parameters[0].record, "CARTESIAN_POINT");
+        assert_eq!(parameters[0].path, [1, 0]);
+        assert_eq!(parameters[0].new, "1.0");
+    }
+
+    #[test]
+    fn cycles() {
+        let old = DataSection::from_str(
+            r#"
+            DATA;
+              #1 = A(#2, 1);
+              #2 = B(#1);
+              #3 = (C(#3) D(1.0));
+            ENDSEC;
+            "#,
+        )
+        .unwrap();
+        let new = DataSection::from_str(
+            r#"
+            DATA;
+              #5 = (C(#5) D(2.0));
+              #6 = B(#7);
+              #7 = A(#6, 1);
+            ENDSEC;
+            "#,
+        )
+        .unwrap();
+        let diff = Diff::new(&[old], &[new]).unwrap();
+        assert_eq!(diff.unchanged, [(1, 7), (2, 6)]);
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.added.len(), 1);
+    }
+
+    #[test]
+    fn complex() {
+        let old = DataSection::from_str("DATA; #1 = (A(1) B(#2)); #2 = C(); ENDSEC;").unwrap();
+        let new = DataSection::from_str("DATA; #1 = (A(2) B(#2)); #2 = C(); ENDSEC;").unwrap();
+        let diff = Diff::new(&[old], &[new]).unwrap();
+        assert_eq!(diff.to_string(), "~ #1 -> #1 A[0]: 1 -> 2\n");
+    }
+}
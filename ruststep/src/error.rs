@@ -8,12 +8,24 @@ pub enum Error {
     #[error(transparent)]
     TokenizeFailed(#[from] TokenizeFailed),
 
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid string literal '{raw}': {reason}")]
+    InvalidStringLiteral { raw: String, reason: &'static str },
+
     #[error("Extra input string remains behind: {0}")]
     ExtraInputRemaining(String),
 
     #[error("Error while deserialize STEP struct: {0}")]
     DeserializeFailed(String),
 
+    #[error("Error while serialize STEP struct: {0}")]
+    SerializeFailed(String),
+
+    #[error("Real number {0} cannot be written in exchange structure")]
+    NonFiniteReal(f64),
+
     #[error("Lookup failed for #{0}")]
     UnknownEntity(u64),
 
@@ -22,6 +34,47 @@ pub enum Error {
 
     #[error("Entity '{entity_name}' is not a member of the schema '{schema}'")]
     UnknownEntityName { entity_name: String, schema: String },
+
+    #[error("Invalid complex entity instance: {0}")]
+    InvalidComplexEntity(String),
+
+    #[error("Cyclic reference among {0:?}")]
+    CyclicReference(Vec<u64>),
+
+    #[error("Cyclic reference while converting into owned value: {}", chain(.0))]
+    CyclicOwnership(Vec<u64>),
+
+    #[error("Invalid HEADER section: {0}")]
+    InvalidHeader(String),
+
+    #[error("Schema '{0}' is not supported by ruststep")]
+    UnknownSchema(String),
+
+    #[error("Schema '{schema}' requires the cargo feature '{feature}' of ruststep")]
+    SchemaNotEnabled {
+        schema: String,
+        feature: &'static str,
+    },
+
+    #[error("Name {0} is not resolved through ANCHOR or REFERENCE section")]
+    UnresolvedName(String),
+
+    #[error("Anchor <{0}> is duplicated")]
+    DuplicatedAnchor(String),
+
+    #[error("Cannot merge exchange structures of different schemas {expected:?} and {found:?}")]
+    SchemaMismatch {
+        expected: Vec<String>,
+        found: Vec<String>,
+    },
+}
+
+/// Entity ids joined by arrows, e.g. `#4 -> #5 -> #4`
+fn chain(ids: &[u64]) -> String {
+    ids.iter()
+        .map(|id| format!("#{}", id))
+        .collect::<Vec<_>>()
+        .join(" -> ")
 }
 
 impl de::Error for Error {
@@ -38,13 +91,17 @@ impl ser::Error for Error {
     where
         T: fmt::Display,
     {
-        Error::DeserializeFailed(msg.to_string())
+        Error::SerializeFailed(msg.to_string())
     }
 }
 
 /// Error while tokenizing STEP input
+///
+/// [Display](fmt::Display) shows the failed line rendered by nom,
+/// and [TokenizeFailed::diagnostic] gives where it fails in structured form.
 pub struct TokenizeFailed {
     rendered_error: String,
+    diagnostic: Diagnostic,
 }
 
 impl fmt::Debug for TokenizeFailed {
@@ -68,9 +125,164 @@ impl fmt::Display for TokenizeFailed {
 impl std::error::Error for TokenizeFailed {}
 
 impl TokenizeFailed {
+    /// `input` must contain the inputs in `err`, i.e. the entire input given to the parser
+    ///
+    /// [Diagnostic::entity_id] is left `None`, which is filled by the parser.
     pub fn new(input: &str, err: nom::error::VerboseError<&str>) -> Self {
+        let diagnostic = Diagnostic::new(input, &err);
         TokenizeFailed {
             rendered_error: nom::error::convert_error(input, err),
+            diagnostic,
         }
     }
+
+    pub fn diagnostic(&self) -> &Diagnostic {
+        &self.diagnostic
+    }
+
+    /// Set the id of the entity instance being tokenized
+    pub(crate) fn with_entity_id(mut self, entity_id: Option<u64>) -> Self {
+        self.diagnostic.entity_id = entity_id;
+        self
+    }
+
+    /// Shift the location for the input starting at the given position in a larger input
+    pub(crate) fn shift(mut self, offset: usize, line: usize, column: usize) -> Self {
+        let d = &mut self.diagnostic;
+        if d.line == 1 {
+            d.column += column - 1;
+        }
+        d.line += line - 1;
+        d.offset += offset;
+        self
+    }
+}
+
+/// Where and why tokenizing failed
+///
+/// ```
+/// use ruststep::{ast::DataSection, error::Error};
+/// use std::str::FromStr;
+///
+/// let err = DataSection::from_str("DATA;\n#1 = A(1.0);\n#2 = B(1.0, #1;\nENDSEC;").unwrap_err();
+/// let diagnostic = match &err {
+///     Error::TokenizeFailed(e) => e.diagnostic(),
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(diagnostic.line, 3);
+/// assert_eq!(diagnostic.column, 15);
+/// assert_eq!(diagnostic.entity_id, Some(2));
+/// assert_eq!(diagnostic.expected, "')'");
+/// assert_eq!(diagnostic.to_string(), "line 3, column 15 in #2: expected ')'");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Byte offset from the head of input
+    pub offset: usize,
+    /// Line number starting from 1
+    pub line: usize,
+    /// Column number in characters starting from 1
+    pub column: usize,
+    /// Id of the entity instance being tokenized, e.g. `2` for `#2 = B(1.0, #1;`
+    pub entity_id: Option<u64>,
+    /// Token or syntax element expected at the location, e.g. `')'` or `ENDSEC;`
+    pub expected: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)?;
+        if let Some(id) = self.entity_id {
+            write!(f, " in #{}", id)?;
+        }
+        write!(f, ": expected {}", self.expected)
+    }
+}
+
+impl Diagnostic {
+    fn new(input: &str, err: &nom::error::VerboseError<&str>) -> Self {
+        use nom::error::VerboseErrorKind;
+
+        // The first error is the innermost one, i.e. where tokenizing actually failed
+        let offset = match err.errors.first() {
+            Some((remaining, _)) => offset_in(input, remaining),
+            None => input.len(),
+        };
+        let expected = err
+            .errors
+            .iter()
+            .find_map(|(_, kind)| match kind {
+                VerboseErrorKind::Char(c) => Some(format!("'{}'", c)),
+                VerboseErrorKind::Context(context) => Some(context.to_string()),
+                VerboseErrorKind::Nom(_) => None,
+            })
+            .or_else(|| err.errors.first().map(|(_, kind)| format!("{:?}", kind)))
+            .unwrap_or_default();
+
+        let (line, column) = line_column(&input[..offset]);
+        Diagnostic {
+            offset,
+            line,
+            column,
+            entity_id: None,
+            expected,
+        }
+    }
+}
+
+/// Line and column at the end of `head`
+pub(crate) fn line_column(head: &str) -> (usize, usize) {
+    let line = head.matches('\n').count() + 1;
+    let column = match head.rfind('\n') {
+        Some(pos) => head[pos + 1..].chars().count() + 1,
+        None => head.chars().count() + 1,
+    };
+    (line, column)
+}
+
+/// Byte offset of `remaining` in `input`, assuming `remaining` is a sub-slice of `input`
+fn offset_in(input: &str, remaining: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let pos = remaining.as_ptr() as usize;
+    if start <= pos && pos <= start + input.len() {
+        pos - start
+    } else {
+        input.len().saturating_sub(remaining.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::*;
+    use std::str::FromStr;
+
+    const HEADER: &str = "ISO-10303-21;\nHEADER;\nFILE_SCHEMA(('EXAMPLE'));\nENDSEC;\n";
+
+    fn diagnostic(body: &str) -> Diagnostic {
+        match Exchange::from_str(&format!("{}{}", HEADER, body)) {
+            Err(Error::TokenizeFailed(e)) => e.diagnostic().clone(),
+            _ => panic!("tokenize must fail"),
+        }
+    }
+
+    #[test]
+    fn expected_keyword() {
+        // `ENDSEC;` is missing at the end of input
+        let d = diagnostic("DATA;\n#1 = A(1.0);");
+        assert_eq!(d.line, 6);
+        assert_eq!(d.column, 13);
+        assert_eq!(d.entity_id, None);
+        assert_eq!(d.expected, "ENDSEC;");
+    }
+
+    #[test]
+    fn non_ascii_column() {
+        let d = diagnostic("DATA;\n#12 = A('魔法', /* ; */ .T);\nENDSEC;\nEND-ISO-10303-21;\n");
+        // Parameter list ends before `.T`, which is not a valid enumeration
+        assert_eq!(d.line, 6);
+        assert_eq!(d.column, 13);
+        assert_eq!(d.entity_id, Some(12));
+        assert_eq!(d.expected, "')'");
+    }
 }
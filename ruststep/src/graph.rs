@@ -0,0 +1,353 @@
+//! Reference graph of entity instances
+//!
+//! Entity instances refer other instances by their names, e.g. `#1` in `#2 = B(#1);`,
+//! and thus an exchange structure consists a directed graph.
+//! [Graph] indexes this graph directly on the AST,
+//! i.e. it can be used for any schema without generated `Tables`.
+//!
+//! ```
+//! use ruststep::{ast::DataSection, graph::Graph};
+//! use std::str::FromStr;
+//!
+//! let data = DataSection::from_str(r#"
+//! DATA;
+//!   #1 = CPT(0.0, 0.0);
+//!   #2 = CPT(1.0, 0.0);
+//!   #3 = LINE(#1, #2);
+//!   #4 = CURVE_SET((#3, #5));
+//!   #6 = CPT(2.0, 0.0);
+//! ENDSEC;
+//! "#).unwrap();
+//! let graph = Graph::new(&data).unwrap();
+//!
+//! assert_eq!(graph.references(3), [1, 2]);
+//! assert_eq!(graph.referenced_by(1), [3]);
+//!
+//! // `#5` is never defined
+//! assert_eq!(graph.dangling(), [(4, 5)]);
+//! // Instances not referred from others
+//! assert_eq!(graph.orphans(), [4, 6]);
+//! // Referred instances come first
+//! assert_eq!(graph.topological_order().unwrap(), [1, 2, 3, 4, 6]);
+//! ```
+
+use crate::{ast::*, error::*};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Node<'a> {
+    instance: &'a EntityInstance,
+    references: Vec<u64>,
+    referenced_by: Vec<u64>,
+}
+
+/// Index of entity instances and their references in DATA sections
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph<'a> {
+    nodes: BTreeMap<u64, Node<'a>>,
+}
+
+impl<'a> Graph<'a> {
+    /// Returns [Error::DuplicatedEntity] if an entity id is defined twice
+    pub fn new(data: &'a DataSection) -> Result<Self> {
+        Self::from_sections(std::slice::from_ref(data))
+    }
+
+    /// Graph of all DATA sections in an exchange structure, which share entity ids
+    pub fn from_sections(sections: &'a [DataSection]) -> Result<Self> {
+        let mut nodes = BTreeMap::new();
+        for instance in sections.iter().flat_map(|data| data.entities.iter()) {
+            let node = Node {
+                instance,
+                references: instance.references(),
+                referenced_by: Vec::new(),
+            };
+            if nodes.insert(instance.id(), node).is_some() {
+                return Err(Error::DuplicatedEntity(instance.id()));
+            }
+        }
+        // Since `nodes` is iterated in ascending order, `referenced_by` is also sorted
+        let edges: Vec<(u64, u64)> = nodes
+            .iter()
+            .flat_map(|(&id, node)| node.references.iter().map(move |&to| (id, to)))
+            .collect();
+        for (from, to) in edges {
+            if let Some(node) = nodes.get_mut(&to) {
+                node.referenced_by.push(from);
+            }
+        }
+        Ok(Graph { nodes })
+    }
+
+    /// Number of entity instances
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Entity ids in ascending order
+    pub fn ids(&self) -> impl Iterator<Item = u64> + '_ {
+        self.nodes.keys().cloned()
+    }
+
+    pub fn get(&self, id: u64) -> Option<&'a EntityInstance> {
+        self.nodes.get(&id).map(|node| node.instance)
+    }
+
+    pub fn contains(&self, id: u64) -> bool {
+        self.nodes.contains_key(&id)
+    }
+
+    /// Ids referred from `#id` in order of appearance, including undefined ones
+    pub fn references(&self, id: u64) -> &[u64] {
+        self.nodes
+            .get(&id)
+            .map(|node| node.references.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Ids referring `#id` in ascending order
+    pub fn referenced_by(&self, id: u64) -> &[u64] {
+        self.nodes
+            .get(&id)
+            .map(|node| node.referenced_by.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Ids of `roots` and all instances reachable from them through references, in ascending order
+    ///
+    /// Undefined ids referred from them are not included.
+    /// Returns [Error::UnknownEntity] if a root is not defined.
+    pub fn closure(&self, roots: &[u64]) -> Result<Vec<u64>> {
+        let mut found = BTreeSet::new();
+        let mut stack = Vec::new();
+        for &root in roots {
+            if !self.nodes.contains_key(&root) {
+                return Err(Error::UnknownEntity(root));
+            }
+            if found.insert(root) {
+                stack.push(root);
+            }
+        }
+        while let Some(id) = stack.pop() {
+            for &to in self.references(id) {
+                if self.nodes.contains_key(&to) && found.insert(to) {
+                    stack.push(to);
+                }
+            }
+        }
+        Ok(found.into_iter().collect())
+    }
+
+    /// References to undefined instances as pairs of the referring id and the undefined id
+    pub fn dangling(&self) -> Vec<(u64, u64)> {
+        self.nodes
+            .iter()
+            .flat_map(|(&id, node)| {
+                node.references
+                    .iter()
+                    .filter(|to| !self.nodes.contains_key(to))
+                    .map(move |&to| (id, to))
+            })
+            .collect()
+    }
+
+    /// Instances not referred from any other instance
+    ///
+    /// Note that the roots of the graph, e.g. `PRODUCT` instances, are also listed here.
+    pub fn orphans(&self) -> Vec<u64> {
+        self.nodes
+            .iter()
+            .filter(|(&id, node)| node.referenced_by.iter().all(|&from| from == id))
+            .map(|(&id, _)| id)
+            .collect()
+    }
+
+    /// Instances which can reach each other through references
+    ///
+    /// Each cycle is a strongly connected component of the graph with more than one instances,
+    /// or an instance referring itself. Ids are sorted in each cycle.
+    pub fn cycles(&self) -> Vec<Vec<u64>> {
+        // Tarjan's algorithm without recursion to handle deep graphs
+        let mut index = HashMap::new();
+        let mut lowlink = HashMap::new();
+        let mut stack = Vec::new();
+        let mut on_stack = HashSet::new();
+        let mut cycles = Vec::new();
+
+        for &root in self.nodes.keys() {
+            if index.contains_key(&root) {
+                continue;
+            }
+            // Nodes in the DFS path and the position of their next reference
+            let mut path = vec![(root, 0)];
+            index.insert(root, index.len());
+            lowlink.insert(root, index[&root]);
+            stack.push(root);
+            on_stack.insert(root);
+
+            while let Some((id, pos)) = path.last_mut() {
+                let id = *id;
+                let references = self.references(id);
+                if let Some(&to) = references.get(*pos) {
+                    *pos += 1;
+                    if !self.nodes.contains_key(&to) {
+                        continue;
+                    }
+                    if !index.contains_key(&to) {
+                        index.insert(to, index.len());
+                        lowlink.insert(to, index[&to]);
+                        stack.push(to);
+                        on_stack.insert(to);
+                        path.push((to, 0));
+                    } else if on_stack.contains(&to) {
+                        let low = lowlink[&id].min(index[&to]);
+                        lowlink.insert(id, low);
+                    }
+                    continue;
+                }
+
+                path.pop();
+                if let Some(&(parent, _)) = path.last() {
+                    let low = lowlink[&parent].min(lowlink[&id]);
+                    lowlink.insert(parent, low);
+                }
+                if lowlink[&id] == index[&id] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack.remove(&member);
+                        component.push(member);
+                        if member == id {
+                            break;
+                        }
+                    }
+                    if component.len() > 1 || references.contains(&id) {
+                        component.sort_unstable();
+                        cycles.push(component);
+                    }
+                }
+            }
+        }
+        cycles.sort();
+        cycles
+    }
+
+    /// Entity ids ordered so that referred instances come before referring instances
+    ///
+    /// Instances independent of each other are ordered by their ids.
+    /// Returns [Error::CyclicReference] with the instances in a cycle if the graph has cycles.
+    pub fn topological_order(&self) -> Result<Vec<u64>> {
+        if let Some(cycle) = self.cycles().into_iter().next() {
+            return Err(Error::CyclicReference(cycle));
+        }
+        Ok(self.post_order())
+    }
+
+    /// Entity ids in post-order of depth-first search starting from smaller ids
+    ///
+    /// This equals to [Graph::topological_order] if the graph has no cycles.
+    /// Otherwise, instances in a cycle are ordered arbitrary,
+    /// but referred instances out of the cycle still come before them.
+    pub fn post_order(&self) -> Vec<u64> {
+        let mut visited = HashSet::new();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        for &root in self.nodes.keys() {
+            if !visited.insert(root) {
+                continue;
+            }
+            let mut path = vec![(root, 0)];
+            while let Some((id, pos)) = path.last_mut() {
+                let id = *id;
+                match self.references(id).get(*pos) {
+                    Some(&to) => {
+                        *pos += 1;
+                        if self.nodes.contains_key(&to) && visited.insert(to) {
+                            path.push((to, 0));
+                        }
+                    }
+                    None => {
+                        path.pop();
+                        order.push(id);
+                    }
+                }
+            }
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn cycles() {
+        let data = DataSection::from_str(
+            r#"
+            DATA;
+              #1 = A(#2);
+              #2 = B(#3);
+              #3 = C(#1);
+              #4 = D(#4);
+              #5 = E((#1, #6));
+              #6 = (F(#5) G(1.0));
+            ENDSEC;
+            "#,
+        )
+        .unwrap();
+        let graph = Graph::new(&data).unwrap();
+        assert_eq!(graph.cycles(), [vec![1, 2, 3], vec![4], vec![5, 6]]);
+        assert_eq!(graph.post_order(), [3, 2, 1, 4, 6, 5]);
+        assert!(matches!(
+            graph.topological_order(),
+            Err(Error::CyclicReference(cycle)) if cycle == [1, 2, 3]
+        ));
+        // Self reference is not a referrer
+        assert_eq!(graph.orphans(), [4]);
+    }
+
+    #[test]
+    fn deep_chain() {
+        let n = 100_000;
+        let data = DataSection {
+            meta: Vec::new(),
+            entities: (1..=n)
+                .map(|id| EntityInstance::Simple {
+                    id,
+                    record: Record {
+                        name: "A".to_string(),
+                        parameter: Parameter::List(vec![Parameter::Ref(Name::Entity(id + 1))]),
+                    },
+                })
+                .collect(),
+        };
+        let graph = Graph::new(&data).unwrap();
+        assert!(graph.cycles().is_empty());
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order.first(), Some(&n));
+        assert_eq!(order.last(), Some(&1));
+    }
+
+    #[test]
+    fn multiple_sections() {
+        let sections = [
+            DataSection::from_str("DATA; #1 = A(#2); ENDSEC;").unwrap(),
+            DataSection::from_str("DATA; #2 = B(); ENDSEC;").unwrap(),
+        ];
+        let graph = Graph::from_sections(&sections).unwrap();
+        assert!(graph.dangling().is_empty());
+        assert_eq!(graph.referenced_by(2), [1]);
+        assert_eq!(graph.closure(&[1]).unwrap(), [1, 2]);
+        assert!(matches!(graph.closure(&[3]), Err(Error::UnknownEntity(3))));
+
+        let sections = [sections[0].clone(), sections[0].clone()];
+        assert!(matches!(
+            Graph::from_sections(&sections),
+            Err(Error::DuplicatedEntity(1))
+        ));
+    }
+}
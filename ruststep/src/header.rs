@@ -19,9 +19,32 @@
 //! Although we can generate corresponding Rust struct using espr compiler,
 //! we write these definitions manually to keep development process simple.
 //!
+//! [HeaderBuilder] creates a [Header] for writing exchange structures:
+//!
+//! ```
+//! use ruststep::header::*;
+//!
+//! let header = HeaderBuilder::new()
+//!     .name("example.step")
+//!     .author("John Doe")
+//!     .schema("CONFIG_CONTROL_DESIGN")
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(header.file_schema.schema, ["CONFIG_CONTROL_DESIGN"]);
+//! assert!(header.file_name.preprocessor_version.starts_with("ruststep"));
+//!
+//! // Into records, and back
+//! let records = header.to_records().unwrap();
+//! assert_eq!(Header::from_records(&records).unwrap(), header);
+//! ```
+//!
 
-use crate::{ast::*, error::Result};
-use serde::Deserialize;
+use crate::{
+    ast::{ser::to_record, *},
+    error::*,
+};
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// File description
 ///
@@ -34,7 +57,8 @@ use serde::Deserialize;
 ///   implementation_level : STRING (256) ;
 /// END_ENTITY;
 /// ```
-#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize)]
+#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize, Serialize)]
+#[serde(rename = "FILE_DESCRIPTION")]
 pub struct FileDescription {
     pub description: Vec<String>,
     pub implementation_level: String,
@@ -59,7 +83,8 @@ pub struct FileDescription {
 /// TYPE time_stamp_text = STRING(256);
 /// END_TYPE;
 /// ```
-#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize)]
+#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize, Serialize)]
+#[serde(rename = "FILE_NAME")]
 pub struct FileName {
     pub name: String,
     /// ISO-8601 formatted date and time specifying when the exchange structure was created.
@@ -84,11 +109,90 @@ pub struct FileName {
 /// TYPE schema_name = STRING(1024);
 /// END_TYPE;
 /// ```
-#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize)]
+#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize, Serialize)]
+#[serde(rename = "FILE_SCHEMA")]
 pub struct FileSchema {
     pub schema: Vec<String>,
 }
 
+/// Schema population
+///
+/// Following EXPRESS schema is an exerpt from
+/// [ISO-10303-21:2016(E) "8.2.5 schema_population"](https://www.iso.org/standard/63141.html):
+///
+/// ```text
+/// ENTITY schema_population;
+///   external_file_identifications : SET OF STRING (1024) ;
+/// END_ENTITY;
+/// ```
+#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize, Serialize)]
+#[serde(rename = "SCHEMA_POPULATION")]
+pub struct SchemaPopulation {
+    pub external_file_identifications: Vec<String>,
+}
+
+/// File population
+///
+/// Following EXPRESS schema is an exerpt from
+/// [ISO-10303-21:2016(E) "8.2.6 file_population"](https://www.iso.org/standard/63141.html):
+///
+/// ```text
+/// ENTITY file_population;
+///   governing_schema     : schema_name;
+///   determination_method : exchange_structure_identifier;
+///   governed_sections    : OPTIONAL SET [1:?] OF section_name;
+/// END_ENTITY;
+/// ```
+#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize, Serialize)]
+#[serde(rename = "FILE_POPULATION")]
+pub struct FilePopulation {
+    pub governing_schema: String,
+    pub determination_method: String,
+    pub governed_sections: Option<Vec<String>>,
+}
+
+/// Section language
+///
+/// Following EXPRESS schema is an exerpt from
+/// [ISO-10303-21:2016(E) "8.2.7 section_language"](https://www.iso.org/standard/63141.html):
+///
+/// ```text
+/// ENTITY section_language;
+///   section          : OPTIONAL section_name;
+///   default_language : language_name;
+/// UNIQUE
+///   UR1 : section;
+/// END_ENTITY;
+/// ```
+#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize, Serialize)]
+#[serde(rename = "SECTION_LANGUAGE")]
+pub struct SectionLanguage {
+    /// Applies to all sections without own `section_language` if not specified
+    pub section: Option<String>,
+    pub default_language: String,
+}
+
+/// Section context
+///
+/// Following EXPRESS schema is an exerpt from
+/// [ISO-10303-21:2016(E) "8.2.8 section_context"](https://www.iso.org/standard/63141.html):
+///
+/// ```text
+/// ENTITY section_context;
+///   section             : OPTIONAL section_name;
+///   context_identifiers : LIST [1:?] OF context_name;
+/// UNIQUE
+///   UR1 : section;
+/// END_ENTITY;
+/// ```
+#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize, Serialize)]
+#[serde(rename = "SECTION_CONTEXT")]
+pub struct SectionContext {
+    /// Applies to all sections without own `section_context` if not specified
+    pub section: Option<String>,
+    pub context_identifiers: Vec<String>,
+}
+
 /// STEP-file HEADER section
 ///
 /// There is a schema for HEADER section,
@@ -99,25 +203,299 @@ pub struct Header {
     pub file_description: FileDescription,
     pub file_name: FileName,
     pub file_schema: FileSchema,
+    pub schema_population: Option<SchemaPopulation>,
+    pub file_population: Vec<FilePopulation>,
+    pub section_language: Vec<SectionLanguage>,
+    pub section_context: Vec<SectionContext>,
+    /// User-defined header entities, kept as they are
+    pub user_defined: Vec<Record>,
 }
 
 impl Header {
+    /// Read header records
+    ///
+    /// Returns [Error::InvalidHeader] if `file_description`, `file_name`, and `file_schema`
+    /// do not appear first in this order, or if any of them or `schema_population` appears twice.
     pub fn from_records(records: &[Record]) -> Result<Self> {
-        assert!(records.len() >= 3);
-        let file_description = FileDescription::deserialize(&records[0])?;
-        let file_name = FileName::deserialize(&records[1])?;
-        let file_schema = FileSchema::deserialize(&records[2])?;
-        Ok(Header {
+        let mut iter = records.iter();
+        let file_description =
+            FileDescription::deserialize(next_record(&mut iter, "FILE_DESCRIPTION")?)?;
+        let file_name = FileName::deserialize(next_record(&mut iter, "FILE_NAME")?)?;
+        let file_schema = FileSchema::deserialize(next_record(&mut iter, "FILE_SCHEMA")?)?;
+        let mut header = Header {
             file_description,
             file_name,
             file_schema,
+            schema_population: None,
+            file_population: Vec::new(),
+            section_language: Vec::new(),
+            section_context: Vec::new(),
+            user_defined: Vec::new(),
+        };
+        for record in iter {
+            match record.name.as_str() {
+                "SCHEMA_POPULATION" => {
+                    if header.schema_population.is_some() {
+                        return Err(Error::InvalidHeader(
+                            "SCHEMA_POPULATION appears twice".to_string(),
+                        ));
+                    }
+                    header.schema_population = Some(SchemaPopulation::deserialize(record)?);
+                }
+                "FILE_POPULATION" => header
+                    .file_population
+                    .push(FilePopulation::deserialize(record)?),
+                "SECTION_LANGUAGE" => header
+                    .section_language
+                    .push(SectionLanguage::deserialize(record)?),
+                "SECTION_CONTEXT" => header
+                    .section_context
+                    .push(SectionContext::deserialize(record)?),
+                name @ ("FILE_DESCRIPTION" | "FILE_NAME" | "FILE_SCHEMA") => {
+                    return Err(Error::InvalidHeader(format!("{} appears twice", name)));
+                }
+                _ => header.user_defined.push(record.clone()),
+            }
+        }
+        Ok(header)
+    }
+
+    /// Set the current time and ruststep as the time stamp and preprocessor version
+    /// for writing an exchange structure derived from this header
+    pub fn touch(&mut self) {
+        self.file_name.time_stamp = format_time_stamp(SystemTime::now());
+        self.file_name.preprocessor_version = PREPROCESSOR_VERSION.to_string();
+    }
+
+    /// Header records in the order defined in ISO-10303-21
+    pub fn to_records(&self) -> Result<Vec<Record>> {
+        let mut records = vec![
+            to_record(&self.file_description)?,
+            to_record(&self.file_name)?,
+            to_record(&self.file_schema)?,
+        ];
+        if let Some(schema_population) = &self.schema_population {
+            records.push(to_record(schema_population)?);
+        }
+        for file_population in &self.file_population {
+            records.push(to_record(file_population)?);
+        }
+        for section_language in &self.section_language {
+            records.push(to_record(section_language)?);
+        }
+        for section_context in &self.section_context {
+            records.push(to_record(section_context)?);
+        }
+        records.extend(self.user_defined.iter().cloned());
+        Ok(records)
+    }
+}
+
+fn next_record<'a>(iter: &mut impl Iterator<Item = &'a Record>, name: &str) -> Result<&'a Record> {
+    match iter.next() {
+        Some(record) if record.name == name => Ok(record),
+        Some(record) => Err(Error::InvalidHeader(format!(
+            "{} is expected, but {} found",
+            name, record.name
+        ))),
+        None => Err(Error::InvalidHeader(format!("{} is missing", name))),
+    }
+}
+
+/// Builder of [Header] for writing exchange structures
+///
+/// Values not set are filled as follows:
+///
+/// - `implementation_level` is `2;1`, i.e. ISO-10303-21 second edition without external mapping.
+/// - `time_stamp` is the current time in ISO-8601 format, e.g. `2021-01-01T00:00:00+00:00`.
+/// - `preprocessor_version` is the version of ruststep, e.g. `ruststep 0.3.0`.
+/// - Lists and strings are empty.
+///   Empty lists are written as a list of an empty string, e.g. `('')`, since they are `LIST [1:?]`.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HeaderBuilder {
+    description: Vec<String>,
+    implementation_level: Option<String>,
+    name: String,
+    time_stamp: Option<String>,
+    author: Vec<String>,
+    organization: Vec<String>,
+    preprocessor_version: Option<String>,
+    originating_system: String,
+    authorization: String,
+    schema: Vec<String>,
+    schema_population: Option<SchemaPopulation>,
+    file_population: Vec<FilePopulation>,
+    section_language: Vec<SectionLanguage>,
+    section_context: Vec<SectionContext>,
+}
+
+impl HeaderBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a line of `file_description.description`
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description.push(description.into());
+        self
+    }
+
+    pub fn implementation_level(mut self, implementation_level: impl Into<String>) -> Self {
+        self.implementation_level = Some(implementation_level.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Overwrite the time stamp, which must be formatted in ISO-8601
+    pub fn time_stamp(mut self, time_stamp: impl Into<String>) -> Self {
+        self.time_stamp = Some(time_stamp.into());
+        self
+    }
+
+    /// Append an author
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author.push(author.into());
+        self
+    }
+
+    /// Append an organization
+    pub fn organization(mut self, organization: impl Into<String>) -> Self {
+        self.organization.push(organization.into());
+        self
+    }
+
+    pub fn preprocessor_version(mut self, preprocessor_version: impl Into<String>) -> Self {
+        self.preprocessor_version = Some(preprocessor_version.into());
+        self
+    }
+
+    pub fn originating_system(mut self, originating_system: impl Into<String>) -> Self {
+        self.originating_system = originating_system.into();
+        self
+    }
+
+    pub fn authorization(mut self, authorization: impl Into<String>) -> Self {
+        self.authorization = authorization.into();
+        self
+    }
+
+    /// Append a schema to `file_schema`
+    pub fn schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema.push(schema.into());
+        self
+    }
+
+    pub fn schema_population(mut self, schema_population: SchemaPopulation) -> Self {
+        self.schema_population = Some(schema_population);
+        self
+    }
+
+    pub fn file_population(mut self, file_population: FilePopulation) -> Self {
+        self.file_population.push(file_population);
+        self
+    }
+
+    pub fn section_language(mut self, section_language: SectionLanguage) -> Self {
+        self.section_language.push(section_language);
+        self
+    }
+
+    pub fn section_context(mut self, section_context: SectionContext) -> Self {
+        self.section_context.push(section_context);
+        self
+    }
+
+    /// Returns [Error::InvalidHeader] if no schema is specified
+    pub fn build(self) -> Result<Header> {
+        if self.schema.is_empty() {
+            return Err(Error::InvalidHeader(
+                "FILE_SCHEMA requires at least one schema".to_string(),
+            ));
+        }
+        Ok(Header {
+            file_description: FileDescription {
+                description: non_empty(self.description),
+                implementation_level: self
+                    .implementation_level
+                    .unwrap_or_else(|| "2;1".to_string()),
+            },
+            file_name: FileName {
+                name: self.name,
+                time_stamp: self
+                    .time_stamp
+                    .unwrap_or_else(|| format_time_stamp(SystemTime::now())),
+                author: non_empty(self.author),
+                organization: non_empty(self.organization),
+                preprocessor_version: self
+                    .preprocessor_version
+                    .unwrap_or_else(|| PREPROCESSOR_VERSION.to_string()),
+                originating_system: self.originating_system,
+                authorization: self.authorization,
+            },
+            file_schema: FileSchema {
+                schema: self.schema,
+            },
+            schema_population: self.schema_population,
+            file_population: self.file_population,
+            section_language: self.section_language,
+            section_context: self.section_context,
+            user_defined: Vec::new(),
         })
     }
 }
 
+/// Default `preprocessor_version`
+const PREPROCESSOR_VERSION: &str = concat!("ruststep ", env!("CARGO_PKG_VERSION"));
+
+fn non_empty(list: Vec<String>) -> Vec<String> {
+    if list.is_empty() {
+        vec![String::new()]
+    } else {
+        list
+    }
+}
+
+/// Format time in ISO-8601 extended format in UTC, e.g. `2021-01-01T00:00:00+00:00`
+fn format_time_stamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (days, secs) = ((secs / 86400) as i64, secs % 86400);
+
+    // Convert days from 1970-01-01 into proleptic Gregorian calendar date,
+    // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}+00:00",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use nom::Finish;
+    use std::{str::FromStr, time::Duration};
 
     #[test]
     fn header() {
@@ -135,4 +513,97 @@ mod tests {
         let header = super::Header::from_records(&records).unwrap();
         dbg!(header);
     }
+
+    #[test]
+    fn optional_entities() {
+        let header = r#"
+        HEADER;
+            FILE_DESCRIPTION(('example'), '2;1');
+            FILE_NAME('example.step', '2021-01-01T00:00:00', ('A'), ('B'), 'ruststep', '', '');
+            FILE_SCHEMA(('CONFIG_CONTROL_DESIGN'));
+            FILE_POPULATION('CONFIG_CONTROL_DESIGN', 'SECTION_BOUNDARY', $);
+            SECTION_LANGUAGE($, 'CONFIG_CONTROL_DESIGN');
+            SECTION_CONTEXT('GEOMETRY', ('ISO 10303-42'));
+            MY_HEADER(1.0);
+        ENDSEC;
+        "#
+        .trim();
+        let (_residual, records) = crate::parser::exchange::header_section(header)
+            .finish()
+            .unwrap();
+        let header = Header::from_records(&records).unwrap();
+        assert_eq!(header.schema_population, None);
+        assert_eq!(
+            header.file_population,
+            [FilePopulation {
+                governing_schema: "CONFIG_CONTROL_DESIGN".to_string(),
+                determination_method: "SECTION_BOUNDARY".to_string(),
+                governed_sections: None,
+            }]
+        );
+        assert_eq!(header.section_language[0].section, None);
+        assert_eq!(
+            header.section_context[0].section.as_deref(),
+            Some("GEOMETRY")
+        );
+        assert_eq!(header.user_defined[0].name, "MY_HEADER");
+        assert_eq!(header.to_records().unwrap(), records);
+    }
+
+    #[test]
+    fn invalid_order() {
+        let records = [
+            Record::from_str("FILE_NAME('', '', (''), (''), '', '', '')").unwrap(),
+            Record::from_str("FILE_DESCRIPTION((''), '2;1')").unwrap(),
+            Record::from_str("FILE_SCHEMA(('EXAMPLE'))").unwrap(),
+        ];
+        assert!(matches!(
+            Header::from_records(&records),
+            Err(Error::InvalidHeader(_))
+        ));
+        // Missing FILE_SCHEMA
+        assert!(matches!(
+            Header::from_records(&[records[1].clone(), records[0].clone()]),
+            Err(Error::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            Header::from_records(&[]),
+            Err(Error::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    fn builder() {
+        assert!(matches!(
+            HeaderBuilder::new().build(),
+            Err(Error::InvalidHeader(_))
+        ));
+        let header = HeaderBuilder::new()
+            .time_stamp("2021-01-01T00:00:00")
+            .schema("EXAMPLE")
+            .build()
+            .unwrap();
+        assert_eq!(
+            to_record(&header.file_name).unwrap(),
+            Record::from_str(&format!(
+                "FILE_NAME('', '2021-01-01T00:00:00', (''), (''), 'ruststep {}', '', '')",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn time_stamp() {
+        let time = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+        assert_eq!(format_time_stamp(time(0)), "1970-01-01T00:00:00+00:00");
+        assert_eq!(
+            format_time_stamp(time(951782400)),
+            "2000-02-29T00:00:00+00:00"
+        );
+        assert_eq!(
+            format_time_stamp(time(1700000000)),
+            "2023-11-14T22:13:20+00:00"
+        );
+    }
 }
@@ -0,0 +1,507 @@
+//! Lazy tables for random access into large exchange structures
+//!
+//! [TableInit] deserializes all entity instances in DATA sections up front.
+//! [LazyTables] instead scans the input once to record the byte offset and keywords of each entity instance,
+//! and parses and deserializes entity instances only when they are requested.
+//! To get an owned entity, the requested instance and the instances referred from it transitively
+//! are appended to a table, i.e. the cost does not depend on the size of entire input.
+//! The table is kept for each type of tables, and filled lazily by later requests.
+//! Since the table is not bounded by default, [LazyTables::cache_capacity] limits
+//! the number of instances kept in it, e.g. to iterate over all instances of a large input.
+//!
+//! The input is any bytes implementing `AsRef<[u8]>`, e.g. `Vec<u8>` read by [LazyTables::open],
+//! or a memory-mapped file like `memmap2::Mmap` not to read entire file into memory.
+//!
+//! ```
+//! use ruststep::{ast::*, lazy::LazyTables};
+//! use std::str::FromStr;
+//!
+//! let step = r#"
+//! ISO-10303-21;
+//! HEADER;
+//!   FILE_DESCRIPTION((''), '2;1');
+//!   FILE_NAME('', '', (''), (''), '', '', '');
+//!   FILE_SCHEMA(('EXAMPLE'));
+//! ENDSEC;
+//! DATA;
+//!   #1 = CPT(0.0, 0.0);
+//!   #2 = CPT(1.0, 0.0);
+//!   #3 = LINE(#1, #2);
+//!   #4 = LABEL('unused');
+//! ENDSEC;
+//! END-ISO-10303-21;
+//! "#;
+//!
+//! // Only the ids and keywords are read here
+//! let tables = LazyTables::new(step.as_bytes()).unwrap();
+//! assert_eq!(tables.ids().collect::<Vec<_>>(), [1, 2, 3, 4]);
+//! assert_eq!(tables.keywords(3).unwrap(), ["LINE"]);
+//!
+//! // `#3` and its references are parsed
+//! let data = tables.data_section(&[3]).unwrap();
+//! assert_eq!(
+//!     data.entities,
+//!     [
+//!         EntityInstance::from_str("#1 = CPT(0.0, 0.0);").unwrap(),
+//!         EntityInstance::from_str("#2 = CPT(1.0, 0.0);").unwrap(),
+//!         EntityInstance::from_str("#3 = LINE(#1, #2);").unwrap(),
+//!     ]
+//! );
+//! ```
+//!
+//! Typed entities are taken by [LazyTables::get_owned] and [LazyTables::owned_iter]
+//! using the tables generated from EXPRESS schema, e.g. `LazyTables::get_owned::<LineHolder>(3)`.
+
+use crate::{
+    ast::*,
+    error::*,
+    header::Header,
+    parser::{
+        exchange::entity_instance,
+        stream::{
+            data_start, is_statement, parse_statement, scan_instance, Reader, StatementScanner,
+        },
+    },
+    tables::*,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt, fs, io,
+    path::Path,
+    sync::Mutex,
+};
+
+/// Location of an entity instance in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    offset: usize,
+    len: usize,
+    /// Keyword of simple instance, or keywords of partial records of complex instance
+    keywords: Vec<String>,
+}
+
+/// Tables parsing and deserializing entity instances on demand
+///
+/// See [module level document](self).
+/// This is [Sync] if the source is, and can be shared among threads.
+#[derive(Debug)]
+pub struct LazyTables<S = Vec<u8>> {
+    source: S,
+    header: Vec<Record>,
+    names: NameTable,
+    entries: BTreeMap<u64, Entry>,
+    capacity: Option<usize>,
+    loaded: Mutex<Loaded>,
+}
+
+impl LazyTables<Vec<u8>> {
+    /// Read entire file into memory, and scan it
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(fs::read(path)?)
+    }
+}
+
+impl<S: AsRef<[u8]>> LazyTables<S> {
+    /// Scan the entity ids and keywords in DATA sections
+    ///
+    /// HEADER, ANCHOR, and REFERENCE sections are parsed here,
+    /// and the names defined in ANCHOR and REFERENCE sections are resolved as [NameTable] does.
+    /// Returns [Error::DuplicatedEntity] if an id is defined twice.
+    pub fn new(source: S) -> Result<Self> {
+        let bytes = source.as_ref();
+        let reader = Reader::new(bytes)?;
+        let header = reader.header_records().to_vec();
+        let names = NameTable::new(reader.anchor(), reader.reference());
+        let entries = match reader.data_offset() {
+            Some(offset) => scan(bytes, offset)?,
+            None => BTreeMap::new(),
+        };
+        Ok(LazyTables {
+            source,
+            header,
+            names,
+            entries,
+            capacity: None,
+            loaded: Mutex::new(Loaded::default()),
+        })
+    }
+
+    /// Limit the number of entity instances kept in each table filled by [LazyTables::get_owned]
+    ///
+    /// Since a table cannot drop some of its holders, the table is dropped entirely
+    /// when a request would exceed the capacity, and refilled by the instances of the request.
+    /// A table may hold more instances than `capacity`
+    /// if a single entity refers more instances transitively.
+    /// A small capacity saves memory at the cost of parsing the shared instances again.
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Drop the tables filled by [LazyTables::get_owned] to release memory
+    pub fn clear_tables(&self) {
+        self.lock().clear();
+    }
+
+    /// Number of entity instances kept in the tables filled by [LazyTables::get_owned]
+    pub fn loaded_len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Records in HEADER section
+    pub fn header_records(&self) -> &[Record] {
+        &self.header
+    }
+
+    /// HEADER section
+    pub fn header(&self) -> Result<Header> {
+        Header::from_records(&self.header)
+    }
+
+    /// Number of entity instances
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entity ids in ascending order
+    pub fn ids(&self) -> impl Iterator<Item = u64> + '_ {
+        self.entries.keys().copied()
+    }
+
+    pub fn contains(&self, id: u64) -> bool {
+        self.entries.contains_key(&id)
+    }
+
+    /// Keyword of simple entity instance, or keywords of partial records of complex entity instance
+    pub fn keywords(&self, id: u64) -> Option<&[String]> {
+        self.entries.get(&id).map(|entry| entry.keywords.as_slice())
+    }
+
+    /// Parse an entity instance
+    ///
+    /// Returns [Error::UnknownEntity] if `id` is not defined.
+    pub fn instance(&self, id: u64) -> Result<EntityInstance> {
+        let entry = self.entries.get(&id).ok_or(Error::UnknownEntity(id))?;
+        let bytes = self.source.as_ref();
+        let statement = statement(bytes, entry.offset, entry.offset + entry.len)?;
+        let mut instance = parse_statement(statement, entity_instance)
+            .map_err(|e| locate(bytes, entry.offset, e))?;
+        if !self.names.is_empty() {
+            for record in instance.records_mut() {
+                self.names.resolve_parameter(&mut record.parameter)?;
+            }
+        }
+        Ok(instance)
+    }
+
+    /// Parse `roots` and the entity instances referred from them transitively, in ascending order of ids
+    ///
+    /// References to undefined ids are kept as they are.
+    /// Returns [Error::UnknownEntity] if a root is not defined.
+    pub fn data_section(&self, roots: &[u64]) -> Result<DataSection> {
+        Ok(DataSection {
+            meta: Vec::new(),
+            entities: self.collect(roots, |_| false)?.into_values().collect(),
+        })
+    }
+
+    /// Parse `roots` and the entity instances referred from them transitively,
+    /// except for the `skip` ones and the instances referred only from them
+    fn collect(
+        &self,
+        roots: &[u64],
+        mut skip: impl FnMut(u64) -> bool,
+    ) -> Result<BTreeMap<u64, EntityInstance>> {
+        let mut instances = BTreeMap::new();
+        let mut stack = Vec::new();
+        for &id in roots {
+            if !self.contains(id) {
+                return Err(Error::UnknownEntity(id));
+            }
+            stack.push(id);
+        }
+        while let Some(id) = stack.pop() {
+            if instances.contains_key(&id) || !self.contains(id) || skip(id) {
+                continue;
+            }
+            let instance = self.instance(id)?;
+            stack.extend(instance.references());
+            instances.insert(id, instance);
+        }
+        Ok(instances)
+    }
+
+    /// Get owned entity, deserializing only the entity instances referred from it
+    ///
+    /// The deserialized holders are kept in a table of `H::Table`,
+    /// and the instances already in it are not parsed again.
+    /// The instances are parsed without locking the table,
+    /// which is locked only to look up the loaded ids and to append the parsed instances.
+    pub fn get_owned<H>(&self, id: u64) -> Result<H::Owned>
+    where
+        H: Holder,
+        H::Table: TableInit + EntityTable<H> + Send + 'static,
+    {
+        loop {
+            let mut skipped = Vec::new();
+            let instances = self.collect(&[id], |id| {
+                let loaded = self.lock().contains::<H::Table>(id);
+                if loaded {
+                    skipped.push(id);
+                }
+                loaded
+            })?;
+
+            let mut loaded = self.lock();
+            let (_table, ids) = loaded.get_mut::<H::Table>();
+            // The table may be dropped by another thread while parsing
+            let dropped = skipped.iter().any(|id| !ids.contains(id));
+            let overflow = matches!(
+                self.capacity,
+                Some(capacity) if ids.len() + instances.len() > capacity
+            );
+            if overflow && !ids.is_empty() {
+                loaded.remove::<H::Table>();
+                if !skipped.is_empty() {
+                    continue;
+                }
+            } else if dropped {
+                continue;
+            }
+
+            let (table, ids) = loaded.get_mut::<H::Table>();
+            for (id, instance) in &instances {
+                // Another thread may append it while parsing
+                if !ids.contains(id) {
+                    table.append_instance(instance)?;
+                    ids.insert(*id);
+                }
+            }
+            return EntityTable::<H>::get_owned(&*table, id);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Loaded> {
+        // Tables may be filled partially by the panicked thread
+        self.loaded.lock().unwrap_or_else(|poisoned| {
+            let mut loaded = poisoned.into_inner();
+            loaded.clear();
+            loaded
+        })
+    }
+
+    /// Get owned entities whose keyword is the name of `H`, in ascending order of ids
+    pub fn owned_iter<H>(&self) -> impl Iterator<Item = Result<H::Owned>> + '_
+    where
+        H: Holder,
+        H::Table: TableInit + EntityTable<H> + Send + 'static,
+    {
+        self.owned_iter_with_id::<H>()
+            .map(|owned| owned.map(|(_id, owned)| owned))
+    }
+
+    /// Get pairs of entity id and owned entity as [LazyTables::owned_iter]
+    pub fn owned_iter_with_id<H>(&self) -> impl Iterator<Item = Result<(u64, H::Owned)>> + '_
+    where
+        H: Holder,
+        H::Table: TableInit + EntityTable<H> + Send + 'static,
+    {
+        self.entries
+            .iter()
+            .filter(|(_id, entry)| {
+                entry
+                    .keywords
+                    .iter()
+                    .any(|keyword| keyword.eq_ignore_ascii_case(H::name()))
+            })
+            .map(move |(id, _entry)| Ok((*id, self.get_owned::<H>(*id)?)))
+    }
+}
+
+/// Scan statements in DATA sections starting at `offset` until `END-ISO-10303-21;`
+fn scan(bytes: &[u8], mut offset: usize) -> Result<BTreeMap<u64, Entry>> {
+    let mut entries = BTreeMap::new();
+    let mut in_data = true;
+    loop {
+        let end = match StatementScanner::default().find_end(&bytes[offset..]) {
+            Some(len) => offset + len,
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Exchange structure ends without `END-ISO-10303-21;`",
+                )
+                .into())
+            }
+        };
+        let statement = statement(bytes, offset, end)?;
+        if in_data {
+            if is_statement(statement, "ENDSEC;") {
+                in_data = false;
+            } else {
+                let (id, keywords) =
+                    scan_instance(statement).map_err(|e| locate(bytes, offset, e))?;
+                let entry = Entry {
+                    offset,
+                    len: end - offset,
+                    keywords,
+                };
+                if entries.insert(id, entry).is_some() {
+                    return Err(Error::DuplicatedEntity(id));
+                }
+            }
+        } else if is_statement(statement, "END-ISO-10303-21;") {
+            return Ok(entries);
+        } else {
+            parse_statement(statement, data_start).map_err(|e| locate(bytes, offset, e))?;
+            in_data = true;
+        }
+        offset = end;
+    }
+}
+
+fn statement(bytes: &[u8], start: usize, end: usize) -> Result<&str> {
+    std::str::from_utf8(&bytes[start..end])
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
+}
+
+/// Locate tokenize error in the statement starting at `offset`
+///
+/// Lines are counted on bytes, and only the last line is decoded to count characters.
+fn locate(bytes: &[u8], offset: usize, e: TokenizeFailed) -> Error {
+    let head = &bytes[..offset];
+    let line = head.iter().filter(|&&b| b == b'\n').count() + 1;
+    let line_start = head
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |pos| pos + 1);
+    let (_, column) = line_column(&String::from_utf8_lossy(&head[line_start..]));
+    Error::TokenizeFailed(e.shift(offset, line, column))
+}
+
+/// Tables filled by [LazyTables::get_owned] for each type of tables, with the ids appended to them
+#[derive(Default)]
+struct Loaded(HashMap<TypeId, (Box<dyn Any + Send>, HashSet<u64>)>);
+
+impl fmt::Debug for Loaded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(ty, (_table, ids))| (ty, ids.len())))
+            .finish()
+    }
+}
+
+impl Loaded {
+    fn get_mut<T: TableInit + Send + 'static>(&mut self) -> (&mut T, &mut HashSet<u64>) {
+        let (table, ids) = self
+            .0
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| (Box::new(T::default()), HashSet::new()));
+        let table = table
+            .downcast_mut::<T>()
+            .expect("Table is registered by its TypeId");
+        (table, ids)
+    }
+
+    fn contains<T: 'static>(&self, id: u64) -> bool {
+        self.0
+            .get(&TypeId::of::<T>())
+            .map_or(false, |(_table, ids)| ids.contains(&id))
+    }
+
+    fn remove<T: 'static>(&mut self) {
+        self.0.remove(&TypeId::of::<T>());
+    }
+
+    fn len(&self) -> usize {
+        self.0.values().map(|(_table, ids)| ids.len()).sum()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION((''), '2;1');
+  FILE_NAME('', '', (''), (''), '', '', '');
+  FILE_SCHEMA(('EXAMPLE'));
+ENDSEC;
+ANCHOR;
+  <ORIGIN> = #1;
+ENDSEC;
+DATA;
+  /* #0 = COMMENT(); */
+  #1 = CPT(0.0, 0.0);
+  #2 = (A('a;b') B(#ORIGIN));
+ENDSEC;
+DATA;
+  #11 = VX(#2, #100);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+    #[test]
+    fn scan() {
+        let tables = LazyTables::new(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(tables.header().unwrap().file_schema.schema, ["EXAMPLE"]);
+        assert_eq!(tables.ids().collect::<Vec<_>>(), [1, 2, 11]);
+        assert_eq!(tables.keywords(1).unwrap(), ["CPT"]);
+        assert_eq!(tables.keywords(2).unwrap(), ["A", "B"]);
+        assert!(tables.keywords(3).is_none());
+        assert!(matches!(tables.instance(3), Err(Error::UnknownEntity(3))));
+
+        // `#100` is not defined, and constant name is resolved
+        let data = tables.data_section(&[11]).unwrap();
+        assert_eq!(
+            data.entities,
+            [
+                EntityInstance::from_str("#1 = CPT(0.0, 0.0);").unwrap(),
+                EntityInstance::from_str("#2 = (A('a;b') B(#1));").unwrap(),
+                EntityInstance::from_str("#11 = VX(#2, #100);").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicated() {
+        let input = EXAMPLE.replace("#11 =", "#1 =");
+        assert!(matches!(
+            LazyTables::new(input.as_bytes()),
+            Err(Error::DuplicatedEntity(1))
+        ));
+    }
+
+    #[test]
+    fn error_location() {
+        // Broken parameters are found when the instance is parsed
+        let input = EXAMPLE.replace("#1 = CPT(0.0, 0.0);", "#1 = CPT(0.0, 0.0 x);");
+        let tables = LazyTables::new(input.as_bytes()).unwrap();
+        match tables.instance(1) {
+            Err(Error::TokenizeFailed(e)) => {
+                let d = e.diagnostic();
+                assert_eq!((d.line, d.column, d.entity_id), (13, 21, Some(1)));
+                assert_eq!(&input[d.offset..d.offset + 3], "x);");
+            }
+            _ => panic!("tokenize must fail"),
+        }
+
+        // Broken statement head is found by scan
+        let input = EXAMPLE.replace("#11 = VX", "#11 VX");
+        assert!(matches!(
+            LazyTables::new(input.as_bytes()),
+            Err(Error::TokenizeFailed(_))
+        ));
+    }
+}
@@ -109,11 +109,18 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 
 pub mod ast;
+pub mod diff;
 pub mod error;
+pub mod graph;
 pub mod header;
+pub mod lazy;
+pub mod link;
 pub mod parser;
 pub mod primitive;
+pub mod registry;
 pub mod tables;
+pub mod transform;
+pub mod writer;
 
 // To work generated code by ruststep-derive only with ruststep
 pub use derive_more;
@@ -0,0 +1,537 @@
+//! Resolve external references in REFERENCE section across files
+//!
+//! ISO-10303-21 edition 3 allows an exchange structure to refer the instances in another file
+//! through REFERENCE section, and the referred file exports them by ANCHOR section:
+//!
+//! ```text
+//! /* assembly.stp */
+//! REFERENCE;
+//!   #10 = <part.stp#body>;
+//! ENDSEC;
+//!
+//! /* part.stp */
+//! ANCHOR;
+//!   <body> = #1;
+//! ENDSEC;
+//! ```
+//!
+//! [link] loads the referred files through a [ReferenceResolver],
+//! and combines them into a single [Exchange] as if they were one population.
+//! The result can be loaded by [TableInit::from_exchange](crate::tables::TableInit::from_exchange)
+//! and indexed by [Graph::from_sections](crate::graph::Graph::from_sections).
+//!
+//! ```
+//! use ruststep::{ast::*, error::*, graph::Graph, link::*};
+//! use std::{collections::HashMap, str::FromStr};
+//!
+//! // Resolver reading files from memory instead of local filesystem
+//! struct Memory(HashMap<&'static str, &'static str>);
+//!
+//! impl ReferenceResolver for Memory {
+//!     fn locate(&self, _base: &str, uri: &str) -> Result<String> {
+//!         Ok(uri.to_string())
+//!     }
+//!     fn load(&mut self, location: &str) -> Result<Exchange> {
+//!         match self.0.get(location) {
+//!             Some(content) => Exchange::from_str(content),
+//!             None => Err(Error::UnresolvedName(location.to_string())),
+//!         }
+//!     }
+//! }
+//!
+//! let assembly = Exchange::from_str(r#"
+//! ISO-10303-21;
+//! HEADER;
+//!   FILE_DESCRIPTION((''), '2;1');
+//!   FILE_NAME('assembly.stp', '', (''), (''), '', '', '');
+//!   FILE_SCHEMA(('EXAMPLE'));
+//! ENDSEC;
+//! REFERENCE;
+//!   #10 = <part.stp#body>;
+//! ENDSEC;
+//! DATA;
+//!   #1 = ASSEMBLY((#10, #10));
+//! ENDSEC;
+//! END-ISO-10303-21;
+//! "#).unwrap();
+//!
+//! let mut resolver = Memory(HashMap::new());
+//! resolver.0.insert("part.stp", r#"
+//! ISO-10303-21;
+//! HEADER;
+//!   FILE_DESCRIPTION((''), '2;1');
+//!   FILE_NAME('part.stp', '', (''), (''), '', '', '');
+//!   FILE_SCHEMA(('EXAMPLE'));
+//! ENDSEC;
+//! ANCHOR;
+//!   <body> = #2;
+//! ENDSEC;
+//! DATA;
+//!   #1 = CPT(0.0, 0.0);
+//!   #2 = BODY(#1);
+//! ENDSEC;
+//! END-ISO-10303-21;
+//! "#);
+//!
+//! let linked = link(&assembly, "assembly.stp", &mut resolver).unwrap();
+//! // Instances of `part.stp` are numbered after `#10` in `assembly.stp`
+//! assert_eq!(
+//!     linked.data[0].entities[0],
+//!     EntityInstance::from_str("#1 = ASSEMBLY((#12, #12));").unwrap()
+//! );
+//! assert_eq!(
+//!     linked.data[1].entities[1],
+//!     EntityInstance::from_str("#12 = BODY(#11);").unwrap()
+//! );
+//! let graph = Graph::from_sections(&linked.data).unwrap();
+//! assert!(graph.dangling().is_empty());
+//! ```
+
+use crate::{ast::*, error::*, tables::NameTable};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Locate and load the files referred in REFERENCE section
+pub trait ReferenceResolver {
+    /// Identify the file of `uri` referred from the file at `base`
+    ///
+    /// `uri` is the resource without fragment, e.g. `part.stp` for `<part.stp#body>`.
+    /// The returned location is used as the key to load each file only once,
+    /// and as `base` of the references in the file.
+    fn locate(&self, base: &str, uri: &str) -> Result<String>;
+
+    /// Load the exchange structure at the location returned by [ReferenceResolver::locate]
+    fn load(&mut self, location: &str) -> Result<Exchange>;
+}
+
+/// Resolve references as paths in local filesystem
+///
+/// Relative paths are resolved from the directory of the referring file,
+/// and `file://` scheme is accepted.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FileSystem;
+
+impl ReferenceResolver for FileSystem {
+    fn locate(&self, base: &str, uri: &str) -> Result<String> {
+        let path = Path::new(uri.strip_prefix("file://").unwrap_or(uri));
+        let path = match Path::new(base).parent() {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path.to_path_buf(),
+        };
+        let path = path.canonicalize().unwrap_or(path);
+        Ok(path.to_string_lossy().into_owned())
+    }
+
+    fn load(&mut self, location: &str) -> Result<Exchange> {
+        Exchange::from_str(&fs::read_to_string(location)?)
+    }
+}
+
+/// Load an exchange structure from local filesystem, and [link] it with the files it refers
+pub fn link_file(path: impl AsRef<Path>) -> Result<Exchange> {
+    let path: PathBuf = path.as_ref().canonicalize()?;
+    let exchange = FileSystem.load(&path.to_string_lossy())?;
+    link(&exchange, &path.to_string_lossy(), &mut FileSystem)
+}
+
+/// Combine `exchange` at `location` and the files referred from it into a single exchange structure
+///
+/// - DATA sections of the referred files follow the DATA sections of `exchange`.
+///   Their entity ids are shifted by the largest id of the preceding files,
+///   i.e. `exchange` keeps its ids.
+/// - Names defined in REFERENCE sections are replaced by the items of the referred anchors.
+///   Anchors in the same file like `<#body>`, and constant names like `#ORIGIN`, are also resolved.
+///   Files referred transitively are loaded too, and each file is loaded only once.
+/// - Value instance names not defined in REFERENCE sections, e.g. `@3`,
+///   are shifted by the largest value instance name of the preceding files as entity ids,
+///   so that they do not collide across files.
+/// - HEADER and ANCHOR sections of `exchange` are kept, and REFERENCE and SIGNATURE sections are removed.
+///
+/// Returns [Error::UnresolvedName] if the referred anchor does not exist, or anchors refer each other cyclically.
+pub fn link(
+    exchange: &Exchange,
+    location: &str,
+    resolver: &mut impl ReferenceResolver,
+) -> Result<Exchange> {
+    let mut population = Population::default();
+    population.push(location.to_string(), exchange.clone());
+
+    // Load all files referred transitively
+    let mut i = 0;
+    while i < population.files.len() {
+        let mut targets = HashMap::new();
+        let uris: Vec<String> = population.files[i]
+            .exchange
+            .reference
+            .iter()
+            .map(|entry| split_fragment(&entry.resource.0).0.to_string())
+            .collect();
+        for uri in &uris {
+            let uri = uri.as_str();
+            if uri.is_empty() || targets.contains_key(uri) {
+                continue;
+            }
+            let location = resolver.locate(&population.files[i].location, uri)?;
+            let target = match population.index.get(&location) {
+                Some(&target) => target,
+                None => {
+                    let exchange = resolver.load(&location)?;
+                    population.push(location, exchange)
+                }
+            };
+            targets.insert(uri.to_string(), target);
+        }
+        population.files[i].targets = targets;
+        i += 1;
+    }
+
+    let mut data = Vec::new();
+    for (i, file) in population.files.iter().enumerate() {
+        for section in &file.exchange.data {
+            let mut section = section.clone();
+            for instance in &mut section.entities {
+                // Names are resolved in the scope of the file, and then shifted
+                for record in instance.records_mut() {
+                    population.resolve_parameter(i, &mut record.parameter, &mut HashSet::new())?;
+                }
+                match instance {
+                    EntityInstance::Simple { id, .. } | EntityInstance::Complex { id, .. } => {
+                        *id += file.offset
+                    }
+                }
+            }
+            data.push(section);
+        }
+    }
+    let mut anchor = exchange.anchor.clone();
+    for anchor in &mut anchor {
+        population.resolve_anchor_item(0, &mut anchor.item)?;
+        for (_, item) in &mut anchor.tags {
+            population.resolve_anchor_item(0, item)?;
+        }
+    }
+    Ok(Exchange {
+        header: exchange.header.clone(),
+        anchor,
+        reference: Vec::new(),
+        data,
+        signature: Vec::new(),
+    })
+}
+
+/// `part.stp` and `body` for `part.stp#body`
+fn split_fragment(uri: &str) -> (&str, Option<&str>) {
+    match uri.find('#') {
+        Some(pos) => (&uri[..pos], Some(&uri[pos + 1..])),
+        None => (uri, None),
+    }
+}
+
+#[derive(Debug)]
+struct File {
+    location: String,
+    exchange: Exchange,
+    /// ANCHOR and REFERENCE sections of this file
+    names: NameTable,
+    /// Entity ids in this file are shifted by this
+    offset: u64,
+    /// Value instance names in this file not defined in REFERENCE section are shifted by this
+    value_offset: u64,
+    /// Index of the files referred by URI without fragment
+    targets: HashMap<String, usize>,
+}
+
+#[derive(Debug, Default)]
+struct Population {
+    files: Vec<File>,
+    index: HashMap<String, usize>,
+    next_offset: u64,
+    next_value_offset: u64,
+}
+
+/// Largest value instance name in a parameter, e.g. `3` for `(@1, @3)`
+fn largest_value(parameter: &Parameter) -> u64 {
+    match parameter {
+        Parameter::Typed { parameter, .. } => largest_value(parameter),
+        Parameter::List(list) => list.iter().map(largest_value).max().unwrap_or(0),
+        Parameter::Ref(Name::Value(id)) => *id,
+        _ => 0,
+    }
+}
+
+impl Population {
+    fn push(&mut self, location: String, exchange: Exchange) -> usize {
+        let largest = exchange
+            .data
+            .iter()
+            .flat_map(|section| section.entities.iter())
+            .flat_map(|instance| {
+                let id = instance.id();
+                instance.references().into_iter().chain(Some(id))
+            })
+            .chain(
+                exchange
+                    .reference
+                    .iter()
+                    .filter_map(|entry| match entry.name {
+                        Name::Entity(id) => Some(id),
+                        _ => None,
+                    }),
+            )
+            .max()
+            .unwrap_or(0);
+        let largest_value = exchange
+            .data
+            .iter()
+            .flat_map(|section| section.entities.iter())
+            .flat_map(|instance| instance.records())
+            .map(|record| largest_value(&record.parameter))
+            .chain(
+                exchange
+                    .reference
+                    .iter()
+                    .filter_map(|entry| match entry.name {
+                        Name::Value(id) => Some(id),
+                        _ => None,
+                    }),
+            )
+            .max()
+            .unwrap_or(0);
+        let index = self.files.len();
+        self.index.insert(location.clone(), index);
+        self.files.push(File {
+            location,
+            names: NameTable::from_exchange(&exchange),
+            exchange,
+            offset: self.next_offset,
+            value_offset: self.next_value_offset,
+            targets: HashMap::new(),
+        });
+        self.next_offset += largest;
+        self.next_value_offset += largest_value;
+        index
+    }
+
+    /// Replace names in the `file` by the referred items
+    fn resolve_parameter(
+        &self,
+        file: usize,
+        parameter: &mut Parameter,
+        visited: &mut HashSet<(usize, String)>,
+    ) -> Result<()> {
+        match parameter {
+            Parameter::Typed { parameter, .. } => self.resolve_parameter(file, parameter, visited),
+            Parameter::List(list) => list
+                .iter_mut()
+                .try_for_each(|p| self.resolve_parameter(file, p, visited)),
+            Parameter::Ref(name) => {
+                *parameter = self.resolve_name(file, name, visited)?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn resolve_name(
+        &self,
+        file: usize,
+        name: &Name,
+        visited: &mut HashSet<(usize, String)>,
+    ) -> Result<Parameter> {
+        let (target, anchor) = match name {
+            Name::Entity(_) | Name::Value(_) => match self.files[file].names.reference(name) {
+                Some(URI(uri)) => {
+                    let (uri, fragment) = split_fragment(uri);
+                    let target = if uri.is_empty() {
+                        file
+                    } else {
+                        self.files[file].targets[uri]
+                    };
+                    match fragment {
+                        Some(fragment) => (target, fragment),
+                        None => return Err(Error::UnresolvedName(name.to_string())),
+                    }
+                }
+                None => {
+                    return Ok(match name {
+                        Name::Entity(id) => {
+                            Parameter::Ref(Name::Entity(id + self.files[file].offset))
+                        }
+                        Name::Value(id) => {
+                            Parameter::Ref(Name::Value(id + self.files[file].value_offset))
+                        }
+                        _ => Parameter::Ref(name.clone()),
+                    })
+                }
+            },
+            Name::ConstantEntity(constant) | Name::ConstantValue(constant) => {
+                (file, constant.as_str())
+            }
+        };
+        let item = match self.files[target].names.anchor_item(anchor) {
+            Some(item) => item,
+            // Constants may be defined in the schema instead of anchors
+            None if matches!(name, Name::ConstantEntity(_) | Name::ConstantValue(_)) => {
+                return Ok(Parameter::Ref(name.clone()))
+            }
+            None => return Err(Error::UnresolvedName(name.to_string())),
+        };
+        if !visited.insert((target, anchor.to_string())) {
+            return Err(Error::UnresolvedName(name.to_string()));
+        }
+        let parameter = item.to_parameter(&mut |name| self.resolve_name(target, name, visited));
+        visited.remove(&(target, anchor.to_string()));
+        parameter
+    }
+
+    /// Rewrite entity instance names in an anchor item of the `file`
+    fn resolve_anchor_item(&self, file: usize, item: &mut AnchorItem) -> Result<()> {
+        match item {
+            AnchorItem::Name(name) => {
+                if let Parameter::Ref(resolved) =
+                    self.resolve_name(file, name, &mut HashSet::new())?
+                {
+                    *name = resolved;
+                }
+                Ok(())
+            }
+            AnchorItem::List(list) => list
+                .iter_mut()
+                .try_for_each(|item| self.resolve_anchor_item(file, item)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Memory(HashMap<&'static str, String>);
+
+    impl ReferenceResolver for Memory {
+        fn locate(&self, _base: &str, uri: &str) -> Result<String> {
+            Ok(uri.to_string())
+        }
+        fn load(&mut self, location: &str) -> Result<Exchange> {
+            Exchange::from_str(&self.0[location])
+        }
+    }
+
+    fn step(anchor: &str, reference: &str, data: &str) -> String {
+        format!(
+            r#"
+            ISO-10303-21;
+            HEADER;
+              FILE_DESCRIPTION((''), '2;1');
+              FILE_NAME('', '', (''), (''), '', '', '');
+              FILE_SCHEMA(('EXAMPLE'));
+            ENDSEC;
+            ANCHOR;{}ENDSEC;
+            REFERENCE;{}ENDSEC;
+            DATA;{}ENDSEC;
+            END-ISO-10303-21;
+            "#,
+            anchor, reference, data
+        )
+    }
+
+    fn instances(exchange: &Exchange) -> Vec<EntityInstance> {
+        exchange
+            .data
+            .iter()
+            .flat_map(|section| section.entities.iter().cloned())
+            .collect()
+    }
+
+    #[test]
+    fn transitive() {
+        let root = Exchange::from_str(&step(
+            "<root> = #1;",
+            "#2 = <a.stp#x>; @1 = <a.stp#scale>;",
+            "#1 = ROOT(#2, @1);",
+        ))
+        .unwrap();
+        let mut resolver = Memory(HashMap::new());
+        // `a.stp` exports an instance of `b.stp`, and `b.stp` refers `a.stp` back
+        resolver.0.insert(
+            "a.stp",
+            step(
+                "<x> = #3; <scale> = 2.5; <local> = #1;",
+                "#3 = <b.stp#y>;",
+                "#1 = A(#3);",
+            ),
+        );
+        resolver.0.insert(
+            "b.stp",
+            step("<y> = #1;", "#2 = <a.stp#local>;", "#1 = B(#2);"),
+        );
+
+        let linked = link(&root, "root.stp", &mut resolver).unwrap();
+        assert_eq!(
+            instances(&linked),
+            [
+                EntityInstance::from_str("#1 = ROOT(#6, 2.5);").unwrap(),
+                EntityInstance::from_str("#3 = A(#6);").unwrap(),
+                EntityInstance::from_str("#6 = B(#3);").unwrap(),
+            ]
+        );
+        assert_eq!(linked.anchor, root.anchor);
+        assert!(linked.reference.is_empty());
+    }
+
+    #[test]
+    fn value_names() {
+        let root = Exchange::from_str(&step(
+            "",
+            "#2 = <a.stp#x>; @1 = <a.stp#scale>;",
+            "#1 = ROOT(#2, @1, @2);",
+        ))
+        .unwrap();
+        let mut resolver = Memory(HashMap::new());
+        resolver.0.insert(
+            "a.stp",
+            step("<x> = #1; <scale> = 2.5;", "", "#1 = A(@1, @2);"),
+        );
+        let linked = link(&root, "root.stp", &mut resolver).unwrap();
+        // Value names not in REFERENCE section are shifted by `@2` of the root file
+        assert_eq!(
+            instances(&linked),
+            [
+                EntityInstance::from_str("#1 = ROOT(#3, 2.5, @2);").unwrap(),
+                EntityInstance::from_str("#3 = A(@3, @4);").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_anchor() {
+        let root = Exchange::from_str(&step("", "#2 = <a.stp#x>;", "#1 = ROOT(#2);")).unwrap();
+        let mut resolver = Memory(HashMap::new());
+        resolver
+            .0
+            .insert("a.stp", step("<y> = #1;", "", "#1 = A(1.0);"));
+        assert!(matches!(
+            link(&root, "root.stp", &mut resolver),
+            Err(Error::UnresolvedName(name)) if name == "#2"
+        ));
+    }
+
+    #[test]
+    fn cyclic_anchor() {
+        let root = Exchange::from_str(&step(
+            "<x> = #3; <y> = #2;",
+            "#2 = <#x>; #3 = <#y>;",
+            "#1 = ROOT(#2);",
+        ))
+        .unwrap();
+        assert!(matches!(
+            link(&root, "root.stp", &mut Memory(HashMap::new())),
+            Err(Error::UnresolvedName(_))
+        ));
+    }
+}
@@ -94,3 +94,8 @@ pub fn reverse_solidus(input: &str) -> ParseResult<char> {
 pub fn apostrophe(input: &str) -> ParseResult<char> {
     char('\'')(input)
 }
+
+/// hex = `0` | `1` | `2` | `3` | `4` | `5` | `6` | `7` | `8` | `9` | `A` | `B` | `C` | `D` | `E` | `F` .
+pub fn hex(input: &str) -> ParseResult<char> {
+    satisfy(|c| matches!(c, '0'..='9' | 'A'..='F')).parse(input)
+}
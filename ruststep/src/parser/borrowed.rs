@@ -0,0 +1,233 @@
+//! Tokenize exchange structure into [ast::borrowed](crate::ast::borrowed) without copying keywords and strings
+//!
+//! Each parser corresponds to the parser of same name in [exchange](super::exchange) submodules
+//! or [token](super::token).
+
+use crate::{
+    ast::borrowed::*,
+    parser::{basic::upper, combinator::*, token},
+};
+use nom::{
+    branch::alt,
+    bytes::complete::take_while,
+    character::complete::char,
+    combinator::{recognize, value},
+    sequence::tuple,
+    Parser,
+};
+/// keyword = [user_defined_keyword](token::user_defined_keyword) | [standard_keyword] .
+pub fn keyword(input: &str) -> ParseResult<&str> {
+    // `!` is stripped without `alt`, since its failed branch allocates an error
+    let input = input.strip_prefix('!').unwrap_or(input);
+    standard_keyword(input)
+}
+
+/// standard_keyword = [upper] { [upper] | [digit](super::basic::digit) } .
+///
+/// Unlike [token::standard_keyword], this does not collect the characters into a new [String].
+pub fn standard_keyword(input: &str) -> ParseResult<&str> {
+    recognize(tuple((
+        upper,
+        take_while(|c: char| matches!(c, 'A'..='Z' | '_' | '0'..='9')),
+    )))
+    .parse(input)
+}
+
+/// string = `'` { ... } `'` .
+///
+/// See [token::string] for detail.
+///
+/// The content is borrowed from the input if it does not contain any control directives.
+pub fn string(input: &str) -> ParseResult<StringLiteral> {
+    let (residual, raw) = token::string_raw(input)?;
+    match StringLiteral::from_raw(raw) {
+        Ok(s) => Ok((residual, s)),
+        Err(_) => Err(token::invalid_string(input)),
+    }
+}
+
+/// enumeration = `.` [upper](super::basic::upper) { [upper](super::basic::upper) | [digit](super::basic::digit) } `.` .
+pub fn enumeration(input: &str) -> ParseResult<&str> {
+    tuple((char('.'), standard_keyword, char('.')))
+        .map(|(_head, name, _tail)| name)
+        .parse(input)
+}
+
+/// list = `(` \[ [parameter] { `,` [parameter] } \] `)` .
+pub fn list(input: &str) -> ParseResult<Parameter> {
+    tuple_((char_('('), comma_separated(parameter), char_(')')))
+        .map(|(_open, params, _close)| Parameter::List(params))
+        .parse(input)
+}
+
+/// parameter = [typed_parameter] | [untyped_parameter] | [omitted_parameter] .
+pub fn parameter(input: &str) -> ParseResult<Parameter> {
+    alt((typed_parameter, untyped_parameter, omitted_parameter)).parse(input)
+}
+
+/// typed_parameter = [keyword] `(` [parameter] `)` .
+pub fn typed_parameter(input: &str) -> ParseResult<Parameter> {
+    tuple_((keyword, char_('('), parameter, char_(')')))
+        .map(|(name, _open, ty, _close)| Parameter::Typed {
+            keyword: name,
+            parameter: Box::new(ty),
+        })
+        .parse(input)
+}
+
+/// untyped_parameter = `$` | [integer](token::integer) | [real](token::real) | [string] | [rhs_occurrence_name](token::rhs_occurrence_name) | [enumeration] | [binary](token::binary) | [list] .
+pub fn untyped_parameter(input: &str) -> ParseResult<Parameter> {
+    alt((
+        char_('$').map(|_| Parameter::NotProvided),
+        token::real.map(Parameter::Real),
+        token::integer.map(Parameter::Integer),
+        string.map(Parameter::String),
+        token::rhs_occurrence_name.map(Parameter::Ref),
+        enumeration.map(Parameter::Enumeration),
+        token::binary.map(Parameter::Binary),
+        list,
+    ))
+    .parse(input)
+}
+
+/// omitted_parameter = `*` .
+pub fn omitted_parameter(input: &str) -> ParseResult<Parameter> {
+    value(Parameter::Omitted, char_('*')).parse(input)
+}
+
+/// parameter_list = [parameter] { `,` [parameter] } .
+pub fn parameter_list(input: &str) -> ParseResult<Vec<Parameter>> {
+    comma_separated(parameter).parse(input)
+}
+
+/// data_section = `DATA` \[ `(` [parameter_list] `)` \] `;` [entity_instance_list] `ENDSEC;` .
+pub fn data_section(input: &str) -> ParseResult<DataSection> {
+    tuple_((
+        tag_("DATA"),
+        opt_(tuple_((char_('('), parameter_list, char_(')')))),
+        char_(';'),
+        entity_instance_list,
+        // Report the statement which is neither an entity instance nor `ENDSEC;`
+        cut_(tag_("ENDSEC;")),
+    ))
+    .map(|(_start, meta, _semicolon, entities, _end)| {
+        let meta = meta
+            .map(|(_open, params, _close)| params)
+            .unwrap_or_default();
+        DataSection::new(meta, entities)
+    })
+    .parse(input)
+}
+
+/// entity_instance_list = { [entity_instance] } .
+pub fn entity_instance_list(input: &str) -> ParseResult<Vec<EntityInstance>> {
+    many0_(entity_instance).parse(input)
+}
+
+/// entity_instance = [simple_entity_instance] | [complex_entity_instance] .
+///
+/// Once `#id =` is read, the error is not backtracked so that it points the broken instance.
+pub fn entity_instance(input: &str) -> ParseResult<EntityInstance> {
+    let (_rest, (_id, _eq, open)) =
+        tuple_((token::entity_instance_name, char_('='), opt_(char_('(')))).parse(input)?;
+    if open.is_some() {
+        instance_(cut_(complex_entity_instance)).parse(input)
+    } else {
+        instance_(cut_(simple_entity_instance)).parse(input)
+    }
+}
+
+/// simple_entity_instance = [entity_instance_name](token::entity_instance_name) `=` [simple_record] `;` .
+pub fn simple_entity_instance(input: &str) -> ParseResult<EntityInstance> {
+    tuple_((
+        token::entity_instance_name,
+        char_('='),
+        simple_record,
+        char_(';'),
+    ))
+    .map(|(id, _eq, record, _semicolon)| EntityInstance::Simple { id, record })
+    .parse(input)
+}
+
+/// complex_entity_instance = [entity_instance_name](token::entity_instance_name) `=` [subsuper_record] `;` .
+pub fn complex_entity_instance(input: &str) -> ParseResult<EntityInstance> {
+    tuple_((
+        token::entity_instance_name,
+        char_('='),
+        subsuper_record,
+        char_(';'),
+    ))
+    .map(|(id, _eq, subsuper, _semicolon)| EntityInstance::Complex { id, subsuper })
+    .parse(input)
+}
+
+/// simple_record = [keyword] `(` \[ [parameter_list] \] `)` .
+pub fn simple_record(input: &str) -> ParseResult<Record> {
+    tuple_((keyword, char_('('), opt_(parameter_list), char_(')')))
+        .map(|(name, _open, parameter, _close)| Record {
+            name,
+            parameter: parameter.unwrap_or_default().into_iter().collect(),
+        })
+        .parse(input)
+}
+
+/// simple_record_list = [simple_record] { [simple_record] } .
+pub fn simple_record_list(input: &str) -> ParseResult<Vec<Record>> {
+    many0_(simple_record).parse(input)
+}
+
+/// subsuper_record = `(` [simple_record_list] `)` .
+pub fn subsuper_record(input: &str) -> ParseResult<SubSuperRecord> {
+    tuple_((char_('('), simple_record_list, char_(')')))
+        .map(|(_open, records, _close)| SubSuperRecord(records))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::Finish;
+    use std::borrow::Cow;
+
+    #[test]
+    fn borrowed_same_as_owned() {
+        for input in [
+            "A(1, 2.0)",
+            "!USER_DEFINED('str', .ENUM., $, *, #12)",
+            "LENGTH_MEASURE_WITH_UNIT( LENGTH_MEASURE( 1.00000000000000 ), #359 )",
+            "B((1.0, A((2.0, 3.0))))",
+        ] {
+            let (res, borrowed) = super::simple_record(input).finish().unwrap();
+            assert_eq!(res, "");
+            let (res, owned) = crate::parser::exchange::simple_record(input)
+                .finish()
+                .unwrap();
+            assert_eq!(res, "");
+            assert_eq!(borrowed.into_owned(), owned);
+        }
+    }
+
+    #[test]
+    fn keyword() {
+        for (input, name, res) in [
+            ("CARTESIAN_POINT(", "CARTESIAN_POINT", "("),
+            ("!USER_DEFINED2 ", "USER_DEFINED2", " "),
+            ("A", "A", ""),
+        ] {
+            assert_eq!(super::keyword(input).finish().unwrap(), (res, name));
+        }
+        assert!(super::keyword("2A").finish().is_err());
+        assert!(super::keyword("!").finish().is_err());
+    }
+
+    #[test]
+    fn string_is_borrowed() {
+        let (res, s) = super::string("'vim'").finish().unwrap();
+        assert_eq!(res, "");
+        assert!(matches!(s.decoded(), Cow::Borrowed("vim")));
+
+        let (res, s) = super::string("'it''s'").finish().unwrap();
+        assert_eq!(res, "");
+        assert!(matches!(s.decoded(), Cow::Owned(s) if s == "it's"));
+        assert_eq!(s.raw(), "it''s");
+    }
+}
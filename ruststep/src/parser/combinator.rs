@@ -41,7 +41,8 @@ pub fn char_<'a>(c: char) -> impl ExchangeParser<'a, char> {
 
 pub fn tag_<'a>(name: &'static str) -> impl ExchangeParser<'a, &'a str> {
     move |input| {
-        let (input, c) = nom::bytes::complete::tag(name)(input)?;
+        // Keep the keyword in the error to report what is expected
+        let (input, c) = nom::error::context(name, nom::bytes::complete::tag(name))(input)?;
         Ok((input, c))
     }
 }
@@ -53,6 +54,25 @@ pub fn opt_<'a, O>(f: impl ExchangeParser<'a, O>) -> impl ExchangeParser<'a, Opt
     }
 }
 
+/// Context of errors in an entity instance, whose input starts at `#id =` of the instance
+pub(crate) const ENTITY_INSTANCE: &str = "entity instance";
+
+/// Mark errors in `f` as in an entity instance, see [ENTITY_INSTANCE]
+pub(crate) fn instance_<'a, O>(f: impl ExchangeParser<'a, O>) -> impl ExchangeParser<'a, O> {
+    move |input| {
+        let (input, c) = nom::error::context(ENTITY_INSTANCE, f.clone())(input)?;
+        Ok((input, c))
+    }
+}
+
+/// Turn a recoverable error into failure to stop backtracking, see [nom::combinator::cut]
+pub fn cut_<'a, O>(f: impl ExchangeParser<'a, O>) -> impl ExchangeParser<'a, O> {
+    move |input| {
+        let (input, c) = nom::combinator::cut(f.clone())(input)?;
+        Ok((input, c))
+    }
+}
+
 /// Comment
 ///
 /// A comment shall be encoded as a solidus asterisk `/*`
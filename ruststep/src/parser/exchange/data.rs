@@ -2,7 +2,7 @@ use crate::{
     ast::*,
     parser::{combinator::*, exchange::*, token::*},
 };
-use nom::{branch::alt, Parser};
+use nom::Parser;
 
 /// data_section = `DATA` \[ `(` [parameter_list] `)` \] `;` [entity_instance_list] `ENDSEC;` .
 pub fn data_section(input: &str) -> ParseResult<DataSection> {
@@ -11,7 +11,8 @@ pub fn data_section(input: &str) -> ParseResult<DataSection> {
         opt_(tuple_((char_('('), parameter_list, char_(')')))),
         char_(';'),
         entity_instance_list,
-        tag_("ENDSEC;"),
+        // Report the statement which is neither an entity instance nor `ENDSEC;`
+        cut_(tag_("ENDSEC;")),
     ))
     .map(|(_start, meta, _semicolon, entities, _end)| DataSection {
         meta: meta
@@ -28,8 +29,16 @@ pub fn entity_instance_list(input: &str) -> ParseResult<Vec<EntityInstance>> {
 }
 
 /// entity_instance = [simple_entity_instance] | [complex_entity_instance] .
+///
+/// Once `#id =` is read, the error is not backtracked so that it points the broken instance.
 pub fn entity_instance(input: &str) -> ParseResult<EntityInstance> {
-    alt((simple_entity_instance, complex_entity_instance)).parse(input)
+    let (_rest, (_id, _eq, open)) =
+        tuple_((entity_instance_name, char_('='), opt_(char_('(')))).parse(input)?;
+    if open.is_some() {
+        instance_(cut_(complex_entity_instance)).parse(input)
+    } else {
+        instance_(cut_(simple_entity_instance)).parse(input)
+    }
 }
 
 /// simple_entity_instance = [entity_instance_name] `=` [simple_record] `;` .
@@ -6,7 +6,7 @@ use nom::Parser;
 
 /// header_section = `HEADER;` [header_entity] [header_entity] [header_entity] \[ [header_entity_list] \] `ENDSEC;` .
 pub fn header_section(input: &str) -> ParseResult<Vec<Record>> {
-    tuple_((tag_("HEADER;"), header_entity_list, tag_("ENDSEC;")))
+    tuple_((tag_("HEADER;"), header_entity_list, cut_(tag_("ENDSEC;"))))
         .map(|(_start, entities, _close)| entities)
         .parse(input)
 }
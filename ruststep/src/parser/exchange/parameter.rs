@@ -26,16 +26,16 @@ pub fn typed_parameter(input: &str) -> ParseResult<Parameter> {
         .parse(input)
 }
 
-/// untyped_parameter = `$` | [integer] | [real] | [string] | [rhs_occurrence_name] | [enumeration] | binary | [list] .
+/// untyped_parameter = `$` | [integer] | [real] | [string](string_literal) | [rhs_occurrence_name] | [enumeration] | [binary] | [list] .
 pub fn untyped_parameter(input: &str) -> ParseResult<Parameter> {
     alt((
         char_('$').map(|_| Parameter::NotProvided),
         real.map(Parameter::Real),
         integer.map(Parameter::Integer),
-        string.map(Parameter::String),
+        string_literal.map(Parameter::String),
         rhs_occurrence_name.map(Parameter::Ref),
         enumeration.map(Parameter::Enumeration),
-        // FIXME binary
+        binary.map(Parameter::Binary),
         list,
     ))
     .parse(input)
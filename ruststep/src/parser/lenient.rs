@@ -0,0 +1,249 @@
+//! Tokenize exchange structure skipping broken entity instances
+//!
+//! [super::parse] fails if any entity instance in DATA sections is malformed,
+//! e.g. an unescaped quote in a string or a stray character.
+//! [parse] in this module skips such an instance,
+//! and resumes tokenizing at the next `#id =` following a `;`, or at `ENDSEC;`.
+//! The skipped text and its error are returned as [SkippedInstance]s.
+//!
+//! Errors out of DATA sections, e.g. in HEADER section, are not recovered.
+//!
+//! ```
+//! let step_str = r#"
+//! ISO-10303-21;
+//! HEADER;
+//!   FILE_DESCRIPTION((''), '2;1');
+//!   FILE_NAME('', '', (''), (''), '', '', '');
+//!   FILE_SCHEMA(('EXAMPLE'));
+//! ENDSEC;
+//! DATA;
+//!   #1 = A('it's broken');
+//!   #2 = B(1.0);
+//!   #3 = C(#2 x);
+//!   #4 = D(#2);
+//! ENDSEC;
+//! END-ISO-10303-21;
+//! "#.trim();
+//!
+//! let (ex, skipped) = ruststep::parser::lenient::parse(step_str).unwrap();
+//! let ids: Vec<u64> = ex.data[0].entities.iter().map(|e| e.id()).collect();
+//! assert_eq!(ids, [2, 4]);
+//!
+//! assert_eq!(skipped.len(), 2);
+//! assert_eq!(skipped[0].text, "#1 = A('it's broken');");
+//! assert_eq!(skipped[1].text, "#3 = C(#2 x);");
+//! assert_eq!(skipped[1].error.diagnostic().entity_id, Some(3));
+//! assert_eq!(skipped[1].error.diagnostic().line, 10);
+//! ```
+
+use crate::{
+    ast::*,
+    error::{Result, TokenizeFailed},
+    parser::{
+        combinator::*,
+        exchange,
+        stream::{self, Position},
+        token, tokenize_failed,
+    },
+};
+use nom::{Finish, Parser};
+
+/// Entity instance skipped by [parse]
+#[derive(Debug)]
+pub struct SkippedInstance {
+    /// Skipped text from the head of the broken instance to the `;` before resumed position
+    pub text: String,
+    /// Why tokenizing the instance failed
+    pub error: TokenizeFailed,
+}
+
+/// Parse entire STEP file skipping broken entity instances in DATA sections
+pub fn parse(input: &str) -> Result<(Exchange, Vec<SkippedInstance>)> {
+    let mut prefix = tuple_((
+        tag_("ISO-10303-21;"),
+        exchange::header_section,
+        opt_(exchange::anchor_section),
+        opt_(exchange::reference_section),
+        ignorable,
+    ));
+    let (mut rest, (_start, header, anchor, reference, _sep)) = match prefix.parse(input).finish() {
+        Ok(value) => value,
+        Err(e) => return Err(tokenize_failed(input, e).into()),
+    };
+
+    let mut position = Position::default();
+    position.advance(&input[..input.len() - rest.len()]);
+    let mut data = Vec::new();
+    let mut skipped = Vec::new();
+    while rest.starts_with("DATA") {
+        let (residual, section) = data_section(input, rest, &mut position, &mut skipped)?;
+        data.push(section);
+        rest = skip_ignorable(residual);
+        position.advance(&residual[..residual.len() - rest.len()]);
+    }
+
+    let mut suffix = tuple_((
+        tag_("END-ISO-10303-21;"),
+        many0_(exchange::signature_section),
+    ));
+    match suffix.parse(rest).finish() {
+        Ok((_residual, (_end, signature))) => Ok((
+            Exchange {
+                header,
+                anchor: anchor.unwrap_or_default(),
+                reference: reference.unwrap_or_default(),
+                data,
+                signature,
+            },
+            skipped,
+        )),
+        Err(e) => Err(tokenize_failed(input, e).into()),
+    }
+}
+
+/// Tokenize a DATA section in `source`, and push skipped instances into `skipped`
+///
+/// `position` is where `input` starts in `source`, and moved to the end of the section.
+fn data_section<'a>(
+    source: &str,
+    input: &'a str,
+    position: &mut Position,
+    skipped: &mut Vec<SkippedInstance>,
+) -> Result<(&'a str, DataSection)> {
+    let (mut rest, meta) = stream::data_start(input)
+        .finish()
+        .map_err(|e| tokenize_failed(source, e))?;
+    position.advance(&input[..input.len() - rest.len()]);
+    let mut entities = Vec::new();
+    loop {
+        let head = skip_ignorable(rest);
+        position.advance(&rest[..rest.len() - head.len()]);
+        rest = head;
+        if let Ok((residual, _end)) = tag_("ENDSEC;").parse(rest) {
+            position.advance(&rest[..rest.len() - residual.len()]);
+            return Ok((residual, DataSection { meta, entities }));
+        }
+        match exchange::entity_instance(rest).finish() {
+            Ok((residual, instance)) => {
+                entities.push(instance);
+                position.advance(&rest[..rest.len() - residual.len()]);
+                rest = residual;
+            }
+            Err(e) => {
+                // Render only the broken statement, and locate it by the position tracked so far,
+                // since preceding instances may have an unescaped quote and cannot be scanned
+                let end = resume_position(rest);
+                let failed = e
+                    .errors
+                    .iter()
+                    .map(|(remaining, _)| rest.len() - remaining.len())
+                    .max()
+                    .unwrap_or_default();
+                let statement = &rest[..end.unwrap_or(rest.len()).max(failed)];
+                let error = position.locate(tokenize_failed(statement, e));
+                let end = match end {
+                    Some(end) => end,
+                    None => return Err(error.into()),
+                };
+                skipped.push(SkippedInstance {
+                    text: rest[..end].trim_end().to_string(),
+                    error,
+                });
+                position.advance(&rest[..end]);
+                rest = &rest[end..];
+            }
+        }
+    }
+}
+
+fn skip_ignorable(input: &str) -> &str {
+    ignorable(input).map(|(rest, _)| rest).unwrap_or(input)
+}
+
+/// Position to resume tokenizing after a broken statement at the head of `input`
+///
+/// It is the head of `#id =` or `ENDSEC;` following a `;` with only spaces and comments between them.
+/// If the broken statement does not start with `#`, i.e. a stray text between instances,
+/// the first `#id =` or `ENDSEC;` is used without `;`.
+///
+/// The input is scanned only once, i.e. in linear time of the skipped text.
+fn resume_position(input: &str) -> Option<usize> {
+    let is_statement = |head: &str| {
+        tuple_((token::entity_instance_name, char_('=')))
+            .parse(head)
+            .is_ok()
+            || head.starts_with("ENDSEC;")
+    };
+    if !input.starts_with('#') {
+        return input
+            .match_indices(|c| c == '#' || c == 'E')
+            .map(|(i, _)| i)
+            .find(|&i| i > 0 && is_statement(&input[i..]));
+    }
+    let mut begin = 0;
+    while let Some(semicolon) = input[begin..].find(';') {
+        let next = begin + semicolon + 1;
+        let head = skip_ignorable(&input[next..]);
+        if is_statement(head) {
+            return Some(input.len() - head.len());
+        }
+        // `;` in the spaces and comments skipped above is not searched again
+        begin = input.len() - head.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resume() {
+        assert_eq!(resume_position("#1 = A('a'b'); #2 = B();"), Some(15));
+        assert_eq!(resume_position("#1 = A(#2 = 1); /* ; */ ENDSEC;"), Some(24));
+        // `#2 =` without `;` before it is not a head of statement
+        assert_eq!(resume_position("#1 = A(#2 = 1)"), None);
+        // stray text
+        assert_eq!(resume_position("x #2 = B();"), Some(2));
+        // `;` in comments
+        assert_eq!(resume_position("#1 = A(; /* ; */ x); #2 = B();"), Some(21));
+    }
+
+    #[test]
+    fn locate_skipped() {
+        let input = "ISO-10303-21;\nHEADER;\nFILE_SCHEMA(('EXAMPLE'));\nENDSEC;\nDATA;\n#1 = A(x);\n#2 = B(1.0);  #3 = C(y); #4 = D(#2);\nENDSEC;\nEND-ISO-10303-21;";
+        let (ex, skipped) = parse(input).unwrap();
+        let ids: Vec<u64> = ex.data[0].entities.iter().map(|e| e.id()).collect();
+        assert_eq!(ids, [2, 4]);
+
+        let d = skipped[0].error.diagnostic();
+        assert_eq!((d.line, d.column, d.entity_id), (6, 8, Some(1)));
+        assert_eq!(d.offset, input.find("x);").unwrap());
+
+        let d = skipped[1].error.diagnostic();
+        assert_eq!((d.line, d.column, d.entity_id), (7, 22, Some(3)));
+        assert_eq!(d.offset, input.find("y);").unwrap());
+        // Only the broken statement is rendered
+        let rendered = skipped[1].error.to_string();
+        assert!(rendered.contains("#3 = C(y);"));
+        assert!(!rendered.contains("#2"));
+    }
+
+    #[test]
+    fn unterminated() {
+        let input = "ISO-10303-21;\nHEADER;\nFILE_SCHEMA(('EXAMPLE'));\nENDSEC;\nDATA;\n#1 = A(;\n#2 = B();\nEND-ISO-10303-21;";
+        assert!(parse(input).is_err());
+    }
+
+    #[test]
+    fn same_as_parse() {
+        let step_str = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/steps/00000050_80d90bfdd2e74e709956122a_step_000.step"
+        ))
+        .unwrap();
+        let (ex, skipped) = parse(&step_str).unwrap();
+        assert!(skipped.is_empty());
+        assert_eq!(ex, super::super::parse(&step_str).unwrap());
+    }
+}
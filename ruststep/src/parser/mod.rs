@@ -28,17 +28,31 @@
 //! // Parse STEP file into `Exchange` struct
 //! let ex = ruststep::parser::parse(&step_str).unwrap();
 //! ```
+//!
+//! For large files which should not be loaded on memory at once,
+//! [stream::Reader] reads entity instances one by one from [std::io::BufRead].
+//! With `parallel` feature, `parallel` module tokenizes DATA sections on a thread pool.
+//! [lenient::parse] skips broken entity instances instead of failing entire file.
 
 pub mod basic;
+pub mod borrowed;
 pub mod combinator;
 pub mod exchange;
+pub mod lenient;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod stream;
+pub mod string;
 pub mod token;
 
 use crate::{
     ast,
     error::{Result, TokenizeFailed},
 };
-use nom::Finish;
+use nom::{
+    error::{VerboseError, VerboseErrorKind},
+    Finish,
+};
 
 /// Parse HEADER section
 ///
@@ -68,7 +82,7 @@ use nom::Finish;
 pub fn parse_header(input: &str) -> Result<(&str, Vec<ast::Record>)> {
     match exchange::header_section(input).finish() {
         Ok((input, records)) => Ok((input, records)),
-        Err(e) => Err(TokenizeFailed::new(input, e).into()),
+        Err(e) => Err(tokenize_failed(input, e).into()),
     }
 }
 
@@ -76,6 +90,58 @@ pub fn parse_header(input: &str) -> Result<(&str, Vec<ast::Record>)> {
 pub fn parse(input: &str) -> Result<ast::Exchange> {
     match exchange::exchange_file(input).finish() {
         Ok((_residual, ex)) => Ok(ex),
-        Err(e) => Err(TokenizeFailed::new(input, e).into()),
+        Err(e) => Err(tokenize_failed(input, e).into()),
+    }
+}
+
+/// [TokenizeFailed] for `input` with the id of the entity instance where tokenizing failed
+pub(crate) fn tokenize_failed(input: &str, err: VerboseError<&str>) -> TokenizeFailed {
+    let (entity_id, err) = split_instance(err);
+    TokenizeFailed::new(input, err).with_entity_id(entity_id)
+}
+
+/// Take the id of the entity instance out of the [combinator::ENTITY_INSTANCE] context in `err`
+pub(crate) fn split_instance(mut err: VerboseError<&str>) -> (Option<u64>, VerboseError<&str>) {
+    let mut entity_id = None;
+    err.errors.retain(|(head, kind)| match kind {
+        VerboseErrorKind::Context(combinator::ENTITY_INSTANCE) => {
+            if entity_id.is_none() {
+                entity_id = token::entity_instance_name(head).ok().map(|(_, id)| id);
+            }
+            false
+        }
+        _ => true,
+    });
+    (entity_id, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    fn entity_id(data: &str) -> Option<u64> {
+        match exchange::data_section(data).finish() {
+            Err(e) => tokenize_failed(data, e).diagnostic().entity_id,
+            Ok(_) => panic!("tokenize must fail"),
+        }
+    }
+
+    #[test]
+    fn tokenize_failed_entity_id() {
+        assert_eq!(entity_id("DATA; #1 = A(1.0); #2 = B(;"), Some(2));
+        assert_eq!(entity_id("DATA; #1 = A(1.0); #2 = (B(1.0) C(;"), Some(2));
+        assert_eq!(entity_id("DATA; #1 = A('#2 = ; ' x);"), Some(1));
+        // Not an entity instance until `=` is read
+        assert_eq!(entity_id("DATA; #1 = A(1.0); /* ; */ #23"), None);
+        assert_eq!(entity_id("DATA; #1 = A(1.0); "), None);
+    }
+
+    #[test]
+    fn tokenize_failed_borrowed() {
+        match ast::borrowed::DataSection::parse("DATA; #1 = A(1.0); #2 = B(1.0 #1); ENDSEC;") {
+            Err(Error::TokenizeFailed(e)) => assert_eq!(e.diagnostic().entity_id, Some(2)),
+            _ => panic!("tokenize must fail"),
+        }
     }
 }
@@ -0,0 +1,221 @@
+//! Tokenize DATA sections on a thread pool (requires `parallel` feature)
+//!
+//! Entity instances in a DATA section are independent statements terminated by `;`,
+//! and thus they can be tokenized separately.
+//! The body of DATA section is split into chunks at the end of statements
+//! found outside of strings and comments,
+//! i.e. each chunk starts at the `#id =` of an entity instance.
+//! These chunks are tokenized on [rayon]'s global thread pool,
+//! and merged into a [DataSection] in the original order.
+//!
+//! Unlike [exchange::data_section], duplicated entity ids are rejected
+//! with [Error::DuplicatedEntity], since the chunks are checked while merging.
+//!
+//! ```
+//! let step_str = r#"
+//! DATA;
+//!   #1 = A(1.0, 'a;b');
+//!   /* #2 = B(); */
+//!   #3 = C((#1));
+//! ENDSEC;
+//! "#.trim();
+//!
+//! let (residual, data) = ruststep::parser::parallel::parse_data_section(step_str).unwrap();
+//! assert_eq!(residual, "");
+//! assert_eq!(data, step_str.parse().unwrap());
+//! ```
+
+use crate::{
+    ast::*,
+    error::{Error, Result},
+    parser::{combinator::*, exchange, stream, tokenize_failed},
+};
+use nom::{Finish, Parser};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// Lower bound of the chunk size in bytes to avoid splitting small sections too finely
+const MIN_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Parse a DATA section in parallel
+///
+/// This is a parallel version of [exchange::data_section],
+/// and returns the residual input after `ENDSEC;` as [super::parse_header] does.
+pub fn parse_data_section(input: &str) -> Result<(&str, DataSection)> {
+    data_section_in(input, input)
+}
+
+/// [parse_data_section] for `input` in `source`, which is used to locate errors
+fn data_section_in<'a>(source: &str, input: &'a str) -> Result<(&'a str, DataSection)> {
+    let (body, meta) = match stream::data_start(input).finish() {
+        Ok(value) => value,
+        Err(e) => return Err(tokenize_failed(source, e).into()),
+    };
+    let chunk_size = std::cmp::max(
+        input.len() / (rayon::current_num_threads() * 4),
+        MIN_CHUNK_SIZE,
+    );
+    let (chunks, residual) = match split_chunks(body, chunk_size) {
+        Some(split) => split,
+        // `ENDSEC;` is not found. Let the sequential parser report the error.
+        None => {
+            return match exchange::data_section(input).finish() {
+                Ok((residual, data)) => Ok((residual, data)),
+                Err(e) => Err(tokenize_failed(source, e).into()),
+            }
+        }
+    };
+
+    let parsed: Vec<Vec<EntityInstance>> = chunks
+        .into_par_iter()
+        .map(|chunk| parse_chunk(source, chunk))
+        .collect::<Result<_>>()?;
+
+    let mut ids = HashSet::new();
+    let mut entities = Vec::with_capacity(parsed.iter().map(Vec::len).sum());
+    for instance in parsed.into_iter().flatten() {
+        if !ids.insert(instance.id()) {
+            return Err(Error::DuplicatedEntity(instance.id()));
+        }
+        entities.push(instance);
+    }
+    Ok((residual, DataSection { meta, entities }))
+}
+
+/// Parse entire STEP file with [parse_data_section]
+///
+/// This is a parallel version of [super::parse].
+pub fn parse(input: &str) -> Result<Exchange> {
+    let mut prefix = tuple_((
+        tag_("ISO-10303-21;"),
+        exchange::header_section,
+        opt_(exchange::anchor_section),
+        opt_(exchange::reference_section),
+        ignorable,
+    ));
+    let (mut rest, (_start, header, anchor, reference, _sep)) = match prefix.parse(input).finish() {
+        Ok(value) => value,
+        Err(e) => return Err(tokenize_failed(input, e).into()),
+    };
+
+    let mut data = Vec::new();
+    while rest.starts_with("DATA") {
+        let (residual, section) = data_section_in(input, rest)?;
+        data.push(section);
+        rest = ignorable(residual)
+            .map(|(rest, _)| rest)
+            .unwrap_or(residual);
+    }
+
+    let mut suffix = tuple_((
+        tag_("END-ISO-10303-21;"),
+        many0_(exchange::signature_section),
+    ));
+    match suffix.parse(rest).finish() {
+        Ok((_residual, (_end, signature))) => Ok(Exchange {
+            header,
+            anchor: anchor.unwrap_or_default(),
+            reference: reference.unwrap_or_default(),
+            data,
+            signature,
+        }),
+        Err(e) => Err(tokenize_failed(input, e).into()),
+    }
+}
+
+/// Split the body of DATA section into chunks of `entity_instance_list`
+///
+/// Returns the chunks and the residual input after `ENDSEC;`,
+/// or `None` if `ENDSEC;` is not found.
+fn split_chunks(body: &str, chunk_size: usize) -> Option<(Vec<&str>, &str)> {
+    let bytes = body.as_bytes();
+    let mut scanner = stream::StatementScanner::default();
+    let mut chunks = Vec::new();
+    let mut chunk_begin = 0;
+    let mut pos = 0;
+    loop {
+        let end = pos + scanner.find_end(&bytes[pos..])?;
+        // `;` is ASCII, and thus `end` is always at a char boundary
+        let statement = &body[pos..end];
+        if stream::is_statement(statement, "ENDSEC;") {
+            if pos > chunk_begin {
+                chunks.push(&body[chunk_begin..pos]);
+            }
+            return Some((chunks, &body[end..]));
+        }
+        pos = end;
+        if pos - chunk_begin >= chunk_size {
+            chunks.push(&body[chunk_begin..pos]);
+            chunk_begin = pos;
+        }
+    }
+}
+
+/// Tokenize a chunk consists of whole entity instances
+fn parse_chunk(source: &str, chunk: &str) -> Result<Vec<EntityInstance>> {
+    let (residual, (_sep, entities, _sep2)) =
+        tuple_((ignorable, exchange::entity_instance_list, ignorable))
+            .parse(chunk)
+            .finish()
+            .map_err(|e| tokenize_failed(source, e))?;
+    if residual.is_empty() {
+        return Ok(entities);
+    }
+    // Re-parse the first invalid entity instance to report why it fails
+    match exchange::entity_instance(residual).finish() {
+        Ok(_) => Err(Error::ExtraInputRemaining(residual.to_string())),
+        Err(e) => Err(tokenize_failed(source, e).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &str = r#"DATA;
+#1 = A(1.0, 'not; end');
+/* #2 = B(); */
+#3 = C((#1, #4));
+#4 = (D(1) E('ENDSEC;'));
+ENDSEC;
+END"#;
+
+    #[test]
+    fn same_as_sequential() {
+        let (residual, expected) = exchange::data_section(DATA).finish().unwrap();
+        assert_eq!(residual, "\nEND");
+        // Split every statement to check the boundaries
+        let (body, _meta) = stream::data_start(DATA).finish().unwrap();
+        let (chunks, residual) = split_chunks(body, 1).unwrap();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(residual, "\nEND");
+        let (residual, data) = parse_data_section(DATA).unwrap();
+        assert_eq!(residual, "\nEND");
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn duplicated() {
+        let input = "DATA; #1 = A(); #2 = B(); #1 = C(); ENDSEC;";
+        assert!(matches!(
+            parse_data_section(input),
+            Err(Error::DuplicatedEntity(1))
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse_data_section("DATA; #1 = A(); #2 = ; ENDSEC;").is_err());
+        assert!(parse_data_section("DATA; #1 = A();").is_err());
+    }
+
+    #[test]
+    fn diagnostic() {
+        let input = "ISO-10303-21;\nHEADER;\nFILE_SCHEMA(('EXAMPLE'));\nENDSEC;\nDATA;\n#1 = A();\n#2 = B(#1 #1);\nENDSEC;\nEND-ISO-10303-21;";
+        let d = match parse(input) {
+            Err(Error::TokenizeFailed(e)) => e.diagnostic().clone(),
+            _ => panic!("tokenize must fail"),
+        };
+        assert_eq!((d.line, d.column, d.entity_id), (7, 11, Some(2)));
+    }
+}
@@ -0,0 +1,550 @@
+//! Streaming reader for exchange structure
+//!
+//! [parse](super::parse) requires entire STEP file as a `&str`,
+//! and creates [Exchange](crate::ast::Exchange) containing all entity instances on memory.
+//! [Reader] reads an exchange structure from [std::io::BufRead] statement by statement,
+//! i.e. it splits the input at `;` outside of strings and comments,
+//! and tokenizes each statement using same parsers in [exchange](super::exchange).
+//! The memory usage is bounded by the size of the largest statement.
+//!
+//! Example
+//! --------
+//!
+//! ```
+//! use ruststep::parser::stream::Reader;
+//! use std::{fs, io, path::*};
+//!
+//! let step_file = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+//!     .join("tests/steps/00000050_80d90bfdd2e74e709956122a_step_000.step");
+//! let file = io::BufReader::new(fs::File::open(step_file).unwrap());
+//!
+//! // HEADER section is read in `Reader::new`
+//! let mut reader = Reader::new(file).unwrap();
+//! let header = reader.header().unwrap();
+//! assert_eq!(header.file_schema.schema, ["AUTOMOTIVE_DESIGN { 1 0 10303 214 1 1 1 1 }"]);
+//!
+//! // Entity instances in DATA sections are yielded one by one
+//! let first = reader.next().unwrap().unwrap();
+//! assert_eq!(first.id(), 1);
+//! ```
+//!
+//! [Reader::with_filter] skips the entity instances rejected by a [LoadFilter]
+//! reading only their ids and keywords.
+
+use crate::{
+    ast::*,
+    error::{Error, Result, TokenizeFailed},
+    header::Header,
+    parser::{
+        combinator::*,
+        exchange::*,
+        token::{entity_instance_name, keyword},
+        tokenize_failed,
+    },
+    tables::LoadFilter,
+};
+use nom::{Finish, Parser};
+use std::io::{self, BufRead};
+
+/// Scanner for the end of statement, i.e. `;` outside of strings and comments
+///
+/// The state is kept between calls of [StatementScanner::find_end]
+/// so that the input can be fed as chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct StatementScanner {
+    state: ScanState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScanState {
+    Normal,
+    /// After `/` outside of strings and comments
+    Slash,
+    /// In `'...'`
+    String,
+    /// In `/* ... */`
+    Comment,
+    /// After `*` in comments
+    CommentStar,
+}
+
+impl Default for ScanState {
+    fn default() -> Self {
+        ScanState::Normal
+    }
+}
+
+impl StatementScanner {
+    /// Returns the position just after the first `;` terminating a statement
+    pub(crate) fn find_end(&mut self, bytes: &[u8]) -> Option<usize> {
+        for (i, &b) in bytes.iter().enumerate() {
+            self.state = match (self.state, b) {
+                (ScanState::Normal, b';') => {
+                    return Some(i + 1);
+                }
+                (ScanState::Normal, b'\'') => ScanState::String,
+                (ScanState::Normal, b'/') => ScanState::Slash,
+                (ScanState::Normal, _) => ScanState::Normal,
+
+                (ScanState::Slash, b'*') => ScanState::Comment,
+                (ScanState::Slash, b';') => {
+                    self.state = ScanState::Normal;
+                    return Some(i + 1);
+                }
+                (ScanState::Slash, b'\'') => ScanState::String,
+                (ScanState::Slash, b'/') => ScanState::Slash,
+                (ScanState::Slash, _) => ScanState::Normal,
+
+                // Doubled apostrophe `''` in a string re-enters the string state
+                (ScanState::String, b'\'') => ScanState::Normal,
+                (ScanState::String, _) => ScanState::String,
+
+                (ScanState::Comment, b'*') => ScanState::CommentStar,
+                (ScanState::Comment, _) => ScanState::Comment,
+                (ScanState::CommentStar, b'/') => ScanState::Normal,
+                (ScanState::CommentStar, b'*') => ScanState::CommentStar,
+                (ScanState::CommentStar, _) => ScanState::Comment,
+            };
+        }
+        None
+    }
+}
+
+/// Tokenize a statement, which may have spaces and comments at the head
+pub(crate) fn parse_statement<'a, O>(
+    statement: &'a str,
+    f: impl ExchangeParser<'a, O>,
+) -> std::result::Result<O, TokenizeFailed> {
+    tuple_((ignorable, f))
+        .map(|(_sep, value)| value)
+        .parse(statement)
+        .finish()
+        .map(|(_residual, value)| value)
+        .map_err(|err| tokenize_failed(statement, err))
+}
+
+/// Check the statement is the given keyword, e.g. `ENDSEC;`
+pub(crate) fn is_statement(statement: &str, keyword: &'static str) -> bool {
+    parse_statement(statement, tag_(keyword)).is_ok()
+}
+
+/// Read the id and keywords of an entity instance without parsing its parameters,
+/// except for complex entity instances
+pub(crate) fn scan_instance(
+    statement: &str,
+) -> std::result::Result<(u64, Vec<String>), TokenizeFailed> {
+    match parse_statement(
+        statement,
+        tuple_((entity_instance_name, char_('='), keyword)),
+    ) {
+        Ok((id, _equal, keyword)) => Ok((id, vec![keyword])),
+        Err(_) => {
+            let instance = parse_statement(statement, entity_instance)?;
+            let keywords = instance.records().iter().map(|r| r.name.clone()).collect();
+            Ok((instance.id(), keywords))
+        }
+    }
+}
+
+/// `DATA` \[ `(` [parameter_list] `)` \] `;`
+pub(crate) fn data_start(input: &str) -> ParseResult<Vec<Parameter>> {
+    tuple_((
+        tag_("DATA"),
+        opt_(tuple_((char_('('), parameter_list, char_(')')))),
+        char_(';'),
+    ))
+    .map(|(_start, meta, _semicolon)| {
+        meta.map(|(_open, params, _close)| params)
+            .unwrap_or_default()
+    })
+    .parse(input)
+}
+
+/// Position in the input to locate errors in statements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Position {
+    pub(crate) offset: usize,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Position {
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+}
+
+impl Position {
+    /// Move the position over `text` starting at it
+    pub(crate) fn advance(&mut self, text: &str) {
+        self.offset += text.len();
+        match text.rfind('\n') {
+            Some(pos) => {
+                self.line += text.matches('\n').count();
+                self.column = text[pos + 1..].chars().count() + 1;
+            }
+            None => self.column += text.chars().count(),
+        }
+    }
+
+    /// Locate the error in a text starting at this position
+    pub(crate) fn locate(&self, e: TokenizeFailed) -> TokenizeFailed {
+        e.shift(self.offset, self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReaderState {
+    /// In `DATA` section
+    Data,
+    /// After `ENDSEC;` of `DATA` section
+    BetweenData,
+    /// After `END-ISO-10303-21;` or any error
+    End,
+}
+
+/// Pull-style reader of exchange structure from [BufRead]
+///
+/// HEADER, ANCHOR, and REFERENCE sections are read in [Reader::new],
+/// and then entity instances in DATA sections are yielded through [Iterator].
+/// Statements after `END-ISO-10303-21;`, i.e. SIGNATURE sections, are not read.
+///
+/// The iteration stops at the first error.
+#[derive(Debug)]
+pub struct Reader<R> {
+    inner: R,
+    scanner: StatementScanner,
+    /// Bytes of the current statement
+    buf: Vec<u8>,
+    /// Head of the current statement
+    start: Position,
+    /// End of the current statement
+    end: Position,
+    state: ReaderState,
+    header: Vec<Record>,
+    anchor: Vec<Anchor>,
+    reference: Vec<ReferenceEntry>,
+    meta: Vec<Parameter>,
+    filter: LoadFilter,
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Read until the head of the first DATA section
+    pub fn new(inner: R) -> Result<Self> {
+        let mut reader = Reader {
+            inner,
+            scanner: StatementScanner::default(),
+            buf: Vec::new(),
+            start: Position::default(),
+            end: Position::default(),
+            state: ReaderState::BetweenData,
+            header: Vec::new(),
+            anchor: Vec::new(),
+            reference: Vec::new(),
+            meta: Vec::new(),
+            filter: LoadFilter::All,
+        };
+        match reader.read_head() {
+            Ok(()) => Ok(reader),
+            Err(e) => Err(reader.locate(e)),
+        }
+    }
+
+    fn read_head(&mut self) -> Result<()> {
+        let reader = self;
+        let statement = reader.expect_statement()?;
+        parse_statement(&statement, tag_("ISO-10303-21;"))?;
+
+        let statement = reader.expect_statement()?;
+        parse_statement(&statement, tag_("HEADER;"))?;
+        loop {
+            let statement = reader.expect_statement()?;
+            if is_statement(&statement, "ENDSEC;") {
+                break;
+            }
+            let record = parse_statement(&statement, header_entity)?;
+            reader.header.push(record);
+        }
+
+        loop {
+            let statement = reader.expect_statement()?;
+            if is_statement(&statement, "ANCHOR;") {
+                loop {
+                    let statement = reader.expect_statement()?;
+                    if is_statement(&statement, "ENDSEC;") {
+                        break;
+                    }
+                    reader.anchor.push(parse_statement(&statement, anchor)?);
+                }
+                continue;
+            }
+            if is_statement(&statement, "REFERENCE;") {
+                loop {
+                    let statement = reader.expect_statement()?;
+                    if is_statement(&statement, "ENDSEC;") {
+                        break;
+                    }
+                    reader
+                        .reference
+                        .push(parse_statement(&statement, reference)?);
+                }
+                continue;
+            }
+            if is_statement(&statement, "END-ISO-10303-21;") {
+                reader.state = ReaderState::End;
+                break;
+            }
+            reader.meta = parse_statement(&statement, data_start)?;
+            reader.state = ReaderState::Data;
+            break;
+        }
+        Ok(())
+    }
+
+    /// Skip the entity instances rejected by `filter`
+    ///
+    /// Only the id and keyword of a rejected instance are tokenized,
+    /// i.e. syntax errors in its parameters are not reported.
+    /// A complex entity instance is tokenized entirely to check its keywords.
+    pub fn with_filter(mut self, filter: LoadFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Locate tokenize error in the current statement on entire input
+    fn locate(&self, e: Error) -> Error {
+        match e {
+            Error::TokenizeFailed(e) => Error::TokenizeFailed(self.start.locate(e)),
+            e => e,
+        }
+    }
+
+    /// Records in HEADER section
+    pub fn header_records(&self) -> &[Record] {
+        &self.header
+    }
+
+    /// HEADER section
+    pub fn header(&self) -> Result<Header> {
+        Header::from_records(&self.header)
+    }
+
+    /// Entries in ANCHOR section
+    pub fn anchor(&self) -> &[Anchor] {
+        &self.anchor
+    }
+
+    /// Entries in REFERENCE section
+    pub fn reference(&self) -> &[ReferenceEntry] {
+        &self.reference
+    }
+
+    /// Parameters of current DATA section, e.g. `DATA(...);`
+    pub fn data_meta(&self) -> &[Parameter] {
+        &self.meta
+    }
+
+    /// Byte offset just after the head of the first DATA section, `None` if there is no DATA section
+    pub(crate) fn data_offset(&self) -> Option<usize> {
+        match self.state {
+            ReaderState::Data => Some(self.end.offset),
+            _ => None,
+        }
+    }
+
+    /// Read next statement. Returns `None` if only spaces and comments remain.
+    fn read_statement(&mut self) -> Result<Option<String>> {
+        self.buf.clear();
+        loop {
+            let available = self.inner.fill_buf()?;
+            if available.is_empty() {
+                let rest = String::from_utf8(std::mem::take(&mut self.buf))
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                if parse_statement(&rest, nom::combinator::eof).is_ok() {
+                    return Ok(None);
+                }
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Exchange structure ends without `;`",
+                )
+                .into());
+            }
+            match self.scanner.find_end(available) {
+                Some(end) => {
+                    self.buf.extend_from_slice(&available[..end]);
+                    self.inner.consume(end);
+                    break;
+                }
+                None => {
+                    let len = available.len();
+                    self.buf.extend_from_slice(available);
+                    self.inner.consume(len);
+                }
+            }
+        }
+        let statement = String::from_utf8(std::mem::take(&mut self.buf))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.start = self.end;
+        self.end.advance(&statement);
+        Ok(Some(statement))
+    }
+
+    fn expect_statement(&mut self) -> Result<String> {
+        match self.read_statement()? {
+            Some(statement) => Ok(statement),
+            None => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Exchange structure ends without `END-ISO-10303-21;`",
+            )
+            .into()),
+        }
+    }
+
+    fn next_instance(&mut self) -> Result<Option<EntityInstance>> {
+        loop {
+            match self.state {
+                ReaderState::End => return Ok(None),
+                ReaderState::BetweenData => {
+                    let statement = self.expect_statement()?;
+                    if is_statement(&statement, "END-ISO-10303-21;") {
+                        self.state = ReaderState::End;
+                        continue;
+                    }
+                    self.meta = parse_statement(&statement, data_start)?;
+                    self.state = ReaderState::Data;
+                }
+                ReaderState::Data => {
+                    let statement = self.expect_statement()?;
+                    if is_statement(&statement, "ENDSEC;") {
+                        self.state = ReaderState::BetweenData;
+                        continue;
+                    }
+                    if !matches!(self.filter, LoadFilter::All) {
+                        let (_id, keywords) = scan_instance(&statement)?;
+                        if !keywords.iter().any(|keyword| self.filter.accepts(keyword)) {
+                            continue;
+                        }
+                    }
+                    let instance = parse_statement(&statement, entity_instance)?;
+                    return Ok(Some(instance));
+                }
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<EntityInstance>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_instance() {
+            Ok(instance) => instance.map(Ok),
+            Err(e) => {
+                self.state = ReaderState::End;
+                Some(Err(self.locate(e)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION(('THIS FILE CONTAINS A SMALL SAMPLE STEP MODEL'), '3;1');
+  FILE_NAME('EXAMPLE STEP FILE #1', '2013-02-11T15:30:00', ('JOHN DOE'), ('ACME INC.'), 'CIM/STEP VERSION2', 'SUPER CIM SYSTEM RELEASE 4.0', 'APPROVED BY JOE BLOGGS');
+  FILE_SCHEMA(('EXAMPLE_GEOMETRY'));
+ENDSEC;
+DATA;
+  /* cartesian point entity; */
+  #1 = CPT(0.0, 0.0, 0.0);
+  #2 = CPT(0.0, 1.0, 0.0);
+  #3 = (A('a;b') B(#1));
+ENDSEC;
+DATA;
+  #11 = VX(#1);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+    #[test]
+    fn scanner() {
+        let mut scanner = StatementScanner::default();
+        assert_eq!(scanner.find_end(b"A('x;y'); B();"), Some(9));
+
+        let mut scanner = StatementScanner::default();
+        assert_eq!(scanner.find_end(b"/* ; */ A();"), Some(12));
+
+        // chunked input
+        let mut scanner = StatementScanner::default();
+        assert_eq!(scanner.find_end(b"A('it''s"), None);
+        assert_eq!(scanner.find_end(b";');"), Some(4));
+    }
+
+    #[test]
+    fn read() {
+        // Use small buffer to test statements split over buffer boundaries
+        let mut reader = Reader::new(io::BufReader::with_capacity(7, EXAMPLE.as_bytes())).unwrap();
+        assert_eq!(reader.header_records().len(), 3);
+        let header = reader.header().unwrap();
+        assert_eq!(header.file_schema.schema, ["EXAMPLE_GEOMETRY"]);
+
+        let ids: Vec<u64> = reader.by_ref().map(|e| e.unwrap().id()).collect();
+        assert_eq!(ids, [1, 2, 3, 11]);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn same_as_parse() {
+        let ex = crate::parser::parse(EXAMPLE.trim()).unwrap();
+        let reader = Reader::new(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(reader.header_records(), ex.header.as_slice());
+        let instances: Vec<EntityInstance> = reader.map(|e| e.unwrap()).collect();
+        let expected: Vec<EntityInstance> = ex
+            .data
+            .into_iter()
+            .flat_map(|sec| sec.entities.into_iter())
+            .collect();
+        assert_eq!(instances, expected);
+    }
+
+    #[test]
+    fn error() {
+        let input = EXAMPLE.replace("#2 = CPT(0.0, 1.0, 0.0);", "#2 = CPT(0.0, 1.0, 0.0) x;");
+        let mut reader = Reader::new(input.as_bytes()).unwrap();
+        assert!(reader.next().unwrap().is_ok());
+        match reader.next().unwrap() {
+            Err(Error::TokenizeFailed(e)) => {
+                let d = e.diagnostic();
+                assert_eq!((d.line, d.column, d.entity_id), (11, 27, Some(2)));
+                assert_eq!(&input[d.offset..d.offset + 2], "x;");
+            }
+            _ => panic!("tokenize must fail"),
+        }
+        // fused after error
+        assert!(reader.next().is_none());
+
+        // truncated
+        let input = &EXAMPLE[..EXAMPLE.find("#11").unwrap()];
+        let reader = Reader::new(input.as_bytes()).unwrap();
+        assert!(reader.last().unwrap().is_err());
+    }
+
+    #[test]
+    fn filter() {
+        // Parameters of rejected instances are not tokenized
+        let input = EXAMPLE.replace("#2 = CPT(0.0, 1.0, 0.0);", "#2 = CPT(0.0, 1.0, 0.0) x;");
+        let reader = Reader::new(input.as_bytes())
+            .unwrap()
+            .with_filter(LoadFilter::allow(["VX", "B"]));
+        let ids: Vec<u64> = reader.map(|e| e.unwrap().id()).collect();
+        assert_eq!(ids, [3, 11]);
+    }
+}
@@ -0,0 +1,275 @@
+//! Decode and encode string literals defined in ISO-10303-21 6.4.3
+//!
+//! The content of a string literal is encoded with the basic alphabet,
+//! i.e. printable ASCII characters, and other characters are represented
+//! by control directives:
+//!
+//! | Encoded            | Decoded                                                        |
+//! |:-------------------|:---------------------------------------------------------------|
+//! | `''`               | `'`                                                            |
+//! | `\\`               | `\`                                                            |
+//! | `\S\c`             | `c` + 0x80 in the ISO 8859 part selected by `\P?\`             |
+//! | `\P?\`             | select ISO 8859-1 to 9 by `A` to `I`, default is ISO 8859-1    |
+//! | `\X\hh`            | U+00hh, i.e. ISO 8859-1 character of code `hh`                 |
+//! | `\X2\hhhh...\X0\`  | UTF-16 code units written by four hex digits                   |
+//! | `\X4\hhhhhhhh...\X0\` | Unicode code points written by eight hex digits             |
+//!
+//! A reverse solidus which does not start any of them, e.g. in `'C:\work\a.stp'` written by some exporters,
+//! is kept as a literal character.
+//!
+//! ```
+//! use ruststep::parser::string::{decode, encode};
+//!
+//! assert_eq!(decode(r"it''s \X2\9B546CD5\X0\").unwrap(), "it's 魔法");
+//! assert_eq!(decode(r"\PB\\S\o").unwrap(), "ď"); // 0xEF in ISO 8859-2
+//! assert_eq!(encode("it's ä"), r"it''s \X\E4");
+//! ```
+
+use crate::error::{Error, Result};
+use encoding_rs::Encoding;
+
+/// Decode the content of a string literal, i.e. the part between the apostrophes
+///
+/// This fails only for an unpaired apostrophe, which never appears in the content tokenized by
+/// [token::string_raw](super::token::string_raw).
+pub fn decode(raw: &str) -> Result<String> {
+    decode_(raw).map_err(|reason| Error::InvalidStringLiteral {
+        raw: raw.to_string(),
+        reason,
+    })
+}
+
+/// Encode a string into the content of a string literal
+///
+/// Characters out of printable ASCII are encoded by `\X\hh` if they are in ISO 8859-1,
+/// by `\X2\...\X0\` if they are in the basic multilingual plane,
+/// and by `\X4\...\X0\` otherwise.
+pub fn encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => out.push_str("''"),
+            '\\' => out.push_str("\\\\"),
+            ' '..='~' => out.push(c),
+            '\u{0}'..='\u{ff}' => out.push_str(&format!("\\X\\{:02X}", c as u32)),
+            '\u{100}'..='\u{ffff}' => {
+                out.push_str("\\X2\\");
+                out.push_str(&format!("{:04X}", c as u32));
+                while let Some(&c) = chars.peek() {
+                    if !('\u{100}'..='\u{ffff}').contains(&c) {
+                        break;
+                    }
+                    out.push_str(&format!("{:04X}", c as u32));
+                    chars.next();
+                }
+                out.push_str("\\X0\\");
+            }
+            _ => {
+                out.push_str("\\X4\\");
+                out.push_str(&format!("{:08X}", c as u32));
+                while let Some(&c) = chars.peek() {
+                    if c <= '\u{ffff}' {
+                        break;
+                    }
+                    out.push_str(&format!("{:08X}", c as u32));
+                    chars.next();
+                }
+                out.push_str("\\X0\\");
+            }
+        }
+    }
+    out
+}
+
+/// Check if the raw content is same as decoded one, i.e. no control directives and `''`
+pub(crate) fn is_plain(raw: &str) -> bool {
+    !raw.contains(|c| c == '\'' || c == '\\')
+}
+
+/// ISO 8859 part selected by `\P?\`
+///
+/// Only the upper half (0xA0-0xFF) is used by `\S\`,
+/// where windows-1252 and windows-1254 are same as ISO 8859-1 and 9, respectively.
+fn page(alphabet: char) -> Option<&'static Encoding> {
+    Some(match alphabet {
+        'A' => encoding_rs::WINDOWS_1252,
+        'B' => encoding_rs::ISO_8859_2,
+        'C' => encoding_rs::ISO_8859_3,
+        'D' => encoding_rs::ISO_8859_4,
+        'E' => encoding_rs::ISO_8859_5,
+        'F' => encoding_rs::ISO_8859_6,
+        'G' => encoding_rs::ISO_8859_7,
+        'H' => encoding_rs::ISO_8859_8,
+        'I' => encoding_rs::WINDOWS_1254,
+        _ => return None,
+    })
+}
+
+/// Hex digits in upper case as [basic::hex](super::basic::hex)
+fn hex(s: &str) -> Option<u32> {
+    if s.chars().all(|c| matches!(c, '0'..='9' | 'A'..='F')) {
+        u32::from_str_radix(s, 16).ok()
+    } else {
+        None
+    }
+}
+
+fn decode_(raw: &str) -> std::result::Result<String, &'static str> {
+    let mut out = String::with_capacity(raw.len());
+    let mut encoding = page('A').unwrap();
+    let mut rest = raw;
+    while let Some(pos) = rest.find(|c| c == '\'' || c == '\\') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        if let Some(tail) = rest.strip_prefix("''") {
+            out.push('\'');
+            rest = tail;
+        } else if rest.starts_with('\'') {
+            return Err("unpaired apostrophe");
+        } else if let Some(tail) = rest.strip_prefix("\\\\") {
+            out.push('\\');
+            rest = tail;
+        } else if let Some(tail) = control_directive(rest, &mut encoding, &mut out) {
+            rest = tail;
+        } else {
+            // Not a control directive
+            out.push('\\');
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Decode a control directive at the head of `rest` into `out`, and returns the remaining part
+///
+/// `None` is returned without modifying `out` if `rest` does not start with a valid control directive.
+fn control_directive<'a>(
+    rest: &'a str,
+    encoding: &mut &'static Encoding,
+    out: &mut String,
+) -> Option<&'a str> {
+    if let Some(tail) = rest.strip_prefix("\\S\\") {
+        let c = tail.chars().next().filter(|c| (' '..='~').contains(c))?;
+        // apostrophe is doubled in the raw form
+        let tail = if c == '\'' {
+            tail.strip_prefix("''")?
+        } else {
+            &tail[1..]
+        };
+        let byte = [c as u8 + 0x80];
+        let decoded = encoding.decode_without_bom_handling_and_without_replacement(&byte)?;
+        out.push_str(&decoded);
+        return Some(tail);
+    }
+    if let Some(tail) = rest.strip_prefix("\\P") {
+        let mut chars = tail.chars();
+        let page = chars.next().and_then(page)?;
+        let tail = chars.as_str().strip_prefix('\\')?;
+        *encoding = page;
+        return Some(tail);
+    }
+    if let Some(tail) = rest.strip_prefix("\\X\\") {
+        let code = tail.get(..2).and_then(hex)?;
+        out.push(char::from_u32(code).unwrap());
+        return Some(&tail[2..]);
+    }
+    if let Some(tail) = rest.strip_prefix("\\X2\\") {
+        let end = tail.find("\\X0\\")?;
+        let body = &tail[..end];
+        if !body.is_ascii() || body.len() % 4 != 0 {
+            return None;
+        }
+        let units = (0..body.len())
+            .step_by(4)
+            .map(|i| hex(&body[i..i + 4]).map(|x| x as u16))
+            .collect::<Option<Vec<u16>>>()?;
+        let decoded = char::decode_utf16(units)
+            .collect::<std::result::Result<String, _>>()
+            .ok()?;
+        out.push_str(&decoded);
+        return Some(&tail[end + 4..]);
+    }
+    if let Some(tail) = rest.strip_prefix("\\X4\\") {
+        let end = tail.find("\\X0\\")?;
+        let body = &tail[..end];
+        if !body.is_ascii() || body.len() % 8 != 0 {
+            return None;
+        }
+        let decoded = (0..body.len())
+            .step_by(8)
+            .map(|i| hex(&body[i..i + 8]).and_then(char::from_u32))
+            .collect::<Option<String>>()?;
+        out.push_str(&decoded);
+        return Some(&tail[end + 4..]);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_directives() {
+        assert_eq!(decode("").unwrap(), "");
+        assert_eq!(decode("vim").unwrap(), "vim");
+        assert_eq!(decode("it''s").unwrap(), "it's");
+        assert_eq!(decode(r"C:\\path").unwrap(), r"C:\path");
+        assert_eq!(decode(r"\S\D").unwrap(), "Ä");
+        assert_eq!(decode(r"\S\''").unwrap(), "§");
+        assert_eq!(decode(r"\PE\\S\P\PA\\S\D").unwrap(), "\u{430}Ä");
+        assert_eq!(decode(r"Stra\X\DFe").unwrap(), "Straße");
+        assert_eq!(decode(r"\X2\00E4\X0\").unwrap(), "ä");
+        assert_eq!(decode(r"\X2\D83DDE00\X0\").unwrap(), "😀");
+        assert_eq!(decode(r"\X4\0001F600\X0\").unwrap(), "😀");
+        // high codepoints are allowed in ISO-10303-21:2016
+        assert_eq!(decode("魔法少女").unwrap(), "魔法少女");
+    }
+
+    #[test]
+    fn decode_literal_reverse_solidus() {
+        for raw in [
+            r"\",
+            r"C:\work\a.stp",
+            r"C:\Program Files\X",
+            r"\Q\",
+            r"\X\G0",
+            r"\X2\00E",
+            r"\X2\00E4",
+            r"\X4\0011FFFF\X0\",
+            r"\PZ\",
+            r"\S\",
+            // hex digits must be upper case
+            r"\X\e4",
+            r"\X2\00e4\X0\",
+            r"\X4\000000e4\X0\",
+        ] {
+            assert_eq!(decode(raw).unwrap(), raw);
+        }
+        // valid directives after a literal one
+        assert_eq!(decode(r"C:\d\X\E4").unwrap(), r"C:\dä");
+    }
+
+    #[test]
+    fn decode_invalid() {
+        for raw in ["'", "it's"] {
+            assert!(decode(raw).is_err(), "{}", raw);
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        for s in [
+            "",
+            "it's",
+            r"C:\path",
+            "Straße",
+            "魔法少女まどか☆マギカ",
+            "😀\n",
+        ] {
+            assert_eq!(decode(&encode(s)).unwrap(), s);
+        }
+    }
+}
@@ -3,11 +3,13 @@
 use crate::{
     ast::*,
     parser::{basic::*, combinator::*},
+    primitive::Binary,
 };
 use nom::{
     branch::alt,
+    bytes::complete::tag,
     character::complete::{char, digit0, digit1, multispace0, none_of, satisfy},
-    combinator::opt,
+    combinator::{opt, recognize},
     multi::{many0, many1},
     sequence::tuple,
     Parser,
@@ -67,12 +69,82 @@ pub fn real(input: &str) -> ParseResult<f64> {
 }
 
 /// string = `'` { [special] | [digit] | [space] | [lower] | [upper] | high_codepoint | [apostrophe] [apostrophe] | [reverse_solidus] [reverse_solidus] | control_directive } `'` .
+///
+/// The content is decoded into Unicode by [string::decode](super::string::decode).
+/// Use [string_literal] to keep the encoded form.
 pub fn string(input: &str) -> ParseResult<String> {
-    tuple((char('\''), many0(none_of("'")), char('\'')))
-        .map(|(_start, s, _end)| s.iter().collect())
+    string_literal.map(StringLiteral::into_string).parse(input)
+}
+
+/// Same as [string], but keeps the encoded form in [StringLiteral]
+///
+/// ```
+/// use ruststep::parser::token::string_literal;
+///
+/// let (residual, s) = string_literal(r"'it''s \X2\9B54\X0\'").unwrap();
+/// assert_eq!(residual, "");
+/// assert_eq!(s, "it's 魔");
+/// assert_eq!(s.raw(), r"it''s \X2\9B54\X0\");
+/// ```
+pub fn string_literal(input: &str) -> ParseResult<StringLiteral> {
+    let (residual, raw) = string_raw(input)?;
+    match StringLiteral::from_raw(raw) {
+        Ok(s) => Ok((residual, s)),
+        Err(_) => Err(invalid_string(input)),
+    }
+}
+
+/// Same as [string], but returns the content between apostrophes without decoding
+///
+/// ```
+/// use ruststep::parser::token::string_raw;
+///
+/// let (residual, raw) = string_raw(r"'it''s \X2\9B54\X0\'").unwrap();
+/// assert_eq!(residual, "");
+/// assert_eq!(raw, r"it''s \X2\9B54\X0\");
+/// ```
+pub fn string_raw(input: &str) -> ParseResult<&str> {
+    let content = many0(alt((none_of("'").map(|_| ()), tag("''").map(|_| ()))));
+    tuple((char('\''), recognize(content), char('\'')))
+        .map(|(_start, raw, _end)| raw)
         .parse(input)
 }
 
+// Root error for invalid control directives in string
+pub(crate) fn invalid_string(input: &str) -> nom::Err<nom::error::VerboseError<&str>> {
+    nom::Err::Failure(nom::error::VerboseError {
+        errors: vec![(
+            input,
+            nom::error::VerboseErrorKind::Context("invalid-string-literal"),
+        )],
+    })
+}
+
+/// binary = `"` ( `0` | `1` | `2` | `3` ) { [hex] } `"` .
+pub fn binary(input: &str) -> ParseResult<Binary> {
+    let (residual, encoded) = tuple((
+        char('"'),
+        recognize(tuple((satisfy(|c| matches!(c, '0'..='3')), many0(hex)))),
+        char('"'),
+    ))
+    .map(|(_start, encoded, _end)| encoded)
+    .parse(input)?;
+    match encoded.parse() {
+        Ok(binary) => Ok((residual, binary)),
+        Err(_) => Err(invalid_binary(input)),
+    }
+}
+
+// Root error for binary which has unused bits without any hex digits, e.g. `"1"`
+fn invalid_binary(input: &str) -> nom::Err<nom::error::VerboseError<&str>> {
+    nom::Err::Failure(nom::error::VerboseError {
+        errors: vec![(
+            input,
+            nom::error::VerboseErrorKind::Context("invalid-binary"),
+        )],
+    })
+}
+
 /// resource = `<` UNIVERSAL_RESOURCE_IDENTIFIER `>` .
 ///
 /// Parse as string, without validating as URI
@@ -252,11 +324,42 @@ mod tests {
         assert!(super::real("123").finish().is_err());
     }
 
+    #[test]
+    fn binary() {
+        let (res, b) = super::binary(r#""0ABC", 1"#).finish().unwrap();
+        assert_eq!(res, ", 1");
+        assert_eq!(b.len(), 12);
+        assert_eq!(b.to_string(), "0ABC");
+
+        let (res, b) = super::binary(r#""0""#).finish().unwrap();
+        assert_eq!(res, "");
+        assert!(b.is_empty());
+
+        assert!(super::binary(r#""4A""#).finish().is_err());
+        assert!(super::binary(r#""1""#).finish().is_err());
+        assert!(super::binary(r#""0abc""#).finish().is_err());
+    }
+
     #[test]
     fn string() {
         let (res, s) = super::string("'vim'").finish().unwrap();
         assert_eq!(res, "");
         assert_eq!(s, "vim");
+
+        let (res, s) = super::string("'it''s'").finish().unwrap();
+        assert_eq!(res, "");
+        assert_eq!(s, "it's");
+
+        let (res, s) = super::string(r"'\X2\9B546CD5\X0\ \\ \S\D', 1")
+            .finish()
+            .unwrap();
+        assert_eq!(res, ", 1");
+        assert_eq!(s, r"魔法 \ Ä");
+
+        // Reverse solidus not starting a control directive is a literal character
+        let (res, s) = super::string(r"'C:\work\X2\9B5', 1").finish().unwrap();
+        assert_eq!(res, ", 1");
+        assert_eq!(s, r"C:\work\X2\9B5");
     }
 
     #[test]
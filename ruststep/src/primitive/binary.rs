@@ -0,0 +1,235 @@
+use serde::{de, ser, Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// `BINARY` type, a sequence of bits
+///
+/// Bits are indexed from the most significant bit of the first byte,
+/// i.e. `Binary::from(vec![0b1000_0000])` has `true` at index 0.
+///
+/// In exchange structure, a binary is encoded as a hexadecimal string
+/// in quotation marks like `"0ABC"`.
+/// The first digit is the number of unused bits (0-3) at the head of the first hexadecimal digit.
+/// [Display](fmt::Display) and [FromStr] use this form without the quotation marks.
+///
+/// ```
+/// use ruststep::primitive::Binary;
+///
+/// let b = Binary::from_bits([true, false, true]);
+/// assert_eq!(b.len(), 3);
+/// assert_eq!(b.get(0), Some(true));
+/// assert_eq!(b.get(1), Some(false));
+/// assert_eq!(b.get(3), None);
+///
+/// // "101" is padded as "0101" and one unused bit is recorded
+/// assert_eq!(b.to_string(), "15");
+/// assert_eq!("15".parse::<Binary>().unwrap(), b);
+///
+/// // From bytes
+/// let b = Binary::from(vec![0xAB, 0xCD]);
+/// assert_eq!(b.len(), 16);
+/// assert_eq!(b.to_string(), "0ABCD");
+///
+/// // Empty
+/// assert_eq!(Binary::default().to_string(), "0");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Binary {
+    /// Packed bits. Unused bits at the tail of the last byte are always zero.
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl Binary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_bits<I: IntoIterator<Item = bool>>(bits: I) -> Self {
+        let mut binary = Self::new();
+        for bit in bits {
+            binary.push(bit);
+        }
+        binary
+    }
+
+    /// Number of bits
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+        } else {
+            None
+        }
+    }
+
+    pub fn push(&mut self, bit: bool) {
+        if self.len % 8 == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |i| self.get(i).unwrap())
+    }
+
+    /// Packed bits, where unused bits of the last byte are zero
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl From<Vec<u8>> for Binary {
+    fn from(bytes: Vec<u8>) -> Self {
+        let len = bytes.len() * 8;
+        Binary { bytes, len }
+    }
+}
+
+impl From<&[u8]> for Binary {
+    fn from(bytes: &[u8]) -> Self {
+        bytes.to_vec().into()
+    }
+}
+
+impl fmt::Display for Binary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unused = (4 - self.len % 4) % 4;
+        write!(f, "{}", unused)?;
+        let mut digit = 0;
+        for (i, bit) in std::iter::repeat(false)
+            .take(unused)
+            .chain(self.iter())
+            .enumerate()
+        {
+            digit = (digit << 1) | bit as u32;
+            if i % 4 == 3 {
+                write!(f, "{:X}", digit)?;
+                digit = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error for invalid encoded form of [Binary]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid binary: {0}")]
+pub struct ParseBinaryError(String);
+
+impl FromStr for Binary {
+    type Err = ParseBinaryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseBinaryError(s.to_string());
+        let mut chars = s.chars();
+        let unused = match chars.next() {
+            Some(c @ '0'..='3') => c as usize - '0' as usize,
+            _ => return Err(err()),
+        };
+        let digits = chars.as_str();
+        if digits.is_empty() && unused != 0 {
+            return Err(err());
+        }
+        let mut binary = Binary::new();
+        for (i, c) in digits.chars().enumerate() {
+            let digit = match c {
+                '0'..='9' | 'A'..='F' => c.to_digit(16).unwrap(),
+                _ => return Err(err()),
+            };
+            let skip = if i == 0 { unused } else { 0 };
+            for shift in (0..(4 - skip)).rev() {
+                binary.push(digit & (1 << shift) != 0);
+            }
+        }
+        Ok(binary)
+    }
+}
+
+struct BinaryVisitor;
+
+impl<'de> de::Visitor<'de> for BinaryVisitor {
+    type Value = Binary;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "binary")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(v.into())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(v.into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Binary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(BinaryVisitor)
+    }
+}
+
+impl Serialize for Binary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_newtype_struct(crate::ast::ser::BINARY_TOKEN, &self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode() {
+        for (bits, encoded) in [
+            (vec![], "0"),
+            (vec![true], "31"),
+            (vec![true, true], "23"),
+            (vec![true, false, false, false], "08"),
+            (vec![true, false, false, false, true], "311"),
+        ] {
+            let b = Binary::from_bits(bits.clone());
+            assert_eq!(b.to_string(), encoded);
+            let parsed: Binary = encoded.parse().unwrap();
+            assert_eq!(parsed, b);
+            assert_eq!(parsed.iter().collect::<Vec<_>>(), bits);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for s in ["", "4", "1", "0abc", "0G"] {
+            assert!(s.parse::<Binary>().is_err(), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,331 @@
+//! Select generated schema from `FILE_SCHEMA` header
+//!
+//! `HEADER` section declares the schema of the `DATA` section in `FILE_SCHEMA`,
+//! e.g. `FILE_SCHEMA(('CONFIG_CONTROL_DESIGN'));`.
+//! An entry may have an object identifier after the schema name
+//! as defined in [ISO-10303-21 "8.2.4 file_schema"](https://www.iso.org/standard/63141.html):
+//!
+//! ```text
+//! FILE_SCHEMA(('AUTOMOTIVE_DESIGN { 1 0 10303 214 1 1 1 1 }'));
+//! ```
+//!
+//! [AnyTables] reads this header and loads the data into the `Tables`
+//! of the corresponding generated module.
+//! Only the modules enabled by cargo features are available:
+//!
+//! | Schema                  | Module                              | Feature |
+//! |:------------------------|:------------------------------------|:--------|
+//! | `EXPLICIT_DRAUGHTING`   | `ruststep::ap201::explicit_draughting`   | `ap201` |
+//! | `CONFIG_CONTROL_DESIGN` | `ruststep::ap203::config_control_design` | `ap203` |
+//!
+//! ```
+//! use ruststep::{error::Error, registry::AnyTables};
+//! use std::str::FromStr;
+//!
+//! let step = r#"
+//! ISO-10303-21;
+//! HEADER;
+//!   FILE_DESCRIPTION((''), '2;1');
+//!   FILE_NAME('', '', (''), (''), '', '', '');
+//!   FILE_SCHEMA(('AUTOMOTIVE_DESIGN { 1 0 10303 214 1 1 1 1 }'));
+//! ENDSEC;
+//! DATA;
+//! ENDSEC;
+//! END-ISO-10303-21;
+//! "#;
+//!
+//! // There is no generated module for AP214
+//! assert!(matches!(
+//!     AnyTables::from_str(step),
+//!     Err(Error::UnknownSchema(name)) if name == "AUTOMOTIVE_DESIGN"
+//! ));
+//! ```
+
+use crate::{
+    ast::{ser::DataSectionBuilder, DataSection, EntityInstance, Exchange},
+    error::*,
+    header::Header,
+    tables::{NameTable, ToDataSection},
+};
+use std::{fmt, str::FromStr};
+
+#[cfg(any(feature = "ap201", feature = "ap203"))]
+use crate::tables::TableInit;
+
+/// Schemas which ruststep has generated modules for, and the cargo features enabling them
+const SCHEMAS: &[(&str, &str)] = &[
+    ("EXPLICIT_DRAUGHTING", "ap201"),
+    ("CONFIG_CONTROL_DESIGN", "ap203"),
+];
+
+/// Schema name in `FILE_SCHEMA` with optional object identifier
+///
+/// ```
+/// use ruststep::registry::SchemaName;
+/// use std::str::FromStr;
+///
+/// let name = SchemaName::from_str("AUTOMOTIVE_DESIGN { 1 0 10303 214 1 1 1 1 }").unwrap();
+/// assert_eq!(name.name, "AUTOMOTIVE_DESIGN");
+/// assert_eq!(name.object_identifier, ["1", "0", "10303", "214", "1", "1", "1", "1"]);
+/// assert_eq!(name.to_string(), "AUTOMOTIVE_DESIGN { 1 0 10303 214 1 1 1 1 }");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaName {
+    /// Schema name in upper case
+    pub name: String,
+    /// Components of the object identifier, empty if not specified
+    pub object_identifier: Vec<String>,
+}
+
+impl FromStr for SchemaName {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self> {
+        let (name, object_identifier) = match input.find('{') {
+            Some(start) => {
+                let oid = input[start + 1..]
+                    .trim_end()
+                    .strip_suffix('}')
+                    .ok_or_else(|| Error::UnknownSchema(input.to_string()))?;
+                (
+                    &input[..start],
+                    oid.split_whitespace().map(String::from).collect(),
+                )
+            }
+            None => (input, Vec::new()),
+        };
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(Error::UnknownSchema(input.to_string()));
+        }
+        Ok(SchemaName {
+            name: name.to_uppercase(),
+            object_identifier,
+        })
+    }
+}
+
+impl fmt::Display for SchemaName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.object_identifier.is_empty() {
+            write!(f, " {{ {} }}", self.object_identifier.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Tables of a generated schema selected at runtime
+///
+/// Variants exist only for the schemas enabled by cargo features.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyTables {
+    #[cfg(feature = "ap201")]
+    Ap201(crate::ap201::explicit_draughting::Tables),
+    #[cfg(feature = "ap203")]
+    Ap203(crate::ap203::config_control_design::Tables),
+}
+
+impl AnyTables {
+    /// Empty tables for the schema
+    ///
+    /// Returns [Error::SchemaNotEnabled] if ruststep has the module but its feature is disabled,
+    /// and [Error::UnknownSchema] if ruststep does not have the module.
+    pub fn new(schema: &SchemaName) -> Result<Self> {
+        match schema.name.as_str() {
+            #[cfg(feature = "ap201")]
+            "EXPLICIT_DRAUGHTING" => Ok(AnyTables::Ap201(Default::default())),
+            #[cfg(feature = "ap203")]
+            "CONFIG_CONTROL_DESIGN" => Ok(AnyTables::Ap203(Default::default())),
+            name => match SCHEMAS.iter().find(|(schema, _)| *schema == name) {
+                Some((_, feature)) => Err(Error::SchemaNotEnabled {
+                    schema: name.to_string(),
+                    feature,
+                }),
+                None => Err(Error::UnknownSchema(name.to_string())),
+            },
+        }
+    }
+
+    /// Empty tables for the first schema in `FILE_SCHEMA` available in ruststep
+    ///
+    /// When no schema is available, the error for the first one is returned.
+    pub fn from_header(header: &Header) -> Result<Self> {
+        let mut first_err = None;
+        for schema in &header.file_schema.schema {
+            match SchemaName::from_str(schema).and_then(|name| AnyTables::new(&name)) {
+                Ok(tables) => return Ok(tables),
+                Err(e) => {
+                    first_err.get_or_insert(e);
+                }
+            }
+        }
+        Err(first_err.unwrap_or_else(|| Error::UnknownSchema(String::new())))
+    }
+
+    /// Load all `DATA` sections into the tables of the schema declared in `HEADER`
+    ///
+    /// Value instance names and constant names are resolved by [NameTable]
+    /// as [TableInit::from_exchange](crate::tables::TableInit::from_exchange).
+    pub fn from_exchange(exchange: &Exchange) -> Result<Self> {
+        let header = Header::from_records(&exchange.header)?;
+        let mut tables = AnyTables::from_header(&header)?;
+        let names = NameTable::from_exchange(exchange);
+        for instance in exchange.data.iter().flat_map(|section| &section.entities) {
+            tables.append_instance(names.resolve_instance(instance)?.as_ref())?;
+        }
+        Ok(tables)
+    }
+
+    /// Name of the schema in upper case, e.g. `CONFIG_CONTROL_DESIGN`
+    pub fn schema(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "ap201")]
+            AnyTables::Ap201(_) => "EXPLICIT_DRAUGHTING",
+            #[cfg(feature = "ap203")]
+            AnyTables::Ap203(_) => "CONFIG_CONTROL_DESIGN",
+        }
+    }
+
+    /// Append a `DATA` section, see [TableInit::append_data_section](crate::tables::TableInit::append_data_section)
+    ///
+    /// Unlike using generated `Tables` directly,
+    /// [Error::UnknownEntityName] reports the schema name.
+    #[cfg_attr(
+        not(any(feature = "ap201", feature = "ap203")),
+        allow(unused_variables)
+    )]
+    pub fn append_data_section(&mut self, section: &DataSection) -> Result<()> {
+        let schema = self.schema();
+        match *self {
+            #[cfg(feature = "ap201")]
+            AnyTables::Ap201(ref mut tables) => {
+                with_schema(tables.append_data_section(section), schema)
+            }
+            #[cfg(feature = "ap203")]
+            AnyTables::Ap203(ref mut tables) => {
+                with_schema(tables.append_data_section(section), schema)
+            }
+        }
+    }
+
+    /// Append an entity instance, see [TableInit::append_instance](crate::tables::TableInit::append_instance)
+    #[cfg_attr(
+        not(any(feature = "ap201", feature = "ap203")),
+        allow(unused_variables)
+    )]
+    pub fn append_instance(&mut self, instance: &EntityInstance) -> Result<()> {
+        let schema = self.schema();
+        match *self {
+            #[cfg(feature = "ap201")]
+            AnyTables::Ap201(ref mut tables) => {
+                with_schema(tables.append_instance(instance), schema)
+            }
+            #[cfg(feature = "ap203")]
+            AnyTables::Ap203(ref mut tables) => {
+                with_schema(tables.append_instance(instance), schema)
+            }
+        }
+    }
+}
+
+/// Fill the schema name of [Error::UnknownEntityName], which generated `Tables` leaves empty
+#[cfg(any(feature = "ap201", feature = "ap203"))]
+fn with_schema(result: Result<()>, schema: &str) -> Result<()> {
+    result.map_err(|e| match e {
+        Error::UnknownEntityName { entity_name, .. } => Error::UnknownEntityName {
+            entity_name,
+            schema: schema.to_string(),
+        },
+        e => e,
+    })
+}
+
+impl ToDataSection for AnyTables {
+    #[cfg_attr(
+        not(any(feature = "ap201", feature = "ap203")),
+        allow(unused_variables)
+    )]
+    fn append_to_builder(&self, builder: &mut DataSectionBuilder) -> Result<()> {
+        match *self {
+            #[cfg(feature = "ap201")]
+            AnyTables::Ap201(ref tables) => tables.append_to_builder(builder),
+            #[cfg(feature = "ap203")]
+            AnyTables::Ap203(ref tables) => tables.append_to_builder(builder),
+        }
+    }
+}
+
+impl FromStr for AnyTables {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self> {
+        AnyTables::from_exchange(&Exchange::from_str(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_name() {
+        let name = SchemaName::from_str("config_control_design").unwrap();
+        assert_eq!(name.name, "CONFIG_CONTROL_DESIGN");
+        assert!(name.object_identifier.is_empty());
+
+        let name = SchemaName::from_str(" AUTOMOTIVE_DESIGN{1 0 10303 214 1 1 1 1} ").unwrap();
+        assert_eq!(name.name, "AUTOMOTIVE_DESIGN");
+        assert_eq!(name.object_identifier.len(), 8);
+
+        assert!(SchemaName::from_str("").is_err());
+        assert!(SchemaName::from_str("{ 1 0 10303 }").is_err());
+        assert!(SchemaName::from_str("AUTOMOTIVE_DESIGN { 1 0").is_err());
+    }
+
+    #[test]
+    fn unknown_schema() {
+        let name = SchemaName::from_str("AUTOMOTIVE_DESIGN { 1 0 10303 214 1 1 1 1 }").unwrap();
+        assert!(matches!(
+            AnyTables::new(&name),
+            Err(Error::UnknownSchema(name)) if name == "AUTOMOTIVE_DESIGN"
+        ));
+    }
+
+    #[cfg(not(feature = "ap203"))]
+    #[test]
+    fn schema_not_enabled() {
+        let name = SchemaName::from_str("CONFIG_CONTROL_DESIGN").unwrap();
+        assert!(matches!(
+            AnyTables::new(&name),
+            Err(Error::SchemaNotEnabled {
+                feature: "ap203",
+                ..
+            })
+        ));
+    }
+
+    #[cfg(feature = "ap203")]
+    #[test]
+    fn config_control_design() {
+        let step = r#"
+        ISO-10303-21;
+        HEADER;
+          FILE_DESCRIPTION((''), '2;1');
+          FILE_NAME('', '', (''), (''), '', '', '');
+          FILE_SCHEMA(('CONFIG_CONTROL_DESIGN'));
+        ENDSEC;
+        DATA;
+          #1 = APPROVAL_STATUS(LABEL(('approved')));
+          #2 = UNKNOWN_ENTITY(1.0);
+        ENDSEC;
+        END-ISO-10303-21;
+        "#;
+        assert!(matches!(
+            AnyTables::from_str(step),
+            Err(Error::UnknownEntityName { entity_name, schema })
+                if entity_name == "UNKNOWN_ENTITY" && schema == "CONFIG_CONTROL_DESIGN"
+        ));
+
+        let tables = AnyTables::from_str(&step.replace("#2 = UNKNOWN_ENTITY(1.0);", "")).unwrap();
+        assert_eq!(tables.schema(), "CONFIG_CONTROL_DESIGN");
+    }
+}
@@ -101,25 +101,48 @@
 //! These are automated by [ruststep_derive::Holder] proc-macro.
 //!
 
-use crate::{ast::*, error::*};
+use crate::{
+    ast::{ser::DataSectionBuilder, *},
+    error::*,
+};
 use serde::{
     de::{self, IntoDeserializer, VariantAccess},
-    Deserialize,
+    ser, Deserialize, Serialize,
+};
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    marker::PhantomData,
 };
-use std::{collections::HashMap, fmt, marker::PhantomData};
 
 /// Trait for resolving a reference through entity id
 pub trait IntoOwned: Clone + 'static {
     type Owned;
     type Table;
-    fn into_owned(self, table: &Self::Table) -> Result<Self::Owned>;
+
+    /// Convert into owned value, where the references are resolved in `resolution`
+    fn into_owned_in(self, table: &Self::Table, resolution: &mut Resolution)
+        -> Result<Self::Owned>;
+
+    /// Convert into owned value in a new [Resolution]
+    fn into_owned(self, table: &Self::Table) -> Result<Self::Owned> {
+        self.into_owned_in(table, &mut Resolution::new())
+    }
 }
 
 impl<T: IntoOwned> IntoOwned for Vec<T> {
     type Owned = Vec<T::Owned>;
     type Table = T::Table;
-    fn into_owned(self, table: &Self::Table) -> Result<Self::Owned> {
-        self.into_iter().map(|x| x.into_owned(table)).collect()
+    fn into_owned_in(
+        self,
+        table: &Self::Table,
+        resolution: &mut Resolution,
+    ) -> Result<Self::Owned> {
+        self.into_iter()
+            .map(|x| x.into_owned_in(table, resolution))
+            .collect()
     }
 }
 
@@ -127,6 +150,11 @@ impl<T: IntoOwned> IntoOwned for Vec<T> {
 pub trait Holder: IntoOwned {
     fn name() -> &'static str;
     fn attr_len() -> usize;
+
+    /// Names of direct supertypes, which are embedded as the first fields of the entity
+    fn supertypes() -> &'static [&'static str] {
+        &[]
+    }
 }
 
 pub trait WithVisitor {
@@ -136,17 +164,54 @@ pub trait WithVisitor {
 
 /// Trait for tables which pulls an entity (`T`) from an entity id (`u64`)
 pub trait EntityTable<T: Holder<Table = Self>> {
-    /// Get owned entity from table
-    fn get_owned(&self, entity_id: u64) -> Result<T::Owned>;
+    /// Get owned entity from table, where the references are resolved in `resolution`
+    fn get_owned_in(&self, entity_id: u64, resolution: &mut Resolution) -> Result<T::Owned>;
 
-    /// Get owned entities as an iterator
+    /// Get owned entity from table in a new [Resolution]
+    fn get_owned(&self, entity_id: u64) -> Result<T::Owned> {
+        self.get_owned_in(entity_id, &mut Resolution::new())
+    }
+
+    /// Get owned entities as an iterator in ascending order of entity ids
     fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = Result<T::Owned>> + 'table>;
+
+    /// Get pairs of entity id and owned entity as an iterator in ascending order of entity ids
+    fn owned_iter_with_id<'table>(
+        &'table self,
+    ) -> Box<dyn Iterator<Item = Result<(u64, T::Owned)>> + 'table>;
 }
 
 /// Create Table from [DataSection]
 pub trait TableInit: Default {
     fn append_data_section(&mut self, section: &DataSection) -> Result<()>;
 
+    /// Append an entity instance
+    ///
+    /// The default implementation appends a DATA section of a copy of the instance,
+    /// and `#[derive(TableInit)]` overrides it to deserialize the records directly.
+    fn append_instance(&mut self, instance: &EntityInstance) -> Result<()> {
+        self.append_data_section(&DataSection {
+            meta: Vec::new(),
+            entities: vec![instance.clone()],
+        })
+    }
+
+    /// Append a [borrowed::DataSection] without copying keywords and strings into owned AST
+    ///
+    /// The default implementation converts it into owned [DataSection],
+    /// and `#[derive(TableInit)]` overrides it to deserialize records directly.
+    fn append_borrowed_data_section(&mut self, section: &borrowed::DataSection) -> Result<()> {
+        self.append_data_section(&section.into())
+    }
+
+    /// Append a [borrowed::EntityInstance]
+    ///
+    /// The default implementation converts it into owned [EntityInstance],
+    /// and `#[derive(TableInit)]` overrides it to deserialize records directly.
+    fn append_borrowed_instance(&mut self, instance: &borrowed::EntityInstance) -> Result<()> {
+        self.append_instance(&instance.into())
+    }
+
     fn from_data_section(section: &DataSection) -> Result<Self> {
         let mut table = Self::default();
         table.append_data_section(section)?;
@@ -160,40 +225,566 @@ pub trait TableInit: Default {
         }
         Ok(table)
     }
+
+    fn from_borrowed_data_section(section: &borrowed::DataSection) -> Result<Self> {
+        let mut table = Self::default();
+        table.append_borrowed_data_section(section)?;
+        Ok(table)
+    }
+
+    /// Append a [DataSection] resolving value instance names and constant names by [NameTable]
+    ///
+    /// Only the instances referring the names defined in `names` are copied to be resolved.
+    fn append_data_section_with_names(
+        &mut self,
+        section: &DataSection,
+        names: &NameTable,
+    ) -> Result<()> {
+        for instance in &section.entities {
+            self.append_instance(names.resolve_instance(instance)?.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Create tables from all DATA sections,
+    /// where value instance names and constant names are resolved by [NameTable]
+    fn from_exchange(exchange: &Exchange) -> Result<Self> {
+        Self::from_exchange_filtered(exchange, &LoadFilter::All)
+    }
+
+    /// Append the entity instances accepted by the [LoadFilter]
+    ///
+    /// Other instances are skipped before deserialization.
+    /// References to them are kept as entity ids in [PlaceHolder::Ref],
+    /// and [EntityTable::get_owned] for them returns [Error::UnknownEntity].
+    fn append_data_section_filtered(
+        &mut self,
+        section: &DataSection,
+        filter: &LoadFilter,
+    ) -> Result<()> {
+        for instance in &section.entities {
+            if filter.accepts_instance(instance) {
+                self.append_instance(instance)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Append the entity instances accepted by the [LoadFilter] as [TableInit::append_data_section_filtered]
+    fn append_borrowed_data_section_filtered(
+        &mut self,
+        section: &borrowed::DataSection,
+        filter: &LoadFilter,
+    ) -> Result<()> {
+        for instance in &section.entities {
+            if filter.accepts_borrowed_instance(instance) {
+                self.append_borrowed_instance(instance)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn from_borrowed_data_section_filtered(
+        section: &borrowed::DataSection,
+        filter: &LoadFilter,
+    ) -> Result<Self> {
+        let mut table = Self::default();
+        table.append_borrowed_data_section_filtered(section, filter)?;
+        Ok(table)
+    }
+
+    /// Create tables from all DATA sections as [TableInit::from_exchange],
+    /// loading only the entity instances accepted by the [LoadFilter]
+    fn from_exchange_filtered(exchange: &Exchange, filter: &LoadFilter) -> Result<Self> {
+        let names = NameTable::from_exchange(exchange);
+        let mut table = Self::default();
+        for instance in exchange.data.iter().flat_map(|section| &section.entities) {
+            if filter.accepts_instance(instance) {
+                table.append_instance(names.resolve_instance(instance)?.as_ref())?;
+            }
+        }
+        Ok(table)
+    }
+}
+
+/// Filter of entity instances loaded by [TableInit], e.g. [TableInit::from_exchange_filtered]
+///
+/// Instances are filtered by their keywords, i.e. entity names in upper case like `PRODUCT`.
+/// Since each instance is stored in the table of its own entity,
+/// the names of subtypes have to be listed to load them.
+/// A complex entity instance is loaded if any of its partial records is accepted.
+///
+/// ```
+/// use ruststep::tables::LoadFilter;
+///
+/// let filter = LoadFilter::allow(["product", "PRODUCT_DEFINITION"]);
+/// assert!(filter.accepts("PRODUCT"));
+/// assert!(filter.accepts("PRODUCT_DEFINITION"));
+/// assert!(!filter.accepts("CARTESIAN_POINT"));
+///
+/// let filter = LoadFilter::predicate(|keyword| keyword.starts_with("PRODUCT"));
+/// assert!(filter.accepts("PRODUCT_DEFINITION_FORMATION"));
+/// assert!(!filter.accepts("CARTESIAN_POINT"));
+/// ```
+pub enum LoadFilter {
+    /// Load all entity instances
+    All,
+    /// Load entity instances whose keyword is in the list
+    Allow(HashSet<String>),
+    /// Load entity instances whose keyword satisfies the predicate
+    Predicate(Box<dyn Fn(&str) -> bool + Send + Sync>),
+}
+
+impl Default for LoadFilter {
+    fn default() -> Self {
+        LoadFilter::All
+    }
+}
+
+impl fmt::Debug for LoadFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadFilter::All => write!(f, "All"),
+            LoadFilter::Allow(names) => f.debug_tuple("Allow").field(names).finish(),
+            LoadFilter::Predicate(_) => write!(f, "Predicate(..)"),
+        }
+    }
+}
+
+impl LoadFilter {
+    /// Allow list of entity names, which are compared in upper case
+    pub fn allow<I>(names: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        LoadFilter::Allow(
+            names
+                .into_iter()
+                .map(|name| name.as_ref().to_uppercase())
+                .collect(),
+        )
+    }
+
+    /// Predicate on the keyword of each record
+    pub fn predicate(f: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        LoadFilter::Predicate(Box::new(f))
+    }
+
+    /// Check the keyword of a record
+    pub fn accepts(&self, keyword: &str) -> bool {
+        match self {
+            LoadFilter::All => true,
+            LoadFilter::Allow(names) => names.contains(keyword),
+            LoadFilter::Predicate(f) => f(keyword),
+        }
+    }
+
+    pub fn accepts_instance(&self, instance: &EntityInstance) -> bool {
+        match instance {
+            EntityInstance::Simple { record, .. } => self.accepts(&record.name),
+            EntityInstance::Complex { subsuper, .. } => {
+                subsuper.0.iter().any(|record| self.accepts(&record.name))
+            }
+        }
+    }
+
+    pub fn accepts_borrowed_instance(&self, instance: &borrowed::EntityInstance) -> bool {
+        match instance {
+            borrowed::EntityInstance::Simple { record, .. } => self.accepts(record.name),
+            borrowed::EntityInstance::Complex { subsuper, .. } => {
+                subsuper.0.iter().any(|record| self.accepts(record.name))
+            }
+        }
+    }
+}
+
+/// Names defined in ANCHOR and REFERENCE sections
+///
+/// DATA sections may refer to the names which are not entity instances in them:
+///
+/// - Value instance names like `@12`, and entity instance names like `#12` not defined in DATA sections,
+///   are defined in REFERENCE section. If the resource is a fragment `<#name>` in the same file,
+///   it refers the anchor `<name>`.
+/// - Constant names like `#ORIGIN` and `@PI` refer the anchors `<ORIGIN>` and `<PI>`.
+///
+/// [NameTable::resolve] replaces these names by the items of the anchors,
+/// e.g. `#12` for `<ORIGIN> = #12;` or `3.14` for `<PI> = 3.14;`.
+/// Names referring other files are kept as they are.
+///
+/// ```
+/// use ruststep::{ast::*, tables::NameTable};
+/// use std::str::FromStr;
+///
+/// let ex = Exchange::from_str(r#"
+/// ISO-10303-21;
+/// HEADER;
+///   FILE_DESCRIPTION((''), '2;1');
+///   FILE_NAME('', '', (''), (''), '', '', '');
+///   FILE_SCHEMA(('EXAMPLE'));
+/// ENDSEC;
+/// ANCHOR;
+///   <ORIGIN> = #1;
+///   <PI> = 3.14;
+/// ENDSEC;
+/// REFERENCE;
+///   @2 = <#PI>;
+///   #3 = <other.step#SHAPE>;
+/// ENDSEC;
+/// DATA;
+///   #1 = CPT(0.0, 0.0);
+///   #4 = CIRCLE(#ORIGIN, @2, @PI, #3);
+/// ENDSEC;
+/// END-ISO-10303-21;
+/// "#).unwrap();
+///
+/// let names = NameTable::from_exchange(&ex);
+/// let mut data = ex.data[0].clone();
+/// names.resolve_data_section(&mut data).unwrap();
+/// assert_eq!(
+///     data.entities[1],
+///     EntityInstance::from_str("#4 = CIRCLE(#1, 3.14, 3.14, #3);").unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NameTable {
+    anchors: HashMap<String, AnchorItem>,
+    references: HashMap<Name, URI>,
+}
+
+impl NameTable {
+    pub fn new(anchors: &[Anchor], references: &[ReferenceEntry]) -> Self {
+        NameTable {
+            anchors: anchors
+                .iter()
+                .map(|anchor| (anchor.name.clone(), anchor.item.clone()))
+                .collect(),
+            references: references
+                .iter()
+                .map(|entry| (entry.name.clone(), entry.resource.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn from_exchange(exchange: &Exchange) -> Self {
+        Self::new(&exchange.anchor, &exchange.reference)
+    }
+
+    /// No anchors and references are defined
+    pub fn is_empty(&self) -> bool {
+        self.anchors.is_empty() && self.references.is_empty()
+    }
+
+    /// Resolve a name into a parameter
+    ///
+    /// Names which are not defined in ANCHOR and REFERENCE sections,
+    /// or refer other files, are returned as [Parameter::Ref].
+    /// Returns [Error::UnresolvedName] if anchors refer each other cyclically.
+    pub fn resolve(&self, name: &Name) -> Result<Parameter> {
+        self.resolve_name(name, &mut Vec::new())
+    }
+
+    /// Resolve names in a parameter recursively
+    pub fn resolve_parameter(&self, parameter: &mut Parameter) -> Result<()> {
+        match parameter {
+            Parameter::Typed { parameter, .. } => self.resolve_parameter(parameter),
+            Parameter::List(list) => list.iter_mut().try_for_each(|p| self.resolve_parameter(p)),
+            Parameter::Ref(name) => {
+                *parameter = self.resolve(name)?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Resolve names in an entity instance, which is copied only if it refers names defined in this table
+    pub fn resolve_instance<'a>(
+        &self,
+        instance: &'a EntityInstance,
+    ) -> Result<Cow<'a, EntityInstance>> {
+        let refers = instance
+            .records()
+            .iter()
+            .any(|record| self.refers(&record.parameter));
+        if !refers {
+            return Ok(Cow::Borrowed(instance));
+        }
+        let mut instance = instance.clone();
+        for record in instance.records_mut() {
+            self.resolve_parameter(&mut record.parameter)?;
+        }
+        Ok(Cow::Owned(instance))
+    }
+
+    /// Resolve names in all entity instances
+    pub fn resolve_data_section(&self, section: &mut DataSection) -> Result<()> {
+        for instance in &mut section.entities {
+            for record in instance.records_mut() {
+                self.resolve_parameter(&mut record.parameter)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The parameter refers a name which [NameTable::resolve] rewrites
+    fn refers(&self, parameter: &Parameter) -> bool {
+        match parameter {
+            Parameter::Typed { parameter, .. } => self.refers(parameter),
+            Parameter::List(list) => list.iter().any(|p| self.refers(p)),
+            Parameter::Ref(name) => self
+                .anchor(name)
+                .map_or(false, |anchor| self.anchors.contains_key(anchor)),
+            _ => false,
+        }
+    }
+
+    /// Resource of a name defined in REFERENCE section
+    pub(crate) fn reference(&self, name: &Name) -> Option<&URI> {
+        self.references.get(name)
+    }
+
+    /// Item of an anchor defined in ANCHOR section
+    pub(crate) fn anchor_item(&self, anchor: &str) -> Option<&AnchorItem> {
+        self.anchors.get(anchor)
+    }
+
+    /// Name of the anchor which `name` refers in this file
+    fn anchor<'a>(&'a self, name: &'a Name) -> Option<&'a str> {
+        match name {
+            Name::Entity(_) | Name::Value(_) => match self.references.get(name) {
+                Some(URI(uri)) => uri.strip_prefix('#'),
+                None => None,
+            },
+            Name::ConstantEntity(constant) | Name::ConstantValue(constant) => {
+                Some(constant.as_str())
+            }
+        }
+    }
+
+    fn resolve_name(&self, name: &Name, anchors: &mut Vec<String>) -> Result<Parameter> {
+        let item = match self
+            .anchor(name)
+            .and_then(|anchor| self.anchors.get_key_value(anchor))
+        {
+            Some((anchor, item)) => {
+                if anchors.contains(anchor) {
+                    return Err(Error::UnresolvedName(name.to_string()));
+                }
+                anchors.push(anchor.clone());
+                let parameter = item.to_parameter(&mut |name| self.resolve_name(name, anchors));
+                anchors.pop();
+                parameter?
+            }
+            None => Parameter::Ref(name.clone()),
+        };
+        Ok(item)
+    }
+}
+
+/// Serialize tables into [DataSection], i.e. the reverse of [TableInit]
+///
+/// `#[derive(TableInit)]` also implements this trait.
+/// Entity ids in the tables are kept,
+/// and entities owned by other entities get new ids.
+pub trait ToDataSection {
+    /// Insert all entities into `builder` with their entity ids
+    fn append_to_builder(&self, builder: &mut DataSectionBuilder) -> Result<()>;
+
+    fn to_data_section(&self) -> Result<DataSection> {
+        let mut builder = DataSectionBuilder::new();
+        self.append_to_builder(&mut builder)?;
+        Ok(builder.build())
+    }
 }
 
+/// Look up the holder of `entity_id`, and convert it into owned value in a new [Resolution]
 pub fn get_owned<T, Table>(table: &Table, map: &HashMap<u64, T>, entity_id: u64) -> Result<T::Owned>
 where
     T: Holder<Table = Table>,
+    T::Owned: Clone + 'static,
+    Table: EntityTable<T>,
+{
+    get_owned_in(table, map, entity_id, &mut Resolution::new())
+}
+
+/// Look up the holder of `entity_id`, and convert it into owned value in `resolution`
+///
+/// This is the helper function to implement [EntityTable::get_owned_in].
+/// See [Resolution] for how the references are resolved.
+pub fn get_owned_in<T, Table>(
+    table: &Table,
+    map: &HashMap<u64, T>,
+    entity_id: u64,
+    resolution: &mut Resolution,
+) -> Result<T::Owned>
+where
+    T: Holder<Table = Table>,
+    T::Owned: Clone + 'static,
     Table: EntityTable<T>,
 {
     match map.get(&entity_id) {
-        Some(holder) => holder.clone().into_owned(table),
+        Some(holder) => resolution.resolve(table, entity_id, holder),
         None => Err(Error::UnknownEntity(entity_id)),
     }
 }
 
+/// Helper function to implement [EntityTable::owned_iter] as [get_owned]
 pub fn owned_iter<'table, T, Table>(
     table: &'table Table,
     map: &'table HashMap<u64, T>,
 ) -> Box<dyn Iterator<Item = Result<T::Owned>> + 'table>
 where
     T: Holder<Table = Table>,
+    T::Owned: Clone + 'static,
+    Table: EntityTable<T>,
+{
+    Box::new(owned_iter_with_id(table, map).map(|owned| owned.map(|(_id, owned)| owned)))
+}
+
+/// Helper function to implement [EntityTable::owned_iter_with_id] as [get_owned]
+pub fn owned_iter_with_id<'table, T, Table>(
+    table: &'table Table,
+    map: &'table HashMap<u64, T>,
+) -> Box<dyn Iterator<Item = Result<(u64, T::Owned)>> + 'table>
+where
+    T: Holder<Table = Table>,
+    T::Owned: Clone + 'static,
     Table: EntityTable<T>,
 {
+    let mut ids: Vec<u64> = map.keys().cloned().collect();
+    ids.sort_unstable();
+    let mut resolution = Resolution::new();
     Box::new(
-        map.values()
-            .cloned()
-            .map(move |value| value.into_owned(table)),
+        ids.into_iter()
+            .map(move |id| Ok((id, resolution.resolve(table, id, &map[&id])?))),
     )
 }
 
+/// Merge iterators of [EntityTable::owned_iter_with_id] keeping ascending order of entity ids
+///
+/// This is the helper function to implement [EntityTable] for select types,
+/// where each iterator is of a variant. Errors are yielded as soon as they are found.
+///
+/// An entity id yielded by several iterators is a complex entity instance
+/// stored in the tables of its components, e.g. `employee` and `student` for `employee & student`.
+/// It is yielded only once as the value looked up again by `get_owned`.
+pub fn merge_with_id<'table, T: 'table>(
+    iters: Vec<IterWithId<'table, T>>,
+    get_owned: impl Fn(u64) -> Result<T> + 'table,
+) -> IterWithId<'table, T> {
+    fn key<T>(owned: &Result<(u64, T)>) -> u64 {
+        owned.as_ref().map(|(id, _)| *id).unwrap_or(0)
+    }
+    let mut merged = itertools::kmerge_by(iters, |a: &Result<(u64, T)>, b: &Result<(u64, T)>| {
+        key(a) < key(b)
+    })
+    .peekable();
+    Box::new(std::iter::from_fn(move || {
+        let owned = merged.next()?;
+        let id = match &owned {
+            Ok((id, _)) => *id,
+            Err(_) => return Some(owned),
+        };
+        let mut complex = false;
+        while matches!(merged.peek(), Some(Ok((next, _))) if *next == id) {
+            merged.next();
+            complex = true;
+        }
+        if complex {
+            Some(get_owned(id).map(|owned| (id, owned)))
+        } else {
+            Some(owned)
+        }
+    }))
+}
+
+type IterWithId<'table, T> = Box<dyn Iterator<Item = Result<(u64, T)>> + 'table>;
+
+/// Context of converting holders into owned values
+///
+/// This keeps the entity ids being converted to detect cyclic references,
+/// i.e. [IntoOwned::into_owned_in] of an entity reaching the same entity again
+/// returns [Error::CyclicOwnership] with the chain of entity ids instead of overflowing the stack.
+///
+/// [Resolution::with_memo] also keeps the converted entities,
+/// i.e. an entity referred many times is converted only once and cloned for the other referrers.
+/// For example, calling `EntityTable::<ProductHolder>::get_owned_in(&table, id, &mut resolution)`
+/// for many products with one resolution converts the shared contexts and units once.
+///
+/// A resolution must be used with a single table, since the memo is keyed by the holder type and the entity id.
+#[derive(Default)]
+pub struct Resolution {
+    /// Entity ids being converted, outermost first
+    stack: Vec<u64>,
+    memo: Option<HashMap<(TypeId, u64), Box<dyn Any>>>,
+}
+
+impl fmt::Debug for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Resolution")
+            .field("stack", &self.stack)
+            .field("memo", &self.memo.as_ref().map(HashMap::len))
+            .finish()
+    }
+}
+
+impl Resolution {
+    /// Resolution detecting cyclic references without memo
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolution detecting cyclic references and keeping converted entities
+    pub fn with_memo() -> Self {
+        Resolution {
+            stack: Vec::new(),
+            memo: Some(HashMap::new()),
+        }
+    }
+
+    fn resolve<T>(&mut self, table: &T::Table, entity_id: u64, holder: &T) -> Result<T::Owned>
+    where
+        T: Holder,
+        T::Owned: Clone + 'static,
+    {
+        if self.stack.contains(&entity_id) {
+            let mut chain = self.stack.clone();
+            chain.push(entity_id);
+            return Err(Error::CyclicOwnership(chain));
+        }
+        let key = (TypeId::of::<T>(), entity_id);
+        if let Some(owned) = self
+            .memo
+            .as_ref()
+            .and_then(|memo| memo.get(&key))
+            .and_then(|owned| owned.downcast_ref::<T::Owned>())
+        {
+            return Ok(owned.clone());
+        }
+
+        self.stack.push(entity_id);
+        let owned = holder.clone().into_owned_in(table, self);
+        self.stack.pop();
+        let owned = owned?;
+        if let Some(memo) = &mut self.memo {
+            memo.insert(key, Box::new(owned.clone()));
+        }
+        Ok(owned)
+    }
+}
+
 /// Helper function to implement TableInit trait
-pub fn insert_record<'de, T: de::Deserialize<'de>>(
+///
+/// `record` is either of [Record] or [borrowed::Record].
+pub fn insert_record<'de, T, D>(
     table: &mut HashMap<u64, T>,
     id: u64,
-    record: &Record,
-) -> crate::error::Result<()> {
+    record: D,
+) -> crate::error::Result<()>
+where
+    T: de::Deserialize<'de>,
+    D: de::Deserializer<'de, Error = Error>,
+{
     if let Some(_) = table.insert(id, de::Deserialize::deserialize(record)?) {
         Err(Error::DuplicatedEntity(id))
     } else {
@@ -201,6 +792,183 @@ pub fn insert_record<'de, T: de::Deserialize<'de>>(
     }
 }
 
+/// Compose records of the leaf entities of a complex entity instance
+///
+/// A complex entity instance in the external mapping, e.g.
+///
+/// ```text
+/// #5 = (EMPLOYEE(15) PERSON('Nizika Iziti') STUDENT('Simokitazawa'));
+/// ```
+///
+/// consists of the partial records of all its entities and their supertypes.
+/// The entities which are not a supertype of others, i.e. `EMPLOYEE` and `STUDENT` in this case,
+/// form a partial complex entity of the supertype `PERSON`.
+/// This returns the records of these entities,
+/// and the partial records of their supertypes are embedded as inline parameters
+/// as same as the internal mapping:
+///
+/// ```
+/// use ruststep::{ast::*, tables::compose_complex};
+/// use std::str::FromStr;
+///
+/// let subsuper = SubSuperRecord::from_str(
+///     "(EMPLOYEE(15) PERSON('Nizika Iziti') STUDENT('Simokitazawa'))"
+/// ).unwrap();
+/// let records = compose_complex(&subsuper, |name| match name {
+///     "PERSON" => Some(&[]),
+///     "EMPLOYEE" | "STUDENT" => Some(&["PERSON"]),
+///     _ => None,
+/// }).unwrap();
+/// assert_eq!(records, vec![
+///     Record::from_str("EMPLOYEE(PERSON(('Nizika Iziti')), 15)").unwrap(),
+///     Record::from_str("STUDENT(PERSON(('Nizika Iziti')), 'Simokitazawa')").unwrap(),
+/// ]);
+/// ```
+///
+/// `supertypes` returns the names of direct supertypes of each entity,
+/// or `None` if the entity does not exist in the schema.
+pub fn compose_complex<'a>(
+    subsuper: &SubSuperRecord,
+    supertypes: impl Fn(&str) -> Option<&'a [&'a str]>,
+) -> Result<Vec<Record>> {
+    let mut parts: HashMap<&str, (&'a [&'a str], &[Parameter])> = HashMap::new();
+    for record in subsuper {
+        let sup = supertypes(&record.name).ok_or_else(|| Error::UnknownEntityName {
+            entity_name: record.name.clone(),
+            schema: "".to_string(),
+        })?;
+        let parameters = match &record.parameter {
+            Parameter::List(parameters) => parameters.as_slice(),
+            parameter => std::slice::from_ref(parameter),
+        };
+        if parts.insert(&record.name, (sup, parameters)).is_some() {
+            return Err(Error::InvalidComplexEntity(format!(
+                "partial record '{}' is duplicated",
+                record.name
+            )));
+        }
+    }
+
+    fn compose<'a>(
+        parts: &HashMap<&str, (&'a [&'a str], &[Parameter])>,
+        name: &str,
+    ) -> Result<Vec<Parameter>> {
+        let (supertypes, own) = parts.get(name).ok_or_else(|| {
+            Error::InvalidComplexEntity(format!("partial record '{}' is missing", name))
+        })?;
+        let mut parameters = Vec::with_capacity(supertypes.len() + own.len());
+        for sup in supertypes.iter() {
+            parameters.push(Parameter::Typed {
+                keyword: sup.to_string(),
+                parameter: Box::new(Parameter::List(compose(parts, sup)?)),
+            });
+        }
+        parameters.extend(own.iter().cloned());
+        Ok(parameters)
+    }
+
+    subsuper
+        .into_iter()
+        .filter(|record| {
+            parts
+                .values()
+                .all(|(sup, _)| !sup.contains(&record.name.as_str()))
+        })
+        .map(|record| {
+            Ok(Record {
+                name: record.name.clone(),
+                parameter: Parameter::List(compose(&parts, &record.name)?),
+            })
+        })
+        .collect()
+}
+
+/// Inverse of [compose_complex]
+///
+/// The partial records are sorted by their names as the external mapping requires.
+///
+/// ```
+/// use ruststep::{ast::*, tables::decompose_complex};
+/// use std::str::FromStr;
+///
+/// let records = vec![
+///     Record::from_str("STUDENT(PERSON(('Nizika Iziti')), 'Simokitazawa')").unwrap(),
+///     Record::from_str("EMPLOYEE(PERSON(('Nizika Iziti')), 15)").unwrap(),
+/// ];
+/// let subsuper = decompose_complex(records, |name| match name {
+///     "PERSON" => Some(&[]),
+///     "EMPLOYEE" | "STUDENT" => Some(&["PERSON"]),
+///     _ => None,
+/// }).unwrap();
+/// assert_eq!(
+///     subsuper,
+///     SubSuperRecord::from_str(
+///         "(EMPLOYEE(15) PERSON('Nizika Iziti') STUDENT('Simokitazawa'))"
+///     ).unwrap()
+/// );
+/// ```
+pub fn decompose_complex<'a>(
+    records: impl IntoIterator<Item = Record>,
+    supertypes: impl Fn(&str) -> Option<&'a [&'a str]>,
+) -> Result<SubSuperRecord> {
+    fn decompose<'a>(
+        parts: &mut BTreeMap<String, Parameter>,
+        supertypes: &impl Fn(&str) -> Option<&'a [&'a str]>,
+        name: String,
+        parameter: Parameter,
+    ) -> Result<()> {
+        let sup = supertypes(&name).ok_or_else(|| Error::UnknownEntityName {
+            entity_name: name.clone(),
+            schema: "".to_string(),
+        })?;
+        let mut parameters = match parameter {
+            Parameter::List(parameters) => parameters,
+            parameter => vec![parameter],
+        };
+        if parameters.len() < sup.len() {
+            return Err(Error::InvalidComplexEntity(format!(
+                "'{}' lacks its supertypes",
+                name
+            )));
+        }
+        let own = parameters.split_off(sup.len());
+        for (sup, parameter) in sup.iter().zip(parameters) {
+            match parameter {
+                Parameter::Typed { keyword, parameter } if keyword == *sup => {
+                    decompose(parts, supertypes, keyword, *parameter)?
+                }
+                parameter => {
+                    return Err(Error::InvalidComplexEntity(format!(
+                        "supertype '{}' of '{}' must be inline, but {} found",
+                        sup, name, parameter
+                    )))
+                }
+            }
+        }
+        let own = Parameter::List(own);
+        match parts.get(&name) {
+            Some(existing) if existing != &own => Err(Error::InvalidComplexEntity(format!(
+                "partial records '{}' are inconsistent",
+                name
+            ))),
+            Some(_) => Ok(()),
+            None => {
+                parts.insert(name, own);
+                Ok(())
+            }
+        }
+    }
+
+    let mut parts = BTreeMap::new();
+    for record in records {
+        decompose(&mut parts, &supertypes, record.name, record.parameter)?;
+    }
+    Ok(parts
+        .into_iter()
+        .map(|(name, parameter)| Record { name, parameter })
+        .collect())
+}
+
 /// Owned value or reference through entity/value id
 #[derive(Debug, Clone, PartialEq)]
 pub enum PlaceHolder<T> {
@@ -216,19 +984,190 @@ where
     type Table = T::Table;
     /// Get owned value, or look up entity table and clone it for a reference.
     ///
+    /// Value instance names and constant names have to be resolved by [NameTable]
+    /// before loading DATA section, e.g. by [TableInit::from_exchange].
+    ///
     /// Errors
     /// -------
     /// - if table lookup failed, i.e. unknown entity id not registered in the table
+    /// - [Error::UnresolvedName] for a value instance name or a constant name
+    /// - [Error::CyclicOwnership] if the entity refers itself through its attributes
     ///
-    fn into_owned(self, table: &Self::Table) -> Result<T::Owned> {
+    fn into_owned_in(self, table: &Self::Table, resolution: &mut Resolution) -> Result<T::Owned> {
         match self {
             PlaceHolder::Ref(id) => match id {
-                Name::Entity(id) => table.get_owned(id),
-                _ => unimplemented!("ENTITY is only supported now"),
+                Name::Entity(id) => table.get_owned_in(id, resolution),
+                name => Err(Error::UnresolvedName(name.to_string())),
             },
-            PlaceHolder::Owned(a) => a.into_owned(table),
+            PlaceHolder::Owned(a) => a.into_owned_in(table, resolution),
+        }
+    }
+}
+
+impl<T: Holder> PlaceHolder<T>
+where
+    T::Table: EntityTable<T>,
+{
+    /// Get owned value as [IntoOwned::into_owned] with the entity id for a reference
+    pub fn into_identified(self, table: &T::Table) -> Result<Identified<T::Owned>> {
+        self.into_identified_in(table, &mut Resolution::new())
+    }
+
+    /// Get owned value as [IntoOwned::into_owned_in] with the entity id for a reference
+    pub fn into_identified_in(
+        self,
+        table: &T::Table,
+        resolution: &mut Resolution,
+    ) -> Result<Identified<T::Owned>> {
+        let id = match &self {
+            PlaceHolder::Ref(Name::Entity(id)) => Some(*id),
+            _ => None,
+        };
+        Ok(Identified {
+            id,
+            owned: self.into_owned_in(table, resolution)?,
+        })
+    }
+}
+
+/// Owned value with the entity id which it is resolved from
+///
+/// This can be used as a field type of an owned entity with `#[holder(use_place_holder)]`,
+/// e.g. `center: Identified<Point>`, where the holder keeps `PlaceHolder<PointHolder>`,
+/// to keep the ids of nested references.
+/// It is serialized as the owned value, i.e. the id is not written.
+///
+/// ```
+/// use ruststep::tables::Identified;
+///
+/// let point = Identified { id: Some(12), owned: (1.0, 2.0) };
+/// assert_eq!(point.0, 1.0); // dereferenced to the owned value
+/// assert_eq!(point.into_inner(), (1.0, 2.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identified<T> {
+    /// Entity id for a reference, or `None` for an inline value
+    pub id: Option<u64>,
+    pub owned: T,
+}
+
+impl<T> Identified<T> {
+    pub fn into_inner(self) -> T {
+        self.owned
+    }
+}
+
+impl<T> std::ops::Deref for Identified<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.owned
+    }
+}
+
+impl<T> std::ops::DerefMut for Identified<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.owned
+    }
+}
+
+impl<T: Serialize> Serialize for Identified<T> {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.owned.serialize(serializer)
+    }
+}
+
+/// Trait for holders which can be navigated through the table without cloning
+///
+/// `#[derive(Holder)]` implements this trait with a view type borrowing the holder and the table:
+///
+/// - `AView<'t>` for an entity `A`, with a method for each attribute.
+///   Attributes using [PlaceHolder] are returned as [Ref], and others are borrowed.
+/// - The view of the underlying type for a defined type.
+/// - `SView<'t>` enum for a select type `S`, whose variants are the views of its items.
+///
+/// Since a [Ref] is resolved only when [Ref::get] is called,
+/// entities shared by many referrers are never copied, and cyclic references do not recurse.
+pub trait Viewable<'t>: Holder {
+    type View;
+
+    /// View of this holder
+    fn view(&'t self, table: &'t Self::Table) -> Self::View;
+
+    /// View of the entity registered in the table
+    ///
+    /// Returns [Error::UnknownEntity] if `entity_id` is not registered.
+    fn view_id(table: &'t Self::Table, entity_id: u64) -> Result<Self::View>;
+}
+
+/// Typed handle of [PlaceHolder] in a view, resolved against the table lazily
+pub struct Ref<'t, T: Holder> {
+    place: &'t PlaceHolder<T>,
+    table: &'t T::Table,
+}
+
+impl<'t, T: Holder> Ref<'t, T> {
+    pub fn new(place: &'t PlaceHolder<T>, table: &'t T::Table) -> Self {
+        Ref { place, table }
+    }
+
+    /// Entity id for a reference, or `None` for an inline value
+    pub fn id(&self) -> Option<u64> {
+        match self.place {
+            PlaceHolder::Ref(Name::Entity(id)) => Some(*id),
+            _ => None,
+        }
+    }
+
+    pub fn place_holder(&self) -> &'t PlaceHolder<T> {
+        self.place
+    }
+
+    /// Resolve the reference, or view the inline value
+    ///
+    /// Returns [Error::UnresolvedName] for a value instance name or a constant name as [PlaceHolder::into_owned].
+    pub fn get(&self) -> Result<T::View>
+    where
+        T: Viewable<'t>,
+    {
+        match self.place {
+            PlaceHolder::Ref(Name::Entity(id)) => T::view_id(self.table, *id),
+            PlaceHolder::Ref(name) => Err(Error::UnresolvedName(name.to_string())),
+            PlaceHolder::Owned(holder) => Ok(holder.view(self.table)),
         }
     }
+
+    /// Clone the referred value recursively as [IntoOwned::into_owned]
+    pub fn to_owned(&self) -> Result<T::Owned>
+    where
+        T::Table: EntityTable<T>,
+    {
+        self.place.clone().into_owned(self.table)
+    }
+
+    /// Clone the referred value recursively with the entity id as [PlaceHolder::into_identified]
+    pub fn to_identified(&self) -> Result<Identified<T::Owned>>
+    where
+        T::Table: EntityTable<T>,
+    {
+        self.place.clone().into_identified(self.table)
+    }
+}
+
+impl<'t, T: Holder> Clone for Ref<'t, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'t, T: Holder> Copy for Ref<'t, T> {}
+
+impl<'t, T: Holder + fmt::Debug> fmt::Debug for Ref<'t, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Ref").field(self.place).finish()
+    }
 }
 
 impl<T: Holder> From<T> for PlaceHolder<T> {
@@ -243,6 +1182,18 @@ impl<T> From<Name> for PlaceHolder<T> {
     }
 }
 
+impl<T: Serialize> Serialize for PlaceHolder<T> {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self {
+            PlaceHolder::Ref(name) => name.serialize(serializer),
+            PlaceHolder::Owned(owned) => owned.serialize(serializer),
+        }
+    }
+}
+
 impl<'de, T: Holder + WithVisitor + Deserialize<'de>> Deserialize<'de> for PlaceHolder<T> {
     fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
     where
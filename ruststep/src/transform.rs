@@ -0,0 +1,1132 @@
+//! Rewrite exchange structures on the AST
+//!
+//! Transformations in this module work on [Exchange] directly,
+//! i.e. they can be used for any schema without generated `Tables`.
+//!
+//! Extract subgraph
+//! -----------------
+//! [Extract] copies root instances and all instances referred from them transitively
+//! into a new exchange structure, e.g. to cut a part out of a large assembly file:
+//!
+//! ```
+//! use ruststep::{ast::*, transform::Extract};
+//! use std::str::FromStr;
+//!
+//! let ex = Exchange::from_str(r#"
+//! ISO-10303-21;
+//! HEADER;
+//!   FILE_DESCRIPTION(('Assembly'), '2;1');
+//!   FILE_NAME('assembly.step', '2021-01-01T00:00:00', (''), (''), '', '', '');
+//!   FILE_SCHEMA(('EXAMPLE'));
+//! ENDSEC;
+//! DATA;
+//!   #10 = CPT(0.0, 0.0);
+//!   #20 = CPT(1.0, 0.0);
+//!   #30 = LINE(#10, #20);
+//!   #40 = CPT(2.0, 0.0);
+//!   #50 = LINE(#20, #40);
+//! ENDSEC;
+//! END-ISO-10303-21;
+//! "#).unwrap();
+//!
+//! let extracted = Extract::new(&[30]).renumber(true).apply(&ex).unwrap();
+//! assert_eq!(
+//!     extracted.data,
+//!     [DataSection::from_str(r#"
+//!         DATA;
+//!           #1 = CPT(0.0, 0.0);
+//!           #2 = CPT(1.0, 0.0);
+//!           #3 = LINE(#1, #2);
+//!         ENDSEC;
+//!     "#).unwrap()]
+//! );
+//! ```
+
+use crate::{ast::*, error::*, graph::Graph, header::Header};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
+
+/// Copy instances and their transitive references into a new exchange structure
+///
+/// The resulting exchange structure is adapted as follows:
+///
+/// - DATA sections keep only the extracted instances. Sections left empty are removed,
+///   but at least one section remains.
+/// - REFERENCE entries are kept if they are referred from the extracted instances.
+///   Entries for value instances and constants are always kept.
+/// - ANCHOR entries are kept if all instances they refer are kept.
+/// - SIGNATURE sections are removed since they do not match the new content.
+/// - HEADER records a line about the extraction in `FILE_DESCRIPTION`,
+///   and the time stamp and preprocessor version in `FILE_NAME` are updated by [Header::touch].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extract {
+    roots: Vec<u64>,
+    renumber: bool,
+}
+
+impl Extract {
+    /// Extract `roots` and instances referred from them
+    pub fn new(roots: &[u64]) -> Self {
+        Extract {
+            roots: roots.to_vec(),
+            renumber: false,
+        }
+    }
+
+    /// Renumber the extracted instances densely as `#1`, `#2`, ... by [renumber] with [Order::Original]
+    ///
+    /// Instances which are referred but not extracted,
+    /// i.e. defined in REFERENCE section or undefined, are numbered after them.
+    pub fn renumber(mut self, renumber: bool) -> Self {
+        self.renumber = renumber;
+        self
+    }
+
+    /// Returns [Error::UnknownEntity] if a root is not defined in DATA sections
+    pub fn apply(&self, exchange: &Exchange) -> Result<Exchange> {
+        let graph = Graph::from_sections(&exchange.data)?;
+        let kept: HashSet<u64> = graph.closure(&self.roots)?.into_iter().collect();
+
+        let mut data: Vec<DataSection> = exchange
+            .data
+            .iter()
+            .map(|section| DataSection {
+                meta: section.meta.clone(),
+                entities: section
+                    .entities
+                    .iter()
+                    .filter(|instance| kept.contains(&instance.id()))
+                    .cloned()
+                    .collect(),
+            })
+            .filter(|section| !section.entities.is_empty())
+            .collect();
+        if data.is_empty() {
+            // No instance is extracted only when `roots` is empty
+            data.extend(exchange.data.first().map(|section| DataSection {
+                meta: section.meta.clone(),
+                entities: Vec::new(),
+            }));
+        }
+
+        // Names referred from extracted instances but defined out of DATA sections
+        let external: BTreeSet<u64> = data
+            .iter()
+            .flat_map(|section| section.entities.iter())
+            .flat_map(EntityInstance::references)
+            .filter(|id| !kept.contains(id))
+            .collect();
+
+        let reference = exchange
+            .reference
+            .iter()
+            .filter(|entry| match entry.name {
+                Name::Entity(id) => external.contains(&id),
+                _ => true,
+            })
+            .cloned()
+            .collect();
+
+        let anchor = exchange
+            .anchor
+            .iter()
+            .filter(|anchor| {
+                let tags = anchor.tags.iter().map(|(_, item)| item);
+                std::iter::once(&anchor.item)
+                    .chain(tags)
+                    .flat_map(AnchorItem::references)
+                    .all(|id| kept.contains(&id) || external.contains(&id))
+            })
+            .cloned()
+            .collect();
+
+        let mut extracted = Exchange {
+            header: self.header(&exchange.header)?,
+            anchor,
+            reference,
+            data,
+            signature: Vec::new(),
+        };
+        if self.renumber {
+            renumber(&mut extracted, Order::Original)?;
+        }
+        Ok(extracted)
+    }
+
+    fn header(&self, records: &[Record]) -> Result<Vec<Record>> {
+        let mut header = Header::from_records(records)?;
+        let roots: Vec<String> = self.roots.iter().map(|id| format!("#{}", id)).collect();
+        header.file_description.description.push(format!(
+            "Extracted {} from {}",
+            roots.join(", "),
+            header.file_name.name
+        ));
+        header.touch();
+        header.to_records()
+    }
+}
+
+/// Entity instances shared among files as the context of product data
+///
+/// These are units, uncertainties, and application or representation contexts,
+/// which typically appear in every file exported from the same CAD system.
+/// This is the default for [Merge::dedup].
+pub const SHARED_CONTEXT: &[&str] = &[
+    "APPLICATION_CONTEXT",
+    "APPLICATION_PROTOCOL_DEFINITION",
+    "PRODUCT_CONTEXT",
+    "MECHANICAL_CONTEXT",
+    "PRODUCT_DEFINITION_CONTEXT",
+    "DESIGN_CONTEXT",
+    "DIMENSIONAL_EXPONENTS",
+    "NAMED_UNIT",
+    "SI_UNIT",
+    "CONVERSION_BASED_UNIT",
+    "LENGTH_UNIT",
+    "PLANE_ANGLE_UNIT",
+    "SOLID_ANGLE_UNIT",
+    "LENGTH_MEASURE_WITH_UNIT",
+    "PLANE_ANGLE_MEASURE_WITH_UNIT",
+    "UNCERTAINTY_MEASURE_WITH_UNIT",
+    "REPRESENTATION_CONTEXT",
+    "GEOMETRIC_REPRESENTATION_CONTEXT",
+    "GLOBAL_UNIT_ASSIGNED_CONTEXT",
+    "GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT",
+];
+
+/// Merge exchange structures into one, remapping entity ids to avoid conflicts
+///
+/// Entity ids of each input are shifted by the largest id of the preceding inputs,
+/// i.e. the first input keeps its ids, and references are rewritten consistently.
+/// Value instance names `@n` are shifted in the same way, while constant names are kept
+/// since they are defined in the ANCHOR sections or the common schema.
+/// The resulting exchange structure is built as follows:
+///
+/// - DATA sections of the inputs are concatenated if they have the same parameters,
+///   i.e. inputs with a single DATA section result in a single DATA section.
+/// - Instances of the entities specified by [Merge::dedup] are shared
+///   if they are equal after rewriting references, e.g. `SI_UNIT` of each part file.
+///   An instance referring a not shared instance is not shared either.
+/// - ANCHOR and REFERENCE sections are concatenated. Anchor names must be unique among inputs.
+/// - SIGNATURE sections are removed since they do not match the new content.
+/// - HEADER of the first input is used with a line about the merge in `FILE_DESCRIPTION`,
+///   and the time stamp and preprocessor version in `FILE_NAME` are updated by [Header::touch].
+///   All inputs must have the same `FILE_SCHEMA`.
+///
+/// Since the result is an [Exchange], it can be loaded into a single `Tables`
+/// through [TableInit::from_data_sections](crate::tables::TableInit::from_data_sections)
+/// or [AnyTables::from_exchange](crate::registry::AnyTables::from_exchange).
+///
+/// ```
+/// use ruststep::{ast::*, transform::{Merge, SHARED_CONTEXT}};
+/// use std::str::FromStr;
+///
+/// let part = |name: &str| Exchange::from_str(&format!(r#"
+/// ISO-10303-21;
+/// HEADER;
+///   FILE_DESCRIPTION((''), '2;1');
+///   FILE_NAME('{}.step', '2021-01-01T00:00:00', (''), (''), '', '', '');
+///   FILE_SCHEMA(('EXAMPLE'));
+/// ENDSEC;
+/// DATA;
+///   #1 = (LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.));
+///   #2 = PRODUCT('{}', #3);
+///   #3 = LENGTH_MEASURE_WITH_UNIT(1.0, #1);
+/// ENDSEC;
+/// END-ISO-10303-21;
+/// "#, name, name)).unwrap();
+///
+/// let merged = Merge::new()
+///     .dedup(SHARED_CONTEXT)
+///     .apply(&[part("a"), part("b")])
+///     .unwrap();
+/// assert_eq!(
+///     merged.data,
+///     [DataSection::from_str(r#"
+///         DATA;
+///           #1 = (LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.));
+///           #2 = PRODUCT('a', #3);
+///           #3 = LENGTH_MEASURE_WITH_UNIT(1.0, #1);
+///           #5 = PRODUCT('b', #3);
+///         ENDSEC;
+///     "#).unwrap()]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Merge {
+    dedup: HashSet<String>,
+    renumber: bool,
+}
+
+impl Merge {
+    /// Merge without sharing any instances
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Share equal instances of these entities, e.g. [SHARED_CONTEXT]
+    ///
+    /// A complex entity instance is shared if any of its partial records is one of them.
+    pub fn dedup(mut self, entities: &[&str]) -> Self {
+        self.dedup
+            .extend(entities.iter().map(|name| name.to_uppercase()));
+        self
+    }
+
+    /// Renumber merged instances densely as `#1`, `#2`, ... by [renumber] with [Order::Original]
+    pub fn renumber(mut self, renumber: bool) -> Self {
+        self.renumber = renumber;
+        self
+    }
+
+    /// Returns [Error::SchemaMismatch] if the inputs have different `FILE_SCHEMA`,
+    /// and [Error::DuplicatedAnchor] if an anchor name is used in multiple inputs
+    pub fn apply(&self, exchanges: &[Exchange]) -> Result<Exchange> {
+        let mut merged = Exchange {
+            header: self.header(exchanges)?,
+            anchor: Vec::new(),
+            reference: Vec::new(),
+            data: Vec::new(),
+            signature: Vec::new(),
+        };
+        // Shared instances keyed by their records after rewriting references
+        let mut shared = HashMap::new();
+        let mut offset = 0;
+        let mut value_offset = 0;
+        for exchange in exchanges {
+            let graph = Graph::from_sections(&exchange.data)?;
+            let mut map = HashMap::new();
+            for id in graph.ids() {
+                self.resolve(
+                    &graph,
+                    id,
+                    (offset, value_offset),
+                    &mut map,
+                    &mut shared,
+                    &mut Vec::new(),
+                );
+            }
+            let new_id = |id: u64| map.get(&id).cloned().unwrap_or(id + offset);
+
+            for section in &exchange.data {
+                let entities = section
+                    .entities
+                    .iter()
+                    .filter(|instance| new_id(instance.id()) == instance.id() + offset)
+                    .map(|instance| {
+                        let mut instance = instance.clone();
+                        instance.renumber(new_id);
+                        shift_instance_values(&mut instance, value_offset);
+                        instance
+                    });
+                match merged
+                    .data
+                    .iter_mut()
+                    .find(|data| data.meta == section.meta)
+                {
+                    Some(data) => data.entities.extend(entities),
+                    None => merged.data.push(DataSection {
+                        meta: section.meta.clone(),
+                        entities: entities.collect(),
+                    }),
+                }
+            }
+
+            for anchor in &exchange.anchor {
+                if merged.anchor.iter().any(|a| a.name == anchor.name) {
+                    return Err(Error::DuplicatedAnchor(anchor.name.clone()));
+                }
+                let mut anchor = anchor.clone();
+                anchor.item.map_references(&mut |id| new_id(id));
+                shift_anchor_values(&mut anchor.item, value_offset);
+                for (_, item) in &mut anchor.tags {
+                    item.map_references(&mut |id| new_id(id));
+                    shift_anchor_values(item, value_offset);
+                }
+                merged.anchor.push(anchor);
+            }
+            for entry in &exchange.reference {
+                let mut entry = entry.clone();
+                match &mut entry.name {
+                    Name::Entity(id) => *id = new_id(*id),
+                    Name::Value(id) => *id += value_offset,
+                    _ => {}
+                }
+                if !merged.reference.contains(&entry) {
+                    merged.reference.push(entry);
+                }
+            }
+
+            offset = merged
+                .data
+                .iter()
+                .flat_map(|data| data.entities.iter())
+                .flat_map(|instance| {
+                    let id = instance.id();
+                    instance.references().into_iter().chain(Some(id))
+                })
+                .chain(
+                    merged
+                        .reference
+                        .iter()
+                        .filter_map(|entry| match entry.name {
+                            Name::Entity(id) => Some(id),
+                            _ => None,
+                        }),
+                )
+                .max()
+                .unwrap_or(offset);
+            value_offset += largest_value(exchange);
+        }
+        if self.renumber {
+            renumber(&mut merged, Order::Original)?;
+        }
+        Ok(merged)
+    }
+
+    /// Determine the new id of `#id`, and register it to `shared` if it is shareable
+    ///
+    /// Shareable instances referred from `#id` are resolved first,
+    /// and `path` keeps the instances being resolved to stop at cycles.
+    fn resolve(
+        &self,
+        graph: &Graph,
+        id: u64,
+        (offset, value_offset): (u64, u64),
+        map: &mut HashMap<u64, u64>,
+        shared: &mut HashMap<InstanceKey, u64>,
+        path: &mut Vec<u64>,
+    ) -> u64 {
+        if let Some(new_id) = map.get(&id) {
+            return *new_id;
+        }
+        let instance = match graph.get(id) {
+            Some(instance) if !path.contains(&id) && self.is_shareable(instance) => instance,
+            _ => return id + offset,
+        };
+        path.push(id);
+        for &to in graph.references(id) {
+            self.resolve(graph, to, (offset, value_offset), map, shared, path);
+        }
+        path.pop();
+
+        let mut instance = instance.clone();
+        instance.renumber(|to| {
+            if to == id {
+                0
+            } else {
+                map.get(&to).cloned().unwrap_or(to + offset)
+            }
+        });
+        shift_instance_values(&mut instance, value_offset);
+        let new_id = *shared.entry(InstanceKey(instance)).or_insert(id + offset);
+        map.insert(id, new_id);
+        new_id
+    }
+
+    fn is_shareable(&self, instance: &EntityInstance) -> bool {
+        instance
+            .records()
+            .iter()
+            .any(|record| self.dedup.contains(&record.name))
+    }
+
+    fn header(&self, exchanges: &[Exchange]) -> Result<Vec<Record>> {
+        let headers = exchanges
+            .iter()
+            .map(|exchange| Header::from_records(&exchange.header))
+            .collect::<Result<Vec<_>>>()?;
+        let mut header = match headers.first() {
+            Some(header) => header.clone(),
+            None => {
+                return Err(Error::InvalidHeader(
+                    "No exchange structure to merge".to_string(),
+                ))
+            }
+        };
+        for other in &headers[1..] {
+            if other.file_schema != header.file_schema {
+                return Err(Error::SchemaMismatch {
+                    expected: header.file_schema.schema,
+                    found: other.file_schema.schema.clone(),
+                });
+            }
+        }
+        let names: Vec<&str> = headers
+            .iter()
+            .map(|header| header.file_name.name.as_str())
+            .collect();
+        header
+            .file_description
+            .description
+            .push(format!("Merged from {}", names.join(", ")));
+        header.touch();
+        header.to_records()
+    }
+}
+
+/// Largest value instance name in an exchange structure, e.g. `3` for `@1` and `@3`
+fn largest_value(exchange: &Exchange) -> u64 {
+    fn largest(parameter: &Parameter) -> u64 {
+        match parameter {
+            Parameter::Typed { parameter, .. } => largest(parameter),
+            Parameter::List(list) => list.iter().map(largest).max().unwrap_or(0),
+            Parameter::Ref(Name::Value(id)) => *id,
+            _ => 0,
+        }
+    }
+    fn largest_anchor(item: &AnchorItem) -> u64 {
+        match item {
+            AnchorItem::List(list) => list.iter().map(largest_anchor).max().unwrap_or(0),
+            AnchorItem::Name(Name::Value(id)) => *id,
+            _ => 0,
+        }
+    }
+    let data = exchange
+        .data
+        .iter()
+        .flat_map(|section| section.entities.iter())
+        .flat_map(|instance| instance.records())
+        .map(|record| largest(&record.parameter));
+    let anchor = exchange.anchor.iter().flat_map(|anchor| {
+        Some(&anchor.item)
+            .into_iter()
+            .chain(anchor.tags.iter().map(|(_, item)| item))
+            .map(largest_anchor)
+    });
+    let reference = exchange
+        .reference
+        .iter()
+        .filter_map(|entry| match entry.name {
+            Name::Value(id) => Some(id),
+            _ => None,
+        });
+    data.chain(anchor).chain(reference).max().unwrap_or(0)
+}
+
+/// Rewrite value instance names `@n` in an instance into `@(n + offset)`
+fn shift_instance_values(instance: &mut EntityInstance, offset: u64) {
+    fn shift(parameter: &mut Parameter, offset: u64) {
+        match parameter {
+            Parameter::Typed { parameter, .. } => shift(parameter, offset),
+            Parameter::List(list) => list.iter_mut().for_each(|p| shift(p, offset)),
+            Parameter::Ref(Name::Value(id)) => *id += offset,
+            _ => {}
+        }
+    }
+    for record in instance.records_mut() {
+        shift(&mut record.parameter, offset);
+    }
+}
+
+/// Rewrite value instance names `@n` in an anchor item into `@(n + offset)`
+fn shift_anchor_values(item: &mut AnchorItem, offset: u64) {
+    match item {
+        AnchorItem::List(list) => list
+            .iter_mut()
+            .for_each(|item| shift_anchor_values(item, offset)),
+        AnchorItem::Name(Name::Value(id)) => *id += offset,
+        _ => {}
+    }
+}
+
+/// Collapse structurally identical entity instances into one
+///
+/// Instances are compared from the leaves of the reference graph,
+/// i.e. two instances are identical if they have the same parameters
+/// after replacing references to identical instances by the same one.
+/// Among identical instances, the one with the smallest id survives,
+/// and references to the others are rewritten to it.
+/// Instances in reference cycles are kept as they are,
+/// and instances in DATA sections with different parameters are not collapsed.
+///
+/// SIGNATURE sections are removed,
+/// and the time stamp and preprocessor version in `FILE_NAME` are updated by [Header::touch].
+///
+/// ```
+/// use ruststep::{ast::*, transform::{Dedup, Order}};
+/// use std::str::FromStr;
+///
+/// let ex = Exchange::from_str(r#"
+/// ISO-10303-21;
+/// HEADER;
+///   FILE_DESCRIPTION((''), '2;1');
+///   FILE_NAME('', '2021-01-01T00:00:00', (''), (''), '', '', '');
+///   FILE_SCHEMA(('EXAMPLE'));
+/// ENDSEC;
+/// DATA;
+///   #1 = LINE(#2, #3);
+///   #2 = CPT(0.0, 0.0);
+///   #3 = DIR(#5);
+///   #4 = CPT(0.0, 0.0);
+///   #5 = VEC(1.0, 0.0);
+///   #6 = LINE(#4, #7);
+///   #7 = DIR(#8);
+///   #8 = VEC(1.0, 0.0);
+/// ENDSEC;
+/// END-ISO-10303-21;
+/// "#).unwrap();
+///
+/// let deduped = Dedup::new().renumber(true).order(Order::Topological).apply(&ex).unwrap();
+/// assert_eq!(
+///     deduped.data,
+///     [DataSection::from_str(r#"
+///         DATA;
+///           #1 = CPT(0.0, 0.0);
+///           #2 = VEC(1.0, 0.0);
+///           #3 = DIR(#2);
+///           #4 = LINE(#1, #3);
+///         ENDSEC;
+///     "#).unwrap()]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dedup {
+    renumber: bool,
+    order: Order,
+}
+
+impl Default for Dedup {
+    fn default() -> Self {
+        Dedup {
+            renumber: false,
+            order: Order::Original,
+        }
+    }
+}
+
+impl Dedup {
+    /// Collapse identical instances without renumbering
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renumber surviving instances densely as `#1`, `#2`, ... by [renumber]
+    pub fn renumber(mut self, renumber: bool) -> Self {
+        self.renumber = renumber;
+        self
+    }
+
+    /// Order of renumbering, [Order::Original] by default
+    pub fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Returns [Error::DuplicatedEntity] if an id is defined twice
+    pub fn apply(&self, exchange: &Exchange) -> Result<Exchange> {
+        let graph = Graph::from_sections(&exchange.data)?;
+        let in_cycle: HashSet<u64> = graph.cycles().into_iter().flatten().collect();
+        // Index of DATA sections with the same parameters
+        let mut section_of = HashMap::new();
+        for (i, section) in exchange.data.iter().enumerate() {
+            let index = exchange
+                .data
+                .iter()
+                .position(|other| other.meta == section.meta)
+                .unwrap_or(i);
+            for instance in &section.entities {
+                section_of.insert(instance.id(), index);
+            }
+        }
+
+        // Identical instances belong to the same class,
+        // and referred instances have been classified before referring instances
+        let mut class_of: HashMap<u64, u64> = HashMap::new();
+        let mut classes: HashMap<(usize, InstanceKey), u64> = HashMap::new();
+        let mut survivors: Vec<u64> = Vec::new();
+        let new_class = |id: u64, survivors: &mut Vec<u64>| {
+            survivors.push(id);
+            survivors.len() as u64 - 1
+        };
+        for id in graph.post_order() {
+            let class = if in_cycle.contains(&id) {
+                new_class(id, &mut survivors)
+            } else {
+                let mut instance = graph.get(id).unwrap().clone();
+                instance.renumber(|to| {
+                    if to == id {
+                        0
+                    } else {
+                        *class_of
+                            .entry(to)
+                            .or_insert_with(|| new_class(to, &mut survivors))
+                    }
+                });
+                let key = (section_of[&id], InstanceKey(instance));
+                match classes.get(&key) {
+                    Some(&class) => {
+                        let survivor = &mut survivors[class as usize];
+                        *survivor = (*survivor).min(id);
+                        class
+                    }
+                    None => {
+                        let class = new_class(id, &mut survivors);
+                        classes.insert(key, class);
+                        class
+                    }
+                }
+            };
+            class_of.insert(id, class);
+        }
+        let survivor = |id: u64| {
+            class_of
+                .get(&id)
+                .map(|&class| survivors[class as usize])
+                .unwrap_or(id)
+        };
+
+        let mut header = Header::from_records(&exchange.header)?;
+        header.touch();
+        let mut deduped = Exchange {
+            header: header.to_records()?,
+            anchor: exchange.anchor.clone(),
+            reference: exchange.reference.clone(),
+            data: exchange
+                .data
+                .iter()
+                .map(|section| DataSection {
+                    meta: section.meta.clone(),
+                    entities: section
+                        .entities
+                        .iter()
+                        .filter(|instance| survivor(instance.id()) == instance.id())
+                        .cloned()
+                        .collect(),
+                })
+                .collect(),
+            signature: Vec::new(),
+        };
+        map_ids(&mut deduped, survivor);
+        if self.renumber {
+            renumber(&mut deduped, self.order)?;
+        }
+        Ok(deduped)
+    }
+}
+
+/// Key to find identical entity instances
+///
+/// Reals are compared by their bits as [Parameter::same_bits],
+/// since NaN is not equal to itself, and cannot be written in exchange structure to compare the text.
+#[derive(Debug)]
+struct InstanceKey(EntityInstance);
+
+impl PartialEq for InstanceKey {
+    fn eq(&self, other: &Self) -> bool {
+        let (records, other_records) = (self.0.records(), other.0.records());
+        std::mem::discriminant(&self.0) == std::mem::discriminant(&other.0)
+            && self.0.id() == other.0.id()
+            && records.len() == other_records.len()
+            && records.iter().zip(other_records).all(|(record, other)| {
+                record.name == other.name && record.parameter.same_bits(&other.parameter)
+            })
+    }
+}
+
+impl Eq for InstanceKey {}
+
+impl Hash for InstanceKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(&self.0).hash(state);
+        self.0.id().hash(state);
+        for record in self.0.records() {
+            record.name.hash(state);
+            record.parameter.hash_structure(state);
+        }
+    }
+}
+
+/// Order of entity instances used by [renumber]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Ascending order of the current ids
+    Original,
+    /// Referred instances come before referring instances, see [Graph::post_order]
+    Topological,
+}
+
+/// Renumber entity instances densely as `#1`, `#2`, ... in `order`
+///
+/// Referred but undefined names, e.g. defined in REFERENCE section, are numbered after them.
+/// With [Order::Topological], instances in each DATA section are also sorted by their new ids.
+/// Returns [Error::DuplicatedEntity] if an id is defined twice.
+///
+/// ```
+/// use ruststep::{ast::*, transform::{renumber, Order}};
+/// use std::str::FromStr;
+///
+/// let mut ex = Exchange::from_str(r#"
+/// ISO-10303-21;
+/// HEADER;
+///   FILE_DESCRIPTION((''), '2;1');
+///   FILE_NAME('', '2021-01-01T00:00:00', (''), (''), '', '', '');
+///   FILE_SCHEMA(('EXAMPLE'));
+/// ENDSEC;
+/// DATA;
+///   #10 = LINE(#30, #20);
+///   #20 = CPT(1.0, 0.0);
+///   #30 = CPT(0.0, 0.0);
+/// ENDSEC;
+/// END-ISO-10303-21;
+/// "#).unwrap();
+///
+/// let mut original = ex.clone();
+/// renumber(&mut original, Order::Original).unwrap();
+/// assert_eq!(
+///     original.data[0],
+///     DataSection::from_str("DATA; #1 = LINE(#3, #2); #2 = CPT(1.0, 0.0); #3 = CPT(0.0, 0.0); ENDSEC;").unwrap()
+/// );
+///
+/// renumber(&mut ex, Order::Topological).unwrap();
+/// assert_eq!(
+///     ex.data[0],
+///     DataSection::from_str("DATA; #1 = CPT(0.0, 0.0); #2 = CPT(1.0, 0.0); #3 = LINE(#1, #2); ENDSEC;").unwrap()
+/// );
+/// ```
+pub fn renumber(exchange: &mut Exchange, order: Order) -> Result<()> {
+    let graph = Graph::from_sections(&exchange.data)?;
+    let defined = match order {
+        Order::Original => graph.ids().collect(),
+        Order::Topological => graph.post_order(),
+    };
+    let undefined: BTreeSet<u64> = graph.dangling().into_iter().map(|(_, to)| to).collect();
+    let map: HashMap<u64, u64> = defined.into_iter().chain(undefined).zip(1..).collect();
+    map_ids(exchange, |id| map.get(&id).cloned().unwrap_or(id));
+    if order == Order::Topological {
+        for section in &mut exchange.data {
+            section.entities.sort_by_key(EntityInstance::id);
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite entity instance names in DATA, ANCHOR, and REFERENCE sections
+fn map_ids(exchange: &mut Exchange, mut f: impl FnMut(u64) -> u64) {
+    for instance in exchange
+        .data
+        .iter_mut()
+        .flat_map(|data| data.entities.iter_mut())
+    {
+        instance.renumber(&mut f);
+    }
+    for anchor in &mut exchange.anchor {
+        anchor.item.map_references(&mut f);
+        for (_, item) in &mut anchor.tags {
+            item.map_references(&mut f);
+        }
+    }
+    for entry in &mut exchange.reference {
+        if let Name::Entity(id) = &mut entry.name {
+            *id = f(*id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::Writer;
+    use std::str::FromStr;
+
+    const STEP: &str = r#"
+    ISO-10303-21;
+    HEADER;
+      FILE_DESCRIPTION(('Assembly'), '2;1');
+      FILE_NAME('assembly.step', '2021-01-01T00:00:00', (''), (''), '', '', '');
+      FILE_SCHEMA(('EXAMPLE'));
+    ENDSEC;
+    ANCHOR;
+      <line> = #30;
+      <point> = #40;
+    ENDSEC;
+    REFERENCE;
+      #60 = <other.step#1>;
+      #70 = <other.step#2>;
+      @1 = <values.step#1>;
+    ENDSEC;
+    DATA;
+      #10 = CPT(0.0, 0.0);
+      #20 = CPT(1.0, 0.0);
+    ENDSEC;
+    DATA;
+      #30 = LINE(#10, #20, #60, #90);
+      #40 = CPT(2.0, #70);
+      #50 = LINE(#20, #40);
+    ENDSEC;
+    END-ISO-10303-21;
+    "#;
+
+    #[test]
+    fn extract() {
+        let ex = Exchange::from_str(STEP).unwrap();
+        let extracted = Extract::new(&[30]).apply(&ex).unwrap();
+        assert_eq!(extracted.data.len(), 2);
+        assert_eq!(extracted.anchor.len(), 1);
+        assert_eq!(extracted.reference.len(), 2);
+
+        let header = Header::from_records(&extracted.header).unwrap();
+        assert_eq!(
+            header.file_description.description,
+            ["Assembly", "Extracted #30 from assembly.step"]
+        );
+        assert_ne!(header.file_name.time_stamp, "2021-01-01T00:00:00");
+
+        // Written exchange structure is valid
+        let written = Writer::new().to_string(&extracted).unwrap();
+        assert_eq!(Exchange::from_str(&written).unwrap(), extracted);
+    }
+
+    #[test]
+    fn extract_renumber() {
+        let ex = Exchange::from_str(STEP).unwrap();
+        let extracted = Extract::new(&[30]).renumber(true).apply(&ex).unwrap();
+        let ids: Vec<u64> = extracted
+            .data
+            .iter()
+            .flat_map(|data| data.entities.iter().map(EntityInstance::id))
+            .collect();
+        assert_eq!(ids, [1, 2, 3]);
+        assert_eq!(
+            extracted.data[1].entities[0],
+            EntityInstance::from_str("#3 = LINE(#1, #2, #4, #5);").unwrap()
+        );
+        assert_eq!(
+            extracted.anchor[0],
+            Anchor::from_str("<line> = #3;").unwrap()
+        );
+        assert_eq!(extracted.reference[0].name, Name::Entity(4));
+    }
+
+    #[test]
+    fn merge() {
+        let ex = Exchange::from_str(STEP).unwrap();
+        assert!(matches!(
+            Merge::new().apply(&[ex.clone(), ex.clone()]),
+            Err(Error::DuplicatedAnchor(name)) if name == "line"
+        ));
+
+        let mut other = ex.clone();
+        other.anchor.clear();
+        let merged = Merge::new().apply(&[ex.clone(), other.clone()]).unwrap();
+        // All DATA sections have no parameters
+        assert_eq!(merged.data.len(), 1);
+        assert_eq!(merged.data[0].entities.len(), 10);
+        // Shifted by the largest id #90 of the first input
+        assert_eq!(
+            merged.data[0].entities[7],
+            EntityInstance::from_str("#120 = LINE(#100, #110, #150, #180);").unwrap()
+        );
+        assert_eq!(merged.anchor.len(), 2);
+        // `@1` of the second input is shifted by the largest value name `@1` of the first input
+        assert_eq!(merged.reference.len(), 6);
+        assert_eq!(
+            merged.reference[5],
+            ReferenceEntry::from_str("@2 = <values.step#1>;").unwrap()
+        );
+
+        other.header[2] = Record::from_str("FILE_SCHEMA(('OTHER'))").unwrap();
+        assert!(matches!(
+            Merge::new().apply(&[ex, other]),
+            Err(Error::SchemaMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn merge_values() {
+        let part = |uri: &str| {
+            Exchange::from_str(&format!(
+                r#"
+                ISO-10303-21;
+                HEADER;
+                  FILE_DESCRIPTION((''), '2;1');
+                  FILE_NAME('part.step', '2021-01-01T00:00:00', (''), (''), '', '', '');
+                  FILE_SCHEMA(('EXAMPLE'));
+                ENDSEC;
+                ANCHOR;
+                  <{0}> = (@1, #1);
+                ENDSEC;
+                REFERENCE;
+                  @1 = <{0}.step#1>;
+                ENDSEC;
+                DATA;
+                  #1 = A(@1, (@1, B(@1)));
+                ENDSEC;
+                END-ISO-10303-21;
+                "#,
+                uri
+            ))
+            .unwrap()
+        };
+        let merged = Merge::new().apply(&[part("x"), part("y")]).unwrap();
+        // `@1` of each input refers different values
+        assert_eq!(
+            merged.reference,
+            [
+                ReferenceEntry::from_str("@1 = <x.step#1>;").unwrap(),
+                ReferenceEntry::from_str("@2 = <y.step#1>;").unwrap(),
+            ]
+        );
+        assert_eq!(
+            merged.data[0].entities,
+            [
+                EntityInstance::from_str("#1 = A(@1, (@1, B(@1)));").unwrap(),
+                EntityInstance::from_str("#2 = A(@2, (@2, B(@2)));").unwrap(),
+            ]
+        );
+        assert_eq!(
+            merged.anchor[1],
+            Anchor::from_str("<y> = (@2, #2);").unwrap()
+        );
+    }
+
+    #[test]
+    fn merge_dedup() {
+        let part = Exchange::from_str(
+            r#"
+            ISO-10303-21;
+            HEADER;
+              FILE_DESCRIPTION((''), '2;1');
+              FILE_NAME('part.step', '2021-01-01T00:00:00', (''), (''), '', '', '');
+              FILE_SCHEMA(('EXAMPLE'));
+            ENDSEC;
+            DATA;
+              #1 = DIMENSIONAL_EXPONENTS(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+              #2 = (CONVERSION_BASED_UNIT('INCH', #3) LENGTH_UNIT() NAMED_UNIT(#1));
+              #3 = LENGTH_MEASURE_WITH_UNIT(LENGTH_MEASURE(25.4), #4);
+              #4 = (LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.));
+              #5 = A(#2, #6);
+              #6 = LENGTH_MEASURE_WITH_UNIT(1.0, #5);
+              #7 = DESIGN_CONTEXT('', #7, 'design');
+            ENDSEC;
+            END-ISO-10303-21;
+            "#,
+        )
+        .unwrap();
+        let merged = Merge::new()
+            .dedup(SHARED_CONTEXT)
+            .renumber(true)
+            .apply(&[part.clone(), part])
+            .unwrap();
+        let ids: Vec<u64> = merged.data[0]
+            .entities
+            .iter()
+            .map(EntityInstance::id)
+            .collect();
+        // Units and contexts are shared, but `#6` refers not shared `#5`
+        assert_eq!(ids, (1..=9).collect::<Vec<u64>>());
+        assert_eq!(
+            merged.data[0].entities[7],
+            EntityInstance::from_str("#8 = A(#2, #9);").unwrap()
+        );
+    }
+
+    #[test]
+    fn dedup() {
+        let step_str = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/steps/00000050_80d90bfdd2e74e709956122a_step_000.step"
+        ))
+        .unwrap();
+        let ex = Exchange::from_str(&step_str).unwrap();
+        let deduped = Dedup::new().renumber(true).apply(&ex).unwrap();
+        let len = deduped.data[0].entities.len();
+        assert!(len < ex.data[0].entities.len());
+        // Nothing is collapsed in the second time
+        let again = Dedup::new().apply(&deduped).unwrap();
+        assert_eq!(again.data, deduped.data);
+
+        let graph = Graph::new(&deduped.data[0]).unwrap();
+        assert!(graph.dangling().is_empty());
+        assert_eq!(graph.ids().last(), Some(len as u64));
+    }
+
+    #[test]
+    fn dedup_cycles() {
+        let ex = Exchange::from_str(
+            r#"
+            ISO-10303-21;
+            HEADER;
+              FILE_DESCRIPTION((''), '2;1');
+              FILE_NAME('', '2021-01-01T00:00:00', (''), (''), '', '', '');
+              FILE_SCHEMA(('EXAMPLE'));
+            ENDSEC;
+            DATA;
+              #1 = A(#2, #5);
+              #2 = B(#1);
+              #3 = A(#4, #6);
+              #4 = B(#3);
+              #5 = C(#9);
+              #6 = C(#9);
+              #7 = D(#1, #5);
+              #8 = D(#1, #6);
+            ENDSEC;
+            END-ISO-10303-21;
+            "#,
+        )
+        .unwrap();
+        let deduped = Dedup::new().apply(&ex).unwrap();
+        let ids: Vec<u64> = deduped.data[0]
+            .entities
+            .iter()
+            .map(EntityInstance::id)
+            .collect();
+        // Instances in cycles are kept, and references to undefined `#9` are compared by id
+        assert_eq!(ids, [1, 2, 3, 4, 5, 7]);
+        assert_eq!(
+            deduped.data[0].entities[2],
+            EntityInstance::from_str("#3 = A(#4, #5);").unwrap()
+        );
+    }
+
+    #[test]
+    fn dedup_non_finite() {
+        let mut ex = Exchange::from_str(
+            r#"
+            ISO-10303-21;
+            HEADER;
+              FILE_DESCRIPTION((''), '2;1');
+              FILE_NAME('', '2021-01-01T00:00:00', (''), (''), '', '', '');
+              FILE_SCHEMA(('EXAMPLE'));
+            ENDSEC;
+            DATA;
+              #1 = A(0.0);
+              #2 = A(0.0);
+              #3 = A(-0.0);
+              #4 = B(#1);
+              #5 = B(#2);
+            ENDSEC;
+            END-ISO-10303-21;
+            "#,
+        )
+        .unwrap();
+        for instance in &mut ex.data[0].entities[..2] {
+            instance.records_mut()[0].parameter =
+                vec![Parameter::Real(f64::NAN)].into_iter().collect();
+        }
+        let ids = |ex: &Exchange| -> Vec<u64> {
+            ex.data[0].entities.iter().map(EntityInstance::id).collect()
+        };
+        // NaN is collapsed with the same bits, and `-0.0` is not collapsed with `0.0`
+        let deduped = Dedup::new().apply(&ex).unwrap();
+        assert_eq!(ids(&deduped), [1, 3, 4]);
+
+        let merged = Merge::new()
+            .dedup(&["A", "B"])
+            .apply(&[ex.clone(), ex])
+            .unwrap();
+        // Instances of the second input are shared with the first ones
+        assert_eq!(ids(&merged), [1, 3, 4]);
+    }
+
+    #[test]
+    fn unknown_root() {
+        let ex = Exchange::from_str(STEP).unwrap();
+        assert!(matches!(
+            Extract::new(&[60]).apply(&ex),
+            Err(Error::UnknownEntity(60))
+        ));
+    }
+}
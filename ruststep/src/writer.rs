@@ -0,0 +1,610 @@
+//! Write [ast](crate::ast) into exchange structure string
+//!
+//! This is the reverse of [parser]:
+//! [std::fmt::Display] is implemented for AST components,
+//! and [Writer] writes entire exchange structure with layout options.
+//!
+//! - Reals always have a decimal point, and very large or small values are written in exponent form
+//!   like `1.E-7`, since `1e-7` is not a valid real in ISO-10303-21.
+//!   NaN and infinity cannot be written, and [Writer] returns [Error::NonFiniteReal] for them.
+//! - Strings are written in the encoded form read from the exchange structure, see [StringLiteral::raw].
+//!   Strings created in Rust are encoded by [string::encode](crate::parser::string::encode),
+//!   i.e. `'` and `\` are escaped, and non-ASCII characters are written by `\X\`, `\X2\`, or `\X4\`.
+//!   The encoded form read with raw non-ASCII characters, e.g. `'ä'`, is also encoded again.
+//!
+//! ```
+//! use ruststep::{ast::*, writer::Writer};
+//!
+//! let step_str = r#"
+//! ISO-10303-21;
+//! HEADER;
+//! FILE_DESCRIPTION(('Example'), '2;1');
+//! FILE_NAME('example.step', '2021-01-01T00:00:00', ('A'), ('B'), 'ruststep', 'ruststep', '');
+//! FILE_SCHEMA(('EXAMPLE'));
+//! ENDSEC;
+//! DATA;
+//! #1 = A(1.0, 'it''s', (#2, $), .T.);
+//! #2 = (B(1.0E-7) C('\X2\9B546CD5\X0\'));
+//! #3 = D('C:\work\a.stp');
+//! ENDSEC;
+//! END-ISO-10303-21;
+//! "#.trim();
+//! let exchange: Exchange = step_str.parse().unwrap();
+//!
+//! let written = Writer::new().to_string(&exchange).unwrap();
+//! assert!(written.contains(r"#2 = (B(1.E-7) C('\X2\9B546CD5\X0\'));"));
+//! assert!(written.contains(r"#3 = D('C:\work\a.stp');"));
+//!
+//! // Written string can be parsed again
+//! assert_eq!(written.parse::<Exchange>().unwrap(), exchange);
+//! ```
+
+use crate::{
+    ast::*,
+    error::{Error, Result},
+    parser,
+};
+use std::{fmt, io};
+
+/// Write exchange structure with layout options
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Writer {
+    indent: usize,
+    max_width: Option<usize>,
+}
+
+impl Writer {
+    /// Writer without indentation and line wrapping
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indent statements in each section by `indent` spaces
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Wrap lines longer than `max_width` at the separators `,` of parameters
+    ///
+    /// Wrapped lines are indented one more level than the statement.
+    /// Since tokens are never split, a line can still be longer than `max_width`.
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Write entire exchange structure
+    ///
+    /// Each statement is written into `out` as soon as it is formatted,
+    /// so buffering, e.g. by [io::BufWriter], is left to the caller.
+    pub fn write<W: io::Write>(&self, out: W, exchange: &Exchange) -> Result<()> {
+        let mut stream = Stream(out);
+        self.write_exchange(&mut stream, exchange)?;
+        stream.0.flush()?;
+        Ok(())
+    }
+
+    /// Write entire exchange structure into a string
+    pub fn to_string(&self, exchange: &Exchange) -> Result<String> {
+        let mut out = String::new();
+        self.write_exchange(&mut out, exchange)?;
+        Ok(out)
+    }
+
+    /// Write a DATA section into a string
+    pub fn data_section_to_string(&self, data: &DataSection) -> Result<String> {
+        let mut out = String::new();
+        self.write_data_section(&mut out, data)?;
+        Ok(out)
+    }
+
+    fn write_exchange(&self, out: &mut impl Sink, exchange: &Exchange) -> Result<()> {
+        out.push_str("ISO-10303-21;\n")?;
+
+        out.push_str("HEADER;\n")?;
+        for record in &exchange.header {
+            check_parameter(&record.parameter)?;
+            self.write_statement(out, &format!("{};", record))?;
+        }
+        out.push_str("ENDSEC;\n")?;
+
+        if !exchange.anchor.is_empty() {
+            out.push_str("ANCHOR;\n")?;
+            for anchor in &exchange.anchor {
+                check_anchor_item(&anchor.item)?;
+                for (_tag, item) in &anchor.tags {
+                    check_anchor_item(item)?;
+                }
+                self.write_statement(out, &anchor.to_string())?;
+            }
+            out.push_str("ENDSEC;\n")?;
+        }
+
+        if !exchange.reference.is_empty() {
+            out.push_str("REFERENCE;\n")?;
+            for reference in &exchange.reference {
+                self.write_statement(out, &reference.to_string())?;
+            }
+            out.push_str("ENDSEC;\n")?;
+        }
+
+        for data in &exchange.data {
+            self.write_data_section(out, data)?;
+        }
+
+        out.push_str("END-ISO-10303-21;\n")?;
+
+        for signature in &exchange.signature {
+            out.push_str("SIGNATURE\n")?;
+            out.push_str(signature)?;
+            out.push_str("\nENDSEC;\n")?;
+        }
+        Ok(())
+    }
+
+    fn write_data_section(&self, out: &mut impl Sink, data: &DataSection) -> Result<()> {
+        if data.meta.is_empty() {
+            out.push_str("DATA;\n")?;
+        } else {
+            for p in &data.meta {
+                check_parameter(p)?;
+            }
+            out.push_str(&format!("DATA({});\n", Params(&data.meta)))?;
+        }
+        for entity in &data.entities {
+            match entity {
+                EntityInstance::Simple { record, .. } => check_parameter(&record.parameter)?,
+                EntityInstance::Complex { subsuper, .. } => {
+                    for record in &subsuper.0 {
+                        check_parameter(&record.parameter)?;
+                    }
+                }
+            }
+            self.write_statement(out, &entity.to_string())?;
+        }
+        out.push_str("ENDSEC;\n")
+    }
+
+    /// Write a statement with indentation and line wrapping
+    fn write_statement(&self, out: &mut impl Sink, statement: &str) -> Result<()> {
+        let indent = " ".repeat(self.indent);
+        let max_width = match self.max_width {
+            Some(max_width) => max_width,
+            None => {
+                out.push_str(&indent)?;
+                out.push_str(statement)?;
+                return out.push_str("\n");
+            }
+        };
+        let continuation = " ".repeat(self.indent + 2);
+        let mut line = indent;
+        let mut line_is_empty = true;
+        for segment in split_at_separators(statement) {
+            if !line_is_empty && line.len() + segment.trim_end().len() > max_width {
+                out.push_str(line.trim_end())?;
+                out.push_str("\n")?;
+                line = continuation.clone();
+                line.push_str(segment.trim_start());
+            } else {
+                line.push_str(segment);
+            }
+            line_is_empty = false;
+        }
+        out.push_str(&line)?;
+        out.push_str("\n")
+    }
+}
+
+/// Destination of [Writer]
+trait Sink {
+    fn push_str(&mut self, s: &str) -> Result<()>;
+}
+
+impl Sink for String {
+    fn push_str(&mut self, s: &str) -> Result<()> {
+        String::push_str(self, s);
+        Ok(())
+    }
+}
+
+/// Sink writing into [io::Write] directly
+struct Stream<W>(W);
+
+impl<W: io::Write> Sink for Stream<W> {
+    fn push_str(&mut self, s: &str) -> Result<()> {
+        self.0.write_all(s.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Check that the parameter can be written, i.e. it does not contain NaN or infinity
+fn check_parameter(parameter: &Parameter) -> Result<()> {
+    match parameter {
+        Parameter::Real(x) if !x.is_finite() => Err(Error::NonFiniteReal(*x)),
+        Parameter::Typed { parameter, .. } => check_parameter(parameter),
+        Parameter::List(list) => list.iter().try_for_each(check_parameter),
+        _ => Ok(()),
+    }
+}
+
+/// [check_parameter] for anchor items
+fn check_anchor_item(item: &AnchorItem) -> Result<()> {
+    match item {
+        AnchorItem::Real(x) if !x.is_finite() => Err(Error::NonFiniteReal(*x)),
+        AnchorItem::List(list) => list.iter().try_for_each(check_anchor_item),
+        _ => Ok(()),
+    }
+}
+
+/// Split a statement just after `,` found outside of strings
+fn split_at_separators(statement: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut begin = 0;
+    let mut in_string = false;
+    for (i, c) in statement.char_indices() {
+        match c {
+            '\'' => in_string = !in_string,
+            ',' if !in_string => {
+                segments.push(&statement[begin..=i]);
+                begin = i + 1;
+            }
+            _ => {}
+        }
+    }
+    segments.push(&statement[begin..]);
+    segments
+}
+
+/// Comma separated parameters
+struct Params<'a, T>(&'a [T]);
+
+impl<'a, T: fmt::Display> fmt::Display for Params<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, p) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", p)?;
+        }
+        Ok(())
+    }
+}
+
+/// Format real number as `REAL` token
+///
+/// Returns [fmt::Error] for NaN and infinity since they cannot be represented.
+/// [Writer] checks them in advance and returns [Error::NonFiniteReal] instead.
+fn fmt_string(f: &mut fmt::Formatter, s: &StringLiteral) -> fmt::Result {
+    let raw = s.raw();
+    if raw.chars().all(|c| (' '..='~').contains(&c)) {
+        write!(f, "'{}'", raw)
+    } else {
+        // Raw characters out of the basic alphabet are not valid in exchange structure
+        write!(f, "'{}'", parser::string::encode(s.as_str()))
+    }
+}
+
+fn fmt_real(f: &mut fmt::Formatter, x: f64) -> fmt::Result {
+    if !x.is_finite() {
+        return Err(fmt::Error);
+    }
+    // `Debug` gives the shortest representation which can be parsed back into same value
+    let s = format!("{:?}", x);
+    let (mantissa, exponent) = match s.find('e') {
+        Some(pos) => (&s[..pos], Some(&s[pos + 1..])),
+        None => (s.as_str(), None),
+    };
+    write!(f, "{}", mantissa)?;
+    if !mantissa.contains('.') {
+        write!(f, ".")?;
+    }
+    if let Some(exponent) = exponent {
+        write!(f, "E{}", exponent)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Name::Entity(id) => write!(f, "#{}", id),
+            Name::Value(id) => write!(f, "@{}", id),
+            Name::ConstantEntity(name) => write!(f, "#{}", name),
+            Name::ConstantValue(name) => write!(f, "@{}", name),
+        }
+    }
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Parameter::Typed { keyword, parameter } => write!(f, "{}({})", keyword, parameter),
+            Parameter::Integer(i) => write!(f, "{}", i),
+            Parameter::Real(x) => fmt_real(f, *x),
+            Parameter::String(s) => fmt_string(f, s),
+            Parameter::Enumeration(e) => write!(f, ".{}.", e),
+            Parameter::List(list) => write!(f, "({})", Params(list)),
+            Parameter::Ref(name) => write!(f, "{}", name),
+            Parameter::Binary(b) => write!(f, "\"{}\"", b),
+            Parameter::NotProvided => write!(f, "$"),
+            Parameter::Omitted => write!(f, "*"),
+        }
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.parameter {
+            Parameter::List(list) => write!(f, "{}({})", self.name, Params(list)),
+            parameter => write!(f, "{}({})", self.name, parameter),
+        }
+    }
+}
+
+impl fmt::Display for SubSuperRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, record) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", record)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl fmt::Display for EntityInstance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EntityInstance::Simple { id, record } => write!(f, "#{} = {};", id, record),
+            EntityInstance::Complex { id, subsuper } => write!(f, "#{} = {};", id, subsuper),
+        }
+    }
+}
+
+impl fmt::Display for DataSection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let written = Writer::new()
+            .data_section_to_string(self)
+            .map_err(|_| fmt::Error)?;
+        write!(f, "{}", written)
+    }
+}
+
+impl fmt::Display for URI {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{}>", self.0)
+    }
+}
+
+impl fmt::Display for ReferenceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} = {};", self.name, self.resource)
+    }
+}
+
+impl fmt::Display for AnchorItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnchorItem::Integer(i) => write!(f, "{}", i),
+            AnchorItem::Real(x) => fmt_real(f, *x),
+            AnchorItem::String(s) => fmt_string(f, s),
+            AnchorItem::Enumeration(e) => write!(f, ".{}.", e),
+            AnchorItem::NotProvided => write!(f, "$"),
+            AnchorItem::Binary(b) => write!(f, "\"{}\"", b),
+            AnchorItem::Name(name) => write!(f, "{}", name),
+            AnchorItem::List(list) => write!(f, "({})", Params(list)),
+        }
+    }
+}
+
+impl fmt::Display for Anchor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{}> = {}", self.name, self.item)?;
+        for (tag, item) in &self.tags {
+            write!(f, "{{{}:{}}}", tag, item)?;
+        }
+        write!(f, ";")
+    }
+}
+
+impl fmt::Display for Exchange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let written = Writer::new().to_string(self).map_err(|_| fmt::Error)?;
+        write!(f, "{}", written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn real(x: f64) -> String {
+        Parameter::Real(x).to_string()
+    }
+
+    #[test]
+    fn reals() {
+        assert_eq!(real(1.0), "1.0");
+        assert_eq!(real(-2.5), "-2.5");
+        assert_eq!(real(0.0), "0.0");
+        assert_eq!(real(1e-7), "1.E-7");
+        assert_eq!(real(1.5e300), "1.5E300");
+        for x in [1.0, -2.5, 1e-7, 1.5e300, 0.1, std::f64::consts::PI] {
+            let p: Parameter = real(x).parse().unwrap();
+            assert_eq!(p, Parameter::Real(x));
+        }
+    }
+
+    #[test]
+    fn parameters() {
+        for (input, expected) in [
+            ("'it''s'", "'it''s'"),
+            (r"'C:\\path'", r"'C:\\path'"),
+            (r"'\X2\9B546CD5\X0\'", r"'\X2\9B546CD5\X0\'"),
+            (r"'\S\D'", r"'\S\D'"),
+            (r"'C:\work'", r"'C:\work'"),
+            (r#""0ABC""#, r#""0ABC""#),
+            (
+                "(1, 2.5, $, *, .T., #1, @2, #CONST)",
+                "(1, 2.5, $, *, .T., #1, @2, #CONST)",
+            ),
+            ("A((1, 'a'))", "A((1, 'a'))"),
+        ] {
+            let p: Parameter = input.parse().unwrap();
+            assert_eq!(p.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn strings() {
+        // Strings created in Rust are encoded
+        for (s, expected) in [
+            ("it's", "'it''s'"),
+            (r"C:\work", r"'C:\\work'"),
+            ("Straße", r"'Stra\X\DFe'"),
+        ] {
+            assert_eq!(Parameter::string(s).to_string(), expected);
+        }
+
+        // and the encoded form read from exchange structure is kept
+        let input = r"
+ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('\PB\\S\o'), '2;1');
+FILE_NAME('C:\work\a.stp', '', ('\X\DF'), (''), 'ruststep', '\X2\9B54\X0\', '');
+FILE_SCHEMA(('EXAMPLE'));
+ENDSEC;
+ANCHOR;
+<PATH> = 'D:\data';
+ENDSEC;
+DATA;
+#1 = A('it''s \S\D', B('\X4\0001F600\X0\'), 'C:\\work');
+ENDSEC;
+END-ISO-10303-21;
+"
+        .trim_start();
+        let exchange: Exchange = input.parse().unwrap();
+        let written = Writer::new().to_string(&exchange).unwrap();
+        assert_eq!(written, input);
+
+        let read: Exchange = written.parse().unwrap();
+        assert_eq!(read, exchange);
+
+        // Raw non-ASCII characters are encoded
+        let exchange: Exchange = input
+            .replace(r"'D:\data'", "'Dä'")
+            .replace(r"'C:\\work'", "'魔法 ä'")
+            .parse()
+            .unwrap();
+        let written = Writer::new().to_string(&exchange).unwrap();
+        assert!(written.contains(r"<PATH> = 'D\X\E4';"));
+        assert!(written.contains(r"'\X2\9B546CD5\X0\ \X\E4');"));
+        assert!(written.is_ascii());
+        assert_eq!(written.parse::<Exchange>().unwrap(), exchange);
+
+        let read: Exchange = input.parse().unwrap();
+        assert_eq!(
+            read.header[1].parameter,
+            Parameter::List(vec![
+                Parameter::string(r"C:\work\a.stp"),
+                Parameter::string(""),
+                Parameter::List(vec![Parameter::string("ß")]),
+                Parameter::List(vec![Parameter::string("")]),
+                Parameter::string("ruststep"),
+                Parameter::string("魔"),
+                Parameter::string(""),
+            ])
+        );
+    }
+
+    #[test]
+    fn instances() {
+        for input in [
+            "#1 = A(1, 'a', B((2.0)));",
+            "#2 = (A(1) B() C((#1, #2)));",
+            "#3 = A();",
+        ] {
+            let e: EntityInstance = input.parse().unwrap();
+            assert_eq!(e.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn wrap() {
+        let data: DataSection = "DATA; #1 = A('a, b', 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11); ENDSEC;"
+            .parse()
+            .unwrap();
+        let written = Writer::new()
+            .indent(2)
+            .max_width(20)
+            .data_section_to_string(&data)
+            .unwrap();
+        assert_eq!(
+            written,
+            "DATA;\n  #1 = A('a, b', 1,\n    2, 3, 4, 5, 6,\n    7, 8, 9, 10,\n    11);\nENDSEC;\n"
+        );
+        assert_eq!(written.parse::<DataSection>().unwrap(), data);
+    }
+
+    #[test]
+    fn non_finite_reals() {
+        let mut exchange: Exchange = r"
+ISO-10303-21;
+HEADER;
+FILE_SCHEMA(('EXAMPLE'));
+ENDSEC;
+DATA;
+#1 = A(1.0);
+#2 = (B((2.0, 3.0)) C());
+ENDSEC;
+END-ISO-10303-21;
+"
+        .parse()
+        .unwrap();
+        for x in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            exchange.data[0].entities[1] = "#2 = (B((2.0, 3.0)) C());".parse().unwrap();
+            if let EntityInstance::Complex { subsuper, .. } = &mut exchange.data[0].entities[1] {
+                subsuper.0[0].parameter = Parameter::List(vec![Parameter::List(vec![
+                    Parameter::Real(2.0),
+                    Parameter::Real(x),
+                ])]);
+            }
+            match Writer::new().to_string(&exchange) {
+                Err(Error::NonFiniteReal(y)) => assert_eq!(y.to_bits(), x.to_bits()),
+                other => panic!("unexpected result: {:?}", other),
+            }
+            // Statements before the invalid one have been written into the stream
+            let mut out = Vec::new();
+            assert!(Writer::new().write(&mut out, &exchange).is_err());
+            let out = String::from_utf8(out).unwrap();
+            assert!(out.ends_with("DATA;\n#1 = A(1.0);\n"), "{}", out);
+        }
+    }
+
+    #[test]
+    fn stream() {
+        let exchange: Exchange = r"
+ISO-10303-21;
+HEADER;
+FILE_SCHEMA(('EXAMPLE'));
+ENDSEC;
+DATA;
+#1 = A(1.0, 'a');
+ENDSEC;
+END-ISO-10303-21;
+"
+        .trim_start()
+        .parse()
+        .unwrap();
+        let writer = Writer::new().indent(2);
+        let mut out = Vec::new();
+        writer.write(&mut out, &exchange).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            writer.to_string(&exchange).unwrap()
+        );
+    }
+}
@@ -41,3 +41,51 @@ fn abc_dataset() -> anyhow::Result<()> {
     dbg!(exchange);
     Ok(())
 }
+
+#[test]
+fn abc_dataset_stream() -> anyhow::Result<()> {
+    let step_str = format_example()?;
+    let (_residual, exchange) = parser::exchange::exchange_file(&step_str).finish().unwrap();
+
+    let reader = parser::stream::Reader::new(step_str.as_bytes())?;
+    assert_eq!(reader.header_records(), exchange.header.as_slice());
+    let instances = reader.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(instances, exchange.data[0].entities);
+    Ok(())
+}
+
+#[test]
+fn abc_dataset_borrowed() -> anyhow::Result<()> {
+    for line in format_example()?
+        .lines()
+        .skip(7 /* lines before DATA; */)
+        .take(1671 /* lines in DATA secition */)
+    {
+        let owned: ruststep::ast::EntityInstance = line.parse()?;
+        let borrowed = ruststep::ast::borrowed::EntityInstance::parse(line)?;
+        assert_eq!(borrowed.into_owned(), owned);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn abc_dataset_parallel() -> anyhow::Result<()> {
+    let step_str = format_example()?;
+    let expected = parser::parse(&step_str)?;
+    let exchange = parser::parallel::parse(&step_str)?;
+    assert_eq!(exchange, expected);
+    Ok(())
+}
+
+#[test]
+fn abc_dataset_write() -> anyhow::Result<()> {
+    let step_str = format_example()?;
+    let exchange = parser::parse(&step_str)?;
+    let written = ruststep::writer::Writer::new()
+        .indent(2)
+        .max_width(80)
+        .to_string(&exchange)?;
+    assert_eq!(parser::parse(&written)?, exchange);
+    Ok(())
+}
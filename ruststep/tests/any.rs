@@ -1,8 +1,15 @@
 use nom::Finish;
-use ruststep::{ast::*, parser::exchange, tables::*};
+use ruststep::{
+    ast::{ser::*, *},
+    parser::exchange,
+    tables::*,
+};
 use serde::Deserialize;
 use std::str::FromStr;
 
+mod common;
+use common::roundtrip;
+
 espr_derive::inline_express!(
     r#"
     SCHEMA test_schema;
@@ -261,3 +268,17 @@ fn as_ref_base_any() {
     let sub3 = EntityTable::<Sub2Holder>::get_owned(&table, 3).unwrap();
     assert_eq!(sub3.as_ref(), &Base { x: 1.0 });
 }
+
+#[test]
+fn serialize_base_any() {
+    roundtrip::<BaseAnyHolder>("BASE(1.0)");
+    roundtrip::<BaseAnyHolder>("SUB_1(BASE((1.0)), 2.0)");
+    roundtrip::<BaseAnyHolder>("SUB_2(#3, 4.0)");
+    roundtrip::<PlaceHolder<BaseAnyHolder>>("SUB_1(#3, 2.0)");
+
+    // Reference is kept as is
+    assert_eq!(
+        to_parameter(&PlaceHolder::<BaseAnyHolder>::Ref(Name::Entity(1))).unwrap(),
+        Parameter::Ref(Name::Entity(1))
+    );
+}
@@ -0,0 +1,64 @@
+use ruststep::{
+    ast::{ser::*, *},
+    primitive::Binary,
+    tables::*,
+};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE flags = BINARY(8) FIXED;
+      END_TYPE;
+
+      ENTITY a;
+        x: BINARY(16) FIXED;
+        y: OPTIONAL BINARY;
+        z: flags;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+#[test]
+fn deserialize_binary_attribute() {
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = A("0ABCD", "315", #3);
+          #2 = A("00000", $, #4);
+          #3 = FLAGS("0FF");
+          #4 = FLAGS("080");
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+
+    let a: A = EntityTable::<AHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(a.x, Binary::from(vec![0xAB, 0xCD]));
+    assert_eq!(
+        a.y,
+        Some(Binary::from_bits([true, false, true, false, true]))
+    );
+    assert_eq!(a.z, Flags(Binary::from(vec![0xFF])));
+
+    let a: A = EntityTable::<AHolder>::get_owned(&table, 2).unwrap();
+    assert_eq!(a.x, Binary::from(vec![0, 0]));
+    assert_eq!(a.y, None);
+    assert_eq!(a.z, Flags(Binary::from(vec![0x80])));
+}
+
+#[test]
+fn serialize_binary_attribute() {
+    let a = A {
+        x: Binary::from(vec![0xAB, 0xCD]),
+        y: Some(Binary::from_bits([true, false, true, false, true])),
+        z: Flags(Binary::from(vec![0xFF])),
+    };
+    assert_eq!(
+        to_record(&a).unwrap(),
+        Record::from_str(r#"A("0ABCD", "315", FLAGS(("0FF")))"#).unwrap()
+    );
+}
@@ -1,4 +1,7 @@
-use ruststep::{ast::*, primitive::*};
+use ruststep::{
+    ast::{ser::*, *},
+    primitive::*,
+};
 use serde::Deserialize;
 
 fn sub_deserialize<T>(param: &str, ans: T)
@@ -33,3 +36,25 @@ fn deserialize_logical() {
     let p = Parameter::Enumeration("Q".to_string());
     assert!(Logical::deserialize(&p).is_err());
 }
+
+#[test]
+fn serialize_bool_logical() {
+    for (value, ans) in [(true, "T"), (false, "F")] {
+        assert_eq!(
+            to_parameter(&value).unwrap(),
+            Parameter::Enumeration(ans.to_string())
+        );
+        sub_deserialize(ans, value);
+    }
+    for (value, ans) in [
+        (Logical::True, "T"),
+        (Logical::False, "F"),
+        (Logical::Unknown, "U"),
+    ] {
+        assert_eq!(
+            to_parameter(&value).unwrap(),
+            Parameter::Enumeration(ans.to_string())
+        );
+        sub_deserialize(ans, value);
+    }
+}
@@ -0,0 +1,15 @@
+//! Helpers shared by integration tests
+
+use ruststep::ast::{ser::to_record, Record};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Deserialize `input` into `T`, and check it is serialized back into the same record
+pub fn roundtrip<T>(input: &str)
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let p = Record::from_str(input).unwrap();
+    let holder = T::deserialize(&p).unwrap();
+    assert_eq!(to_record(&holder).unwrap(), p);
+}
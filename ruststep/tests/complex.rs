@@ -0,0 +1,156 @@
+use ruststep::{ast::DataSection, error::Error, tables::*};
+use std::{collections::HashMap, str::FromStr};
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY person SUPERTYPE OF (employee ANDOR student);
+        name: STRING;
+      END_ENTITY;
+
+      ENTITY employee SUBTYPE OF (person);
+        pay: INTEGER;
+      END_ENTITY;
+
+      ENTITY student SUBTYPE OF (person);
+        school_name: STRING;
+      END_ENTITY;
+
+      ENTITY team;
+        members: LIST [1:?] OF person;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = PERSON('Hitori Goto');
+  #2 = (EMPLOYEE(15) PERSON('Nizika Iziti') STUDENT('Simokitazawa'));
+  #3 = EMPLOYEE(PERSON(('Ryo Yamada')), 10);
+  #4 = TEAM((#1, #2, #3));
+ENDSEC;
+"#;
+
+fn nizika() -> (Employee, Student) {
+    let person = Person {
+        name: "Nizika Iziti".to_string(),
+    };
+    (
+        Employee {
+            person: person.clone(),
+            pay: 15,
+        },
+        Student {
+            person,
+            school_name: "Simokitazawa".to_string(),
+        },
+    )
+}
+
+#[test]
+fn get_owned_complex() {
+    for table in [
+        Tables::from_str(EXAMPLE).unwrap(),
+        Tables::from_data_section(&DataSection::from_str(EXAMPLE).unwrap()).unwrap(),
+    ] {
+        let (employee, student) = nizika();
+        assert_eq!(
+            EntityTable::<EmployeeHolder>::get_owned(&table, 2).unwrap(),
+            employee
+        );
+        assert_eq!(
+            EntityTable::<StudentHolder>::get_owned(&table, 2).unwrap(),
+            student
+        );
+        // Partial record of supertype is not stored separately
+        assert!(matches!(
+            EntityTable::<PersonHolder>::get_owned(&table, 2),
+            Err(Error::UnknownEntity(2))
+        ));
+    }
+}
+
+#[test]
+fn lookup_any() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let any = EntityTable::<PersonAnyHolder>::get_owned(&table, 2).unwrap();
+    assert_eq!(any, PersonAny::EmployeeAndStudent(Box::new(nizika())));
+    assert_eq!(any.as_ref().name, "Nizika Iziti");
+
+    // Simple instance of a subtype is not regarded as complex one
+    let any = EntityTable::<PersonAnyHolder>::get_owned(&table, 3).unwrap();
+    assert!(matches!(any, PersonAny::Employee(_)));
+
+    // Complex entity instance appears once in iteration
+    let ids: Vec<u64> = EntityTable::<PersonAnyHolder>::owned_iter_with_id(&table)
+        .map(|owned| owned.unwrap().0)
+        .collect();
+    assert_eq!(ids, [1, 2, 3]);
+
+    // View looks up all the components
+    match PersonAnyHolder::view_id(&table, 2).unwrap() {
+        PersonAnyView::EmployeeAndStudent((employee, student)) => {
+            assert_eq!(employee.holder.pay, 15);
+            assert_eq!(student.holder.school_name, "Simokitazawa");
+        }
+        view => panic!("unexpected view: {:?}", view),
+    }
+
+    let team = EntityTable::<TeamHolder>::get_owned(&table, 4).unwrap();
+    assert_eq!(team.members.len(), 3);
+    assert_eq!(
+        team.members
+            .iter()
+            .map(|member| member.as_ref().name.as_str())
+            .collect::<Vec<_>>(),
+        ["Hitori Goto", "Nizika Iziti", "Ryo Yamada"]
+    );
+    assert!(matches!(team.members[1], PersonAny::EmployeeAndStudent(_)));
+}
+
+#[test]
+fn to_data_section_complex() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let data = table.to_data_section().unwrap();
+    assert_eq!(data, DataSection::from_str(EXAMPLE).unwrap());
+}
+
+#[test]
+fn invalid_complex() {
+    // Partial record of supertype is missing
+    assert!(matches!(
+        Tables::from_str("DATA; #1 = (EMPLOYEE(15) STUDENT('Simokitazawa')); ENDSEC;"),
+        Err(Error::InvalidComplexEntity(_))
+    ));
+    // Unknown partial record
+    assert!(matches!(
+        Tables::from_str("DATA; #1 = (EMPLOYEE(15) PERSON('Kita') TEACHER(1)); ENDSEC;"),
+        Err(Error::UnknownEntityName { .. })
+    ));
+}
+
+#[derive(Default, ruststep_derive::TableInit)]
+struct TupleTables(
+    HashMap<u64, PersonHolder>,
+    HashMap<u64, EmployeeHolder>,
+    HashMap<u64, StudentHolder>,
+    HashMap<u64, TeamHolder>,
+);
+
+#[test]
+fn tuple_table_init_complex() {
+    let tables = TupleTables::from_str(EXAMPLE).unwrap();
+    assert_eq!(tables.0.keys().collect::<Vec<_>>(), [&1]);
+    assert_eq!(tables.1[&2].pay, 15);
+    assert_eq!(tables.2[&2].school_name, "Simokitazawa");
+    assert!(tables.1.contains_key(&3));
+    assert!(tables.3.contains_key(&4));
+
+    assert!(matches!(
+        TupleTables::from_str("DATA; #1 = (EMPLOYEE(15) STUDENT('Simokitazawa')); ENDSEC;"),
+        Err(Error::InvalidComplexEntity(_))
+    ));
+}
@@ -0,0 +1,201 @@
+// Test for cycle detection and memoization in IntoOwned
+
+use ruststep::{ast::Name, error::*, tables::*};
+use std::{cell::Cell, collections::HashMap, str::FromStr};
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY node;
+        next: OPTIONAL item;
+      END_ENTITY;
+
+      TYPE item = SELECT (node, point);
+      END_TYPE;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = POINT(0.0, 0.0);
+  #2 = NODE(#1);
+  #3 = NODE(#2);
+  #4 = NODE(#5);
+  #5 = NODE(#6);
+  #6 = NODE(#4);
+  #7 = NODE(#7);
+ENDSEC;
+"#;
+
+#[test]
+fn acyclic() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let node = EntityTable::<NodeHolder>::get_owned(&table, 3).unwrap();
+    let expected = Node {
+        next: Some(Item::Node(Box::new(Node {
+            next: Some(Item::Point(Box::new(Point { x: 0.0, y: 0.0 }))),
+        }))),
+    };
+    assert_eq!(node, expected);
+    assert_eq!(
+        EntityTable::<NodeHolder>::get_owned_in(&table, 3, &mut Resolution::with_memo()).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn cyclic() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let err = EntityTable::<NodeHolder>::get_owned(&table, 4).unwrap_err();
+    assert!(matches!(&err, Error::CyclicOwnership(ids) if ids == &[4, 5, 6, 4]));
+    assert_eq!(
+        err.to_string(),
+        "Cyclic reference while converting into owned value: #4 -> #5 -> #6 -> #4"
+    );
+    let err = EntityTable::<NodeHolder>::get_owned(&table, 5).unwrap_err();
+    assert!(matches!(err, Error::CyclicOwnership(ids) if ids == [5, 6, 4, 5]));
+    let err = EntityTable::<NodeHolder>::get_owned(&table, 7).unwrap_err();
+    assert!(matches!(err, Error::CyclicOwnership(ids) if ids == [7, 7]));
+
+    // Failure of a cycle does not affect the others
+    let nodes: Vec<_> = EntityTable::<NodeHolder>::owned_iter(&table).collect();
+    assert_eq!(nodes.len(), 6);
+    assert_eq!(nodes.iter().filter(|node| node.is_ok()).count(), 2);
+}
+
+// Hand-written table counting the conversions of leaves
+#[derive(Debug, Default)]
+struct Counter {
+    leaf: HashMap<u64, Leaf>,
+    pair: HashMap<u64, Pair>,
+}
+
+thread_local! {
+    static CONVERTED: Cell<usize> = Cell::new(0);
+}
+
+#[derive(Debug, Clone)]
+struct Leaf(i64);
+
+impl IntoOwned for Leaf {
+    type Owned = i64;
+    type Table = Counter;
+    fn into_owned_in(self, _table: &Counter, _resolution: &mut Resolution) -> Result<i64> {
+        CONVERTED.with(|c| c.set(c.get() + 1));
+        Ok(self.0)
+    }
+}
+
+impl Holder for Leaf {
+    fn name() -> &'static str {
+        "LEAF"
+    }
+    fn attr_len() -> usize {
+        1
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Pair(PlaceHolder<Leaf>, PlaceHolder<Leaf>);
+
+impl IntoOwned for Pair {
+    type Owned = (i64, i64);
+    type Table = Counter;
+    fn into_owned_in(self, table: &Counter, resolution: &mut Resolution) -> Result<(i64, i64)> {
+        Ok((
+            self.0.into_owned_in(table, resolution)?,
+            self.1.into_owned_in(table, resolution)?,
+        ))
+    }
+}
+
+impl Holder for Pair {
+    fn name() -> &'static str {
+        "PAIR"
+    }
+    fn attr_len() -> usize {
+        2
+    }
+}
+
+impl EntityTable<Leaf> for Counter {
+    fn get_owned_in(&self, entity_id: u64, resolution: &mut Resolution) -> Result<i64> {
+        get_owned_in(self, &self.leaf, entity_id, resolution)
+    }
+    fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = Result<i64>> + 'table> {
+        owned_iter(self, &self.leaf)
+    }
+    fn owned_iter_with_id<'table>(
+        &'table self,
+    ) -> Box<dyn Iterator<Item = Result<(u64, i64)>> + 'table> {
+        owned_iter_with_id(self, &self.leaf)
+    }
+}
+
+impl EntityTable<Pair> for Counter {
+    fn get_owned_in(&self, entity_id: u64, resolution: &mut Resolution) -> Result<(i64, i64)> {
+        get_owned_in(self, &self.pair, entity_id, resolution)
+    }
+    fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = Result<(i64, i64)>> + 'table> {
+        owned_iter(self, &self.pair)
+    }
+    fn owned_iter_with_id<'table>(
+        &'table self,
+    ) -> Box<dyn Iterator<Item = Result<(u64, (i64, i64))>> + 'table> {
+        owned_iter_with_id(self, &self.pair)
+    }
+}
+
+fn converted() -> usize {
+    CONVERTED.with(|c| c.replace(0))
+}
+
+#[test]
+fn memo() {
+    let mut table = Counter::default();
+    table.leaf.insert(1, Leaf(10));
+    table.leaf.insert(2, Leaf(20));
+    for id in 3..6 {
+        table.pair.insert(
+            id,
+            Pair(
+                PlaceHolder::Ref(Name::Entity(1)),
+                PlaceHolder::Ref(Name::Entity(1)),
+            ),
+        );
+    }
+    table.pair.insert(
+        6,
+        Pair(
+            PlaceHolder::Ref(Name::Entity(2)),
+            PlaceHolder::Owned(Leaf(30)),
+        ),
+    );
+
+    let pairs: Vec<(i64, i64)> = EntityTable::<Pair>::owned_iter(&table)
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(pairs.len(), 4);
+    assert_eq!(converted(), 8);
+
+    let mut resolution = Resolution::with_memo();
+    let memoized: Vec<(i64, i64)> = (3..7)
+        .map(|id| EntityTable::<Pair>::get_owned_in(&table, id, &mut resolution))
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(memoized.len(), 4);
+    // #1 and #2 once, and the inline leaf in #6
+    assert_eq!(converted(), 3);
+
+    // Converted again in a new resolution
+    assert_eq!(EntityTable::<Pair>::get_owned(&table, 6).unwrap(), (20, 30));
+    assert_eq!(converted(), 2);
+}
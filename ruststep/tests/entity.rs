@@ -1,11 +1,18 @@
 // Test for deserializing Holder structs
 
-use ruststep::{ast::*, parser::exchange, tables::*};
+use ruststep::{
+    ast::{ser::*, *},
+    parser::exchange,
+    tables::*,
+};
 
 use nom::Finish;
 use serde::Deserialize;
 use std::str::FromStr;
 
+mod common;
+use common::roundtrip;
+
 espr_derive::inline_express!(
     r#"
     SCHEMA test_schema;
@@ -156,3 +163,23 @@ fn get_owned_b3() {
         }
     );
 }
+
+#[test]
+fn serialize_holder() {
+    roundtrip::<AHolder>("A(1.0, 2.0)");
+    roundtrip::<PlaceHolder<AHolder>>("A(1.0, 2.0)");
+    roundtrip::<BHolder>("B(1.0, A((2.0, 3.0)))");
+    roundtrip::<BHolder>("B(1.0, #2)");
+}
+
+#[test]
+fn serialize_owned() {
+    let b = B {
+        z: 1.0,
+        a: A { x: 2.0, y: 3.0 },
+    };
+    assert_eq!(
+        to_record(&b).unwrap(),
+        Record::from_str("B(1.0, A((2.0, 3.0)))").unwrap()
+    );
+}
@@ -0,0 +1,129 @@
+// Test for entity ids of owned values
+
+use ruststep::{error::*, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY circle;
+        center: point;
+        radius: REAL;
+      END_ENTITY;
+
+      TYPE shape = SELECT (point, circle);
+      END_TYPE;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #30 = POINT(3.0, 0.0);
+  #4 = CIRCLE(#2, 1.0);
+  #2 = POINT(2.0, 0.0);
+  #11 = CIRCLE(POINT((1.0, 1.0)), 2.0);
+  #1 = POINT(1.0, 0.0);
+ENDSEC;
+"#;
+
+#[test]
+fn owned_iter_with_id() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+
+    let points: Vec<(u64, Point)> = EntityTable::<PointHolder>::owned_iter_with_id(&table)
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(
+        points,
+        [
+            (1, Point { x: 1.0, y: 0.0 }),
+            (2, Point { x: 2.0, y: 0.0 }),
+            (30, Point { x: 3.0, y: 0.0 }),
+        ]
+    );
+
+    // Items of a select type are merged in ascending order
+    let ids: Vec<u64> = EntityTable::<ShapeHolder>::owned_iter_with_id(&table)
+        .map(|shape| shape.map(|(id, _shape)| id))
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(ids, [1, 2, 4, 11, 30]);
+
+    // `owned_iter` is also in ascending order
+    let xs: Vec<f64> = EntityTable::<PointHolder>::owned_iter(&table)
+        .map(|point| point.map(|point| point.x))
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(xs, [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn identified() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+
+    let circle = table.circle_holders()[&4].clone();
+    let center = circle.center.into_identified(&table).unwrap();
+    assert_eq!(center.id, Some(2));
+    assert_eq!(center.x, 2.0);
+
+    let circle = CircleHolder::view_id(&table, 11).unwrap();
+    let center = circle.center().to_identified().unwrap();
+    assert_eq!(center.id, None);
+    assert_eq!(center.into_inner(), Point { x: 1.0, y: 1.0 });
+}
+
+mod nested {
+    use ruststep::tables::*;
+    use ruststep_derive::{as_holder, Holder, TableInit};
+    use std::{collections::HashMap, str::FromStr};
+
+    #[derive(TableInit, Default)]
+    pub struct Table {
+        point: HashMap<u64, as_holder!(Point)>,
+        polyline: HashMap<u64, as_holder!(Polyline)>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Holder)]
+    #[holder(table = Table)]
+    #[holder(field = point)]
+    #[holder(generate_deserialize)]
+    pub struct Point {
+        pub x: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Holder)]
+    #[holder(table = Table)]
+    #[holder(field = polyline)]
+    #[holder(generate_deserialize)]
+    pub struct Polyline {
+        #[holder(use_place_holder)]
+        pub start: Identified<Point>,
+        #[holder(use_place_holder)]
+        pub points: Vec<Identified<Point>>,
+        #[holder(use_place_holder)]
+        pub end: Option<Identified<Point>>,
+    }
+
+    #[test]
+    fn identified_field() {
+        let table = Table::from_str(
+            "DATA; #1 = POINT(1.0); #2 = POINT(2.0); #3 = POLYLINE(#1, (#2, POINT((3.0))), $); ENDSEC;",
+        )
+        .unwrap();
+        let polyline = EntityTable::<PolylineHolder>::get_owned(&table, 3).unwrap();
+        assert_eq!(polyline.start.id, Some(1));
+        assert_eq!(polyline.start.x, 1.0);
+        let ids: Vec<Option<u64>> = polyline.points.iter().map(|p| p.id).collect();
+        assert_eq!(ids, [Some(2), None]);
+        assert_eq!(polyline.points[1].x, 3.0);
+        assert_eq!(polyline.end, None);
+    }
+}
@@ -1,8 +1,11 @@
 use nom::Finish;
-use ruststep::{ast::*, parser::exchange};
+use ruststep::{
+    ast::{ser::*, *},
+    parser::exchange,
+};
 use serde::Deserialize;
 
-#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 enum MagicaQuartet {
     MamiTomoe,
     MadokaKaname,
@@ -21,3 +24,11 @@ fn enum_deserialize() {
     let a: MagicaQuartet = Deserialize::deserialize(&p).unwrap();
     assert_eq!(a, MagicaQuartet::HomuraAkemi);
 }
+
+#[test]
+fn enum_serialize() {
+    let p = to_parameter(&MagicaQuartet::HomuraAkemi).unwrap();
+    assert_eq!(p, Parameter::Enumeration("HOMURA_AKEMI".to_string()));
+    let a: MagicaQuartet = Deserialize::deserialize(&p).unwrap();
+    assert_eq!(a, MagicaQuartet::HomuraAkemi);
+}
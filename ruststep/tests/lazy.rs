@@ -0,0 +1,115 @@
+// Test for lazy tables compared with the tables loading all entity instances
+
+use ruststep::{ast::*, lazy::LazyTables, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY a;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY b;
+        z: REAL;
+        a: a;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION((''), '2;1');
+  FILE_NAME('', '', (''), (''), '', '', '');
+  FILE_SCHEMA(('TEST_SCHEMA'));
+ENDSEC;
+DATA;
+  #1 = A(0.0, 1.0);
+  #2 = A(2.0, 3.0);
+  #3 = B(4.0, #1);
+  #4 = B(5.0, A((6.0, 7.0)));
+  #5 = B(8.0, #2);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+#[test]
+fn same_as_tables() {
+    let exchange = Exchange::from_str(EXAMPLE).unwrap();
+    let tables = Tables::from_exchange(&exchange).unwrap();
+    let lazy = LazyTables::new(EXAMPLE.as_bytes()).unwrap();
+
+    for id in [3, 4, 5] {
+        assert_eq!(
+            lazy.get_owned::<BHolder>(id).unwrap(),
+            EntityTable::<BHolder>::get_owned(&tables, id).unwrap()
+        );
+    }
+    assert!(lazy.get_owned::<BHolder>(6).is_err());
+
+    // Holders loaded above are reused, and loaded again after clear
+    lazy.clear_tables();
+    assert_eq!(
+        lazy.get_owned::<BHolder>(5).unwrap(),
+        EntityTable::<BHolder>::get_owned(&tables, 5).unwrap()
+    );
+
+    let a: Vec<A> = lazy.owned_iter::<AHolder>().map(|a| a.unwrap()).collect();
+    assert_eq!(a, [A { x: 0.0, y: 1.0 }, A { x: 2.0, y: 3.0 }]);
+    let b: Vec<B> = lazy.owned_iter::<BHolder>().map(|b| b.unwrap()).collect();
+    assert_eq!(b.len(), 3);
+    let ids: Vec<u64> = lazy
+        .owned_iter_with_id::<BHolder>()
+        .map(|b| b.unwrap().0)
+        .collect();
+    assert_eq!(ids.len(), 3);
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(
+        b[1],
+        B {
+            z: 5.0,
+            a: A { x: 6.0, y: 7.0 }
+        }
+    );
+}
+
+#[test]
+fn cache_capacity() {
+    let lazy = LazyTables::new(EXAMPLE.as_bytes()).unwrap();
+    assert_eq!(lazy.owned_iter::<BHolder>().count(), 3);
+    assert_eq!(lazy.loaded_len(), 5);
+
+    let lazy = LazyTables::new(EXAMPLE.as_bytes())
+        .unwrap()
+        .cache_capacity(2);
+    lazy.get_owned::<BHolder>(3).unwrap();
+    assert_eq!(lazy.loaded_len(), 2);
+    // `#1` is already in the table shared with `BHolder`
+    lazy.get_owned::<AHolder>(1).unwrap();
+    assert_eq!(lazy.loaded_len(), 2);
+    // The table is dropped, and refilled by `#2` and `#5`
+    assert_eq!(
+        lazy.get_owned::<BHolder>(5).unwrap(),
+        B {
+            z: 8.0,
+            a: A { x: 2.0, y: 3.0 }
+        }
+    );
+    assert_eq!(lazy.loaded_len(), 2);
+    for b in lazy.owned_iter::<BHolder>() {
+        b.unwrap();
+        assert!(lazy.loaded_len() <= 2);
+    }
+}
+
+#[test]
+fn sync() {
+    fn assert_sync<T: Sync>(_: &T) {}
+    let lazy = LazyTables::new(EXAMPLE.as_bytes()).unwrap();
+    assert_sync(&lazy);
+}
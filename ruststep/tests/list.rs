@@ -2,6 +2,9 @@ use nom::Finish;
 use ruststep::{ast::*, parser::exchange, tables::*};
 use serde::Deserialize;
 
+mod common;
+use common::roundtrip;
+
 espr_derive::inline_express!(
     r#"
     SCHEMA test_schema;
@@ -71,3 +74,11 @@ fn deserialize_list_c() {
         }
     );
 }
+
+#[test]
+fn serialize_list() {
+    roundtrip::<AHolder>("A((1.0, 2.0))");
+    roundtrip::<BHolder>("B((A(((1.0))), #2))");
+    roundtrip::<CHolder>("C(((A(((1.0)))), (A(((2.0))))))");
+    roundtrip::<DHolder>("D((((#1, #2)), ((A(((3.0)))))))");
+}
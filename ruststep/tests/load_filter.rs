@@ -0,0 +1,111 @@
+// Test for loading selected entity instances by LoadFilter
+
+use ruststep::{
+    ast::{borrowed, Exchange, Name},
+    error::*,
+    tables::*,
+};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY product;
+        name: STRING;
+        shape: point;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = POINT(0.0, 1.0);
+  #2 = PRODUCT('bolt', #1);
+  #3 = UNKNOWN_GEOMETRY(#1);
+  #4 = PRODUCT('nut', POINT((2.0, 3.0)));
+ENDSEC;
+"#;
+
+#[test]
+fn allow() {
+    let section = borrowed::DataSection::parse(EXAMPLE.trim()).unwrap();
+    assert!(Tables::from_borrowed_data_section(&section).is_err());
+
+    let table =
+        Tables::from_borrowed_data_section_filtered(&section, &LoadFilter::allow(["product"]))
+            .unwrap();
+    assert!(table.point_holders().is_empty());
+    assert_eq!(table.product_holders().len(), 2);
+
+    // Reference to the skipped instance is kept as entity id
+    let bolt = &table.product_holders()[&2];
+    assert_eq!(bolt.shape, PlaceHolder::Ref(Name::Entity(1)));
+    assert!(matches!(
+        EntityTable::<ProductHolder>::get_owned(&table, 2),
+        Err(Error::UnknownEntity(1))
+    ));
+    // while inline values are loaded
+    assert_eq!(
+        EntityTable::<ProductHolder>::get_owned(&table, 4).unwrap(),
+        Product {
+            name: "nut".to_string(),
+            shape: Point { x: 2.0, y: 3.0 },
+        }
+    );
+}
+
+#[test]
+fn predicate() {
+    let section = ruststep::ast::DataSection::from_str(EXAMPLE.trim()).unwrap();
+    let mut table = Tables::default();
+    table
+        .append_data_section_filtered(
+            &section,
+            &LoadFilter::predicate(|keyword| keyword != "UNKNOWN_GEOMETRY"),
+        )
+        .unwrap();
+    assert_eq!(table.point_holders().len(), 1);
+    assert_eq!(table.product_holders().len(), 2);
+    assert_eq!(
+        EntityTable::<ProductHolder>::get_owned(&table, 2).unwrap(),
+        Product {
+            name: "bolt".to_string(),
+            shape: Point { x: 0.0, y: 1.0 },
+        }
+    );
+}
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    assert_send_sync(&LoadFilter::predicate(|keyword| keyword.len() < 8));
+}
+
+#[test]
+fn exchange() {
+    let exchange = Exchange::from_str(&format!(
+        r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION((''), '2;1');
+  FILE_NAME('', '', (''), (''), '', '', '');
+  FILE_SCHEMA(('TEST_SCHEMA'));
+ENDSEC;
+{}
+END-ISO-10303-21;
+"#,
+        EXAMPLE.trim()
+    ))
+    .unwrap();
+    let table = Tables::from_exchange_filtered(&exchange, &LoadFilter::allow(["POINT"])).unwrap();
+    assert_eq!(table.point_holders().len(), 1);
+    assert!(table.product_holders().is_empty());
+}
@@ -0,0 +1,146 @@
+// Test for value instance names and constant names resolved through ANCHOR and REFERENCE sections
+
+use ruststep::{ast::*, error::Error, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY a;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY b;
+        z: REAL;
+        a: a;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION((''), '2;1');
+  FILE_NAME('', '', (''), (''), '', '', '');
+  FILE_SCHEMA(('TEST_SCHEMA'));
+ENDSEC;
+ANCHOR;
+  <ORIGIN> = #1;
+  <SCALE> = 2.0;
+ENDSEC;
+REFERENCE;
+  @1 = <#SCALE>;
+  #4 = <#ORIGIN>;
+  #5 = <other.step#A>;
+ENDSEC;
+DATA;
+  #1 = A(0.0, 0.0);
+  #2 = B(@1, #ORIGIN);
+  #3 = B(@SCALE, #4);
+  #6 = B(1.0, #5);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+#[test]
+fn resolve_names() {
+    let exchange = Exchange::from_str(EXAMPLE).unwrap();
+    let table = Tables::from_exchange(&exchange).unwrap();
+    let expected = B {
+        z: 2.0,
+        a: A { x: 0.0, y: 0.0 },
+    };
+    assert_eq!(
+        EntityTable::<BHolder>::get_owned(&table, 2).unwrap(),
+        expected
+    );
+    assert_eq!(
+        EntityTable::<BHolder>::get_owned(&table, 3).unwrap(),
+        expected
+    );
+
+    // `#5` is in other file
+    assert!(matches!(
+        EntityTable::<BHolder>::get_owned(&table, 6),
+        Err(Error::UnknownEntity(5))
+    ));
+}
+
+#[test]
+fn resolve_instance() {
+    let exchange = Exchange::from_str(EXAMPLE).unwrap();
+    let names = NameTable::from_exchange(&exchange);
+    let resolved: Vec<_> = exchange.data[0]
+        .entities
+        .iter()
+        .map(|instance| names.resolve_instance(instance).unwrap())
+        .collect();
+    // Only the instances referring names defined in this file are copied
+    let copied: Vec<bool> = resolved
+        .iter()
+        .map(|instance| matches!(instance, std::borrow::Cow::Owned(_)))
+        .collect();
+    assert_eq!(copied, [false, true, true, false]);
+    assert_eq!(
+        *resolved[1],
+        EntityInstance::from_str("#2 = B(2.0, #1);").unwrap()
+    );
+}
+
+#[test]
+fn unresolved_name() {
+    let exchange = Exchange::from_str(&EXAMPLE.replace("#ORIGIN);", "#MISSING);")).unwrap();
+    let table = Tables::from_exchange(&exchange).unwrap();
+    assert!(matches!(
+        EntityTable::<BHolder>::get_owned(&table, 2),
+        Err(Error::UnresolvedName(name)) if name == "#MISSING"
+    ));
+
+    // Anchors referring each other
+    let exchange = Exchange::from_str(&EXAMPLE.replace("<SCALE> = 2.0;", "<SCALE> = @1;")).unwrap();
+    assert!(matches!(
+        Tables::from_exchange(&exchange),
+        Err(Error::UnresolvedName(name)) if name == "@1"
+    ));
+}
+
+#[test]
+fn resolve_other_file() {
+    let dir = std::env::temp_dir().join(format!("ruststep-names-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("example.step"), EXAMPLE).unwrap();
+    std::fs::write(
+        dir.join("other.step"),
+        r#"
+        ISO-10303-21;
+        HEADER;
+          FILE_DESCRIPTION((''), '2;1');
+          FILE_NAME('', '', (''), (''), '', '', '');
+          FILE_SCHEMA(('TEST_SCHEMA'));
+        ENDSEC;
+        ANCHOR;
+          <A> = #1;
+        ENDSEC;
+        DATA;
+          #1 = A(3.0, 4.0);
+        ENDSEC;
+        END-ISO-10303-21;
+        "#,
+    )
+    .unwrap();
+    let exchange = ruststep::link::link_file(dir.join("example.step")).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let table = Tables::from_exchange(&exchange).unwrap();
+    assert_eq!(
+        EntityTable::<BHolder>::get_owned(&table, 6).unwrap(),
+        B {
+            z: 1.0,
+            a: A { x: 3.0, y: 4.0 },
+        }
+    );
+}
@@ -1,4 +1,4 @@
-use ruststep::ast::*;
+use ruststep::ast::{ser::*, *};
 use serde::Deserialize;
 
 #[test]
@@ -11,3 +11,9 @@ fn deserialize_optional() {
     let x: Option<i32> = Deserialize::deserialize(&p).unwrap();
     assert_eq!(x, Some(3));
 }
+
+#[test]
+fn serialize_optional() {
+    assert_eq!(to_parameter(&None::<i32>).unwrap(), Parameter::NotProvided);
+    assert_eq!(to_parameter(&Some(3)).unwrap(), Parameter::Integer(3));
+}
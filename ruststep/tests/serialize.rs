@@ -0,0 +1,206 @@
+use ruststep::{
+    ast::{ser::*, DataSection, Record},
+    tables::*,
+};
+use std::str::FromStr;
+
+mod common;
+use common::roundtrip;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE label = STRING;
+      END_TYPE;
+
+      TYPE color = ENUMERATION OF (dark_red, light_blue);
+      END_TYPE;
+
+      TYPE item = SELECT (label, a);
+      END_TYPE;
+
+      ENTITY a;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY b;
+        a1: a;
+        a2: a;
+        color: color;
+      END_ENTITY;
+
+      ENTITY c;
+        values: LIST [1:?] OF item;
+      END_ENTITY;
+
+      ENTITY base SUPERTYPE OF (ONEOF (sub));
+        x: REAL;
+      END_ENTITY;
+
+      ENTITY sub SUBTYPE OF (base);
+        y: REAL;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = A(1.0, 2.0);
+  #2 = B(#1, #1, .DARK_RED.);
+  #3 = C((#1, #1));
+  #4 = SUB(BASE((3.0)), 4.0);
+  #5 = SUB(#6, 5.0);
+  #6 = BASE(6.0);
+ENDSEC;
+"#;
+
+#[test]
+fn tables_roundtrip() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let data = table.to_data_section().unwrap();
+    assert_eq!(data, DataSection::from_str(EXAMPLE).unwrap());
+    assert_eq!(Tables::from_data_section(&data).unwrap(), table);
+}
+
+#[test]
+fn hoist_inline_entity() {
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = B(A((1.0, 2.0)), #1, .LIGHT_BLUE.);
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    let data = table.to_data_section().unwrap();
+    assert_eq!(
+        data,
+        DataSection::from_str(
+            r#"
+            DATA;
+              #1 = B(#2, #1, .LIGHT_BLUE.);
+              #2 = A(1.0, 2.0);
+            ENDSEC;
+            "#
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn owned_graph() {
+    let a = A { x: 1.0, y: 2.0 };
+    let b = B {
+        a1: a.clone(),
+        a2: a.clone(),
+        color: Color::LightBlue,
+    };
+    let c = C {
+        values: vec![Item::A(Box::new(a))],
+    };
+    let sub = Sub {
+        base: Base { x: 3.0 },
+        y: 4.0,
+    };
+
+    // Every nested entity has its own id
+    let mut builder = DataSectionBuilder::new();
+    builder.insert(10, &b).unwrap();
+    builder.push(&c).unwrap();
+    builder.push(&sub).unwrap();
+    let data = builder.build();
+    assert_eq!(
+        data,
+        DataSection::from_str(
+            r#"
+            DATA;
+              #10 = B(#11, #12, .LIGHT_BLUE.);
+              #11 = A(1.0, 2.0);
+              #12 = A(1.0, 2.0);
+              #13 = A(1.0, 2.0);
+              #14 = C((#13));
+              #15 = SUB(BASE((3.0)), 4.0);
+            ENDSEC;
+            "#
+        )
+        .unwrap()
+    );
+    let table = Tables::from_data_section(&data).unwrap();
+    assert_eq!(EntityTable::<BHolder>::get_owned(&table, 10).unwrap(), b);
+    assert_eq!(EntityTable::<CHolder>::get_owned(&table, 14).unwrap(), c);
+    assert_eq!(
+        EntityTable::<SubHolder>::get_owned(&table, 15).unwrap(),
+        sub
+    );
+
+    // Equal entities are shared if requested
+    let mut builder = DataSectionBuilder::new().share_equal();
+    builder.insert(10, &b).unwrap();
+    builder.push(&c).unwrap();
+    builder.push(&sub).unwrap();
+    let data = builder.build();
+    assert_eq!(
+        data,
+        DataSection::from_str(
+            r#"
+            DATA;
+              #10 = B(#11, #11, .LIGHT_BLUE.);
+              #11 = A(1.0, 2.0);
+              #12 = C((#11));
+              #13 = SUB(BASE((3.0)), 4.0);
+            ENDSEC;
+            "#
+        )
+        .unwrap()
+    );
+
+    // Shared entity is resolved again
+    let table = Tables::from_data_section(&data).unwrap();
+    assert_eq!(EntityTable::<BHolder>::get_owned(&table, 10).unwrap(), b);
+    assert_eq!(EntityTable::<CHolder>::get_owned(&table, 12).unwrap(), c);
+    assert_eq!(
+        EntityTable::<SubHolder>::get_owned(&table, 13).unwrap(),
+        sub
+    );
+}
+
+#[test]
+fn non_finite() {
+    let a = A {
+        x: f64::NAN,
+        y: f64::INFINITY,
+    };
+    let b = B {
+        a1: a.clone(),
+        a2: a,
+        color: Color::LightBlue,
+    };
+    let mut builder = DataSectionBuilder::new();
+    builder.push(&b).unwrap();
+    assert_eq!(builder.build().entities.len(), 3);
+
+    // NaN is not equal to itself, but shared since reals are compared by their bits
+    let mut builder = DataSectionBuilder::new().share_equal();
+    builder.push(&b).unwrap();
+    assert_eq!(builder.build().entities.len(), 2);
+}
+
+#[test]
+fn select() {
+    let c = C {
+        values: vec![
+            Item::Label(Box::new(Label("ruststep".to_string()))),
+            Item::A(Box::new(A { x: 1.0, y: 2.0 })),
+        ],
+    };
+    let record = to_record(&c).unwrap();
+    assert_eq!(
+        record,
+        Record::from_str("C((LABEL(('ruststep')), A((1.0, 2.0))))").unwrap()
+    );
+    roundtrip::<CHolder>("C((LABEL(('ruststep')), A((1.0, 2.0))))");
+}
@@ -3,6 +3,9 @@ use ruststep::{ast::*, parser::exchange, tables::*};
 use serde::Deserialize;
 use std::str::FromStr;
 
+mod common;
+use common::roundtrip;
+
 espr_derive::inline_express!(
     r#"
     SCHEMA test_schema;
@@ -168,3 +171,13 @@ fn get_owned_any() {
         }))
     );
 }
+
+#[test]
+fn serialize_subsuper() {
+    roundtrip::<BaseHolder>("BASE(1.0)");
+    roundtrip::<SubHolder>("SUB(BASE((1.0)), 2.0)");
+    roundtrip::<SubHolder>("SUB(#3, 2.0)");
+    roundtrip::<SubsubHolder>("SUBSUB(SUB((BASE((1.0)), 2.0)), 3.0)");
+    roundtrip::<BaseAnyHolder>("BASE(1.0)");
+    roundtrip::<SubAnyHolder>("SUBSUB(#2, 3.0)");
+}
@@ -1,6 +1,6 @@
 // Test for deserializing Holder structs
 
-use ruststep::tables::*;
+use ruststep::{ast::DataSection, tables::*};
 use std::str::FromStr;
 
 espr_derive::inline_express!(
@@ -66,3 +66,10 @@ fn get_owned() {
         }
     );
 }
+
+#[test]
+fn serialize_tables() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let data = table.to_data_section().unwrap();
+    assert_eq!(data, DataSection::from_str(EXAMPLE).unwrap());
+}
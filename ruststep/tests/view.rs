@@ -0,0 +1,99 @@
+// Test for typed views navigating the tables without cloning
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE label = STRING;
+      END_TYPE;
+
+      ENTITY point;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY polyline;
+        name: label;
+        points: LIST [2:?] OF point;
+        origin: OPTIONAL point;
+      END_ENTITY;
+
+      ENTITY node;
+        name: label;
+        next: OPTIONAL item;
+      END_ENTITY;
+
+      TYPE item = SELECT (node, point);
+      END_TYPE;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = POINT(0.0, 0.0);
+  #2 = POINT(1.0, 0.0);
+  #3 = POLYLINE(#11, (#1, #2, POINT((1.0, 1.0))), $);
+  #4 = NODE(#12, #5);
+  #5 = NODE(#13, #4);
+  #6 = NODE(#14, #1);
+  #11 = LABEL('line');
+  #12 = LABEL('a');
+  #13 = LABEL('b');
+  #14 = LABEL('c');
+ENDSEC;
+"#;
+
+#[test]
+fn navigate() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let line = PolylineHolder::view_id(&table, 3).unwrap();
+    assert_eq!(line.name().get().unwrap(), "line");
+    assert!(line.origin().is_none());
+
+    let points = line.points();
+    assert_eq!(
+        points.iter().map(|p| p.id()).collect::<Vec<_>>(),
+        [Some(1), Some(2), None]
+    );
+    let p = points[1].get().unwrap();
+    assert_eq!((*p.x(), *p.y()), (1.0, 0.0));
+    // The holder is borrowed from the table
+    assert!(std::ptr::eq(p.holder, &table.point_holders()[&2]));
+    assert_eq!(*points[2].get().unwrap().y(), 1.0);
+    assert_eq!(points[2].to_owned().unwrap(), Point { x: 1.0, y: 1.0 });
+
+    assert!(matches!(
+        PolylineHolder::view_id(&table, 1),
+        Err(ruststep::error::Error::UnknownEntity(1))
+    ));
+}
+
+#[test]
+fn cyclic() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let a = NodeHolder::view_id(&table, 4).unwrap();
+    let b = match a.next().unwrap().get().unwrap() {
+        ItemView::Node(b) => b,
+        _ => panic!("#5 must be NODE"),
+    };
+    assert_eq!(b.name().get().unwrap(), "b");
+    match b.next().unwrap().get().unwrap() {
+        ItemView::Node(a) => assert_eq!(a.name().get().unwrap(), "a"),
+        _ => panic!("#4 must be NODE"),
+    }
+
+    let c = NodeHolder::view_id(&table, 6).unwrap();
+    match c.next().unwrap().get().unwrap() {
+        ItemView::Point(p) => assert_eq!(*p.x(), 0.0),
+        _ => panic!("#1 must be POINT"),
+    }
+    assert!(matches!(
+        ItemHolder::view_id(&table, 6),
+        Ok(ItemView::Node(_))
+    ));
+}